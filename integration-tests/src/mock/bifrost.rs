@@ -35,6 +35,7 @@ use bifrost_polkadot_runtime::{
 };
 use bifrost_primitives::{
 	AccountIdToLocation, CurrencyId, PolkadotUniversalLocation, SelfLocation,
+	SlashInsurancePalletId,
 };
 use bifrost_runtime_common::currency_converter::CurrencyIdConvert;
 use bifrost_slp::QueryResponseManager;
@@ -309,6 +310,9 @@ impl bifrost_slp::Config for Runtime {
 	type StablePoolHandler = ();
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = BifrostTreasuryAccount;
+	type OracleSignature = sp_runtime::MultiSignature;
+	type OracleSigner = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
+	type SlashInsurancePalletId = SlashInsurancePalletId;
 }
 
 impl bifrost_asset_registry::Config for Runtime {
@@ -28,7 +28,8 @@ pub use bifrost_primitives::{
 };
 use bifrost_primitives::{
 	BifrostEntranceAccount, BifrostExitAccount, BifrostFeeAccount, BuyBackAccount,
-	IncentivePalletId, IncentivePoolAccount, MoonbeamChainId,
+	IncentivePalletId, IncentivePoolAccount, InstantRedeemBufferPalletId, MoonbeamChainId,
+	SlashInsurancePalletId,
 };
 use bifrost_runtime_common::{micro, milli};
 use bifrost_slp::{QueryId, QueryResponseManager};
@@ -215,7 +216,11 @@ impl bifrost_vtoken_minting::Config for Runtime {
 	type MoonbeamChainId = MoonbeamChainId;
 	type ChannelCommission = ();
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<100>;
+	type OffchainSignature = sp_runtime::MultiSignature;
+	type SigningPublicKey = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = ();
 }
 
@@ -319,6 +324,9 @@ impl bifrost_slp::Config for Runtime {
 	type StablePoolHandler = ();
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = TreasuryAccount;
+	type OracleSignature = sp_runtime::MultiSignature;
+	type OracleSigner = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
+	type SlashInsurancePalletId = SlashInsurancePalletId;
 }
 
 parameter_types! {
@@ -25,7 +25,8 @@ use bifrost_asset_registry::AssetIdMaps;
 pub use bifrost_primitives::{currency::*, CurrencyId, SlpxOperator};
 use bifrost_primitives::{
 	BifrostEntranceAccount, BifrostExitAccount, BifrostFeeAccount, BuyBackAccount,
-	IncentivePalletId, IncentivePoolAccount, LiquidityAccount, MoonbeamChainId, ZenlinkPalletId,
+	IncentivePalletId, IncentivePoolAccount, InstantRedeemBufferPalletId, LiquidityAccount,
+	MoonbeamChainId, SlashInsurancePalletId, ZenlinkPalletId,
 };
 use bifrost_slp::{QueryId, QueryResponseManager};
 pub use cumulus_primitives_core::ParaId;
@@ -245,6 +246,9 @@ impl bifrost_slp::Config for Runtime {
 	type StablePoolHandler = ();
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = TreasuryAccount;
+	type OracleSignature = sp_runtime::MultiSignature;
+	type OracleSigner = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
+	type SlashInsurancePalletId = SlashInsurancePalletId;
 }
 
 parameter_type_with_key! {
@@ -301,7 +305,11 @@ impl bifrost_vtoken_minting::Config for Runtime {
 	type MoonbeamChainId = MoonbeamChainId;
 	type ChannelCommission = ();
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<100>;
+	type OffchainSignature = sp_runtime::MultiSignature;
+	type SigningPublicKey = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = ();
 }
 
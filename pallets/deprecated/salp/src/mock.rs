@@ -368,6 +368,7 @@ parameter_types! {
 	pub BifrostEntranceAccount: PalletId = PalletId(*b"bf/vtkin");
 	pub BifrostExitAccount: PalletId = PalletId(*b"bf/vtout");
 	pub IncentivePoolAccount: PalletId = PalletId(*b"bf/inpoo");
+	pub InstantRedeemBufferPalletId: PalletId = PalletId(*b"bf/vtbuf");
 }
 
 pub struct SlpxInterface;
@@ -436,7 +437,11 @@ impl bifrost_vtoken_minting::Config for Test {
 	type BifrostSlpx = SlpxInterface;
 	type ChannelCommission = ();
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<100>;
+	type OffchainSignature = sp_runtime::MultiSignature;
+	type SigningPublicKey = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = ();
 	type AssetIdMaps = AssetIdMaps<Test>;
 }
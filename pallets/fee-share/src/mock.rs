@@ -26,7 +26,8 @@ use bifrost_asset_registry::AssetIdMaps;
 pub use bifrost_primitives::{currency::*, CurrencyId, Moment, SlpxOperator};
 use bifrost_primitives::{
 	BifrostEntranceAccount, BifrostExitAccount, BifrostFeeAccount, FeeSharePalletId,
-	IncentivePoolAccount, MoonbeamChainId, PriceDetail, ZenlinkPalletId,
+	IncentivePoolAccount, InstantRedeemBufferPalletId, MoonbeamChainId, PriceDetail,
+	SlashInsurancePalletId, ZenlinkPalletId,
 };
 use bifrost_slp::{QueryId, QueryResponseManager};
 pub use cumulus_primitives_core::ParaId;
@@ -192,9 +193,21 @@ impl pallet_prices::Config for Runtime {
 	type RelayCurrency = RelayCurrencyId;
 	type Assets = Currencies;
 	type CurrencyIdConvert = AssetIdMaps<Runtime>;
+	type PriceSources = ();
+	type UnixTime = MockUnixTime;
+	type MaxAggregatedAssets = ConstU32<10>;
+	type MaxPriceObservations = ConstU32<10>;
+	type MaxCircuitBreakerAssets = ConstU32<10>;
 	type WeightInfo = ();
 }
 
+pub struct MockUnixTime;
+impl frame_support::traits::UnixTime for MockUnixTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::ZERO
+	}
+}
+
 // pallet-price is using for benchmark compilation
 pub type TimeStampedPrice = orml_oracle::TimestampedValue<Price, Moment>;
 pub struct MockDataProvider;
@@ -290,6 +303,10 @@ impl OraclePriceProvider for MockOraclePriceProvider {
 	) -> Option<(bifrost_primitives::Balance, Price, Price)> {
 		todo!()
 	}
+
+	fn is_price_paused(_asset_id: &CurrencyId) -> bool {
+		false
+	}
 }
 
 pub struct ParaInfo;
@@ -357,6 +374,9 @@ impl bifrost_slp::Config for Runtime {
 	type StablePoolHandler = ();
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = TreasuryAccount;
+	type OracleSignature = sp_runtime::MultiSignature;
+	type OracleSigner = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
+	type SlashInsurancePalletId = SlashInsurancePalletId;
 }
 
 parameter_type_with_key! {
@@ -413,7 +433,11 @@ impl bifrost_vtoken_minting::Config for Runtime {
 	type MoonbeamChainId = MoonbeamChainId;
 	type ChannelCommission = ();
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<100>;
+	type OffchainSignature = sp_runtime::MultiSignature;
+	type SigningPublicKey = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = ();
 }
 
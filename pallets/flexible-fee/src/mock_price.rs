@@ -106,6 +106,10 @@ impl OraclePriceProvider for MockOraclePriceProvider {
 		}
 		None
 	}
+
+	fn is_price_paused(_asset_id: &CurrencyId) -> bool {
+		false
+	}
 }
 
 #[cfg(test)]
@@ -14,16 +14,21 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use bifrost_primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall};
+use bifrost_primitives::{
+	AccountPosition, BlockNumber, CurrencyId, Liquidity, Rate, Ratio, Shortfall,
+};
 use parity_scale_codec::Codec;
 use sp_runtime::{DispatchError, FixedU128};
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait LendMarketApi<AccountId, Balance> where
 		AccountId: Codec,
 		Balance: Codec {
 		fn get_account_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError>;
 		fn get_market_status(asset_id: CurrencyId) -> Result<(Rate, Rate, Rate, Ratio, Balance, Balance, FixedU128), DispatchError>;
 		fn get_liquidation_threshold_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError>;
+		#[api_version(2)]
+		fn get_account_position(account: AccountId, blocks_ahead: BlockNumber) -> Result<AccountPosition<Balance>, DispatchError>;
 	}
 }
@@ -16,14 +16,16 @@ use std::sync::Arc;
 
 pub use lend_market_rpc_runtime_api::LendMarketApi as LendMarketRuntimeApi;
 
-use bifrost_primitives::{CurrencyId, Liquidity, Rate, Ratio, Shortfall};
+use bifrost_primitives::{
+	AccountPosition, BlockNumber, CurrencyId, Liquidity, MarketPosition, Rate, Ratio, Shortfall,
+};
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
 	proc_macros::rpc,
 	types::error::{ErrorCode, ErrorObject},
 };
 use parity_scale_codec::Codec;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_rpc::number::NumberOrHex;
 use sp_runtime::{traits::Block as BlockT, FixedU128};
@@ -51,6 +53,13 @@ where
 		account: AccountId,
 		at: Option<BlockHash>,
 	) -> RpcResult<(Liquidity, Shortfall, Liquidity, Shortfall)>;
+	#[method(name = "lend_market_getAccountPosition")]
+	fn get_account_position(
+		&self,
+		account: AccountId,
+		blocks_ahead: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<AccountPosition<NumberOrHex>>;
 }
 
 /// A struct that implements the [`LendMarketApi`].
@@ -70,6 +79,7 @@ pub enum Error {
 	RuntimeError,
 	AccountLiquidityError,
 	MarketStatusError,
+	AccountPositionError,
 }
 
 impl From<Error> for i32 {
@@ -78,6 +88,7 @@ impl From<Error> for i32 {
 			Error::RuntimeError => 1,
 			Error::AccountLiquidityError => 2,
 			Error::MarketStatusError => 3,
+			Error::AccountPositionError => 4,
 		}
 	}
 }
@@ -155,6 +166,51 @@ where
 			.map_err(runtime_error_into_rpc_error)?
 			.map_err(account_liquidity_error_into_rpc_error)
 	}
+
+	fn get_account_position(
+		&self,
+		account: AccountId,
+		blocks_ahead: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<AccountPosition<NumberOrHex>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		);
+		// The account position is only available from version 2 of the runtime api.
+		let api_version = api
+			.api_version::<dyn LendMarketRuntimeApi<Block, AccountId, Balance>>(at)
+			.map_err(runtime_error_into_rpc_error)?;
+		if api_version.map_or(true, |version| version < 2) {
+			return Err(account_position_error_into_rpc_error("LendMarketApi version 2 required"));
+		}
+		let position = api
+			.get_account_position(at, account, blocks_ahead)
+			.map_err(runtime_error_into_rpc_error)?
+			.map_err(account_position_error_into_rpc_error)?;
+		let markets = position
+			.markets
+			.into_iter()
+			.map(|market| {
+				Ok(MarketPosition {
+					asset_id: market.asset_id,
+					voucher_balance: try_into_rpc_balance(market.voucher_balance)?,
+					supply_balance: try_into_rpc_balance(market.supply_balance)?,
+					is_collateral: market.is_collateral,
+					borrow_balance: try_into_rpc_balance(market.borrow_balance)?,
+					supply_rate: market.supply_rate,
+					borrow_rate: market.borrow_rate,
+				})
+			})
+			.collect::<RpcResult<Vec<_>>>()?;
+		Ok(AccountPosition {
+			markets,
+			reward_accrued: try_into_rpc_balance(position.reward_accrued)?,
+			health_factor: position.health_factor,
+			projected_health_factor: position.projected_health_factor,
+		})
+	}
 }
 
 /// Converts a runtime trap into an RPC error.
@@ -180,6 +236,15 @@ fn market_status_error_into_rpc_error(err: impl std::fmt::Debug) -> ErrorObject<
 	)
 }
 
+/// Converts an account position error into an RPC error.
+fn account_position_error_into_rpc_error(err: impl std::fmt::Debug) -> ErrorObject<'static> {
+	ErrorObject::owned(
+		Error::AccountPositionError.into(),
+		"Not able to get account position",
+		Some(format!("{:?}", err)),
+	)
+}
+
 fn try_into_rpc_balance<T: std::fmt::Display + Copy + TryInto<NumberOrHex>>(
 	value: T,
 ) -> RpcResult<NumberOrHex> {
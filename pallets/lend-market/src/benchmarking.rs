@@ -41,6 +41,7 @@ fn market_mock<T: Config>() -> Market<BalanceOf<T>> {
 		supply_cap: 1_000_000_000_000_000_000_000u128, // set to 1B
 		borrow_cap: 1_000_000_000_000_000_000_000u128, // set to 1B
 		lend_token_id: LKSM,
+		isolation: None,
 	}
 }
 
@@ -380,6 +381,150 @@ benchmarks! {
 	verify {
 		assert_last_event::<T>(Event::<T>::LiquidationFreeCollateralsUpdated(vec![PHA]).into());
 	}
+
+	update_isolation_mode {
+		let p in 2 .. 200;
+		let deposit_amount: u32 = 200_000_000;
+		let borrowed_amount: u32 = 100_000_000;
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), VKSM, pending_market_mock::<T>(VSKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), VKSM));
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+		// Every collateral depositor is checked against every market.
+		let markets = Markets::<T>::iter_keys().count() as u32;
+		for i in 0 .. p / markets {
+			let depositor: T::AccountId = account("Sample", i, SEED);
+			transfer_initial_balance::<T>(depositor.clone());
+			assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(depositor.clone()).into(), VKSM, deposit_amount.into()));
+			assert_ok!(LendMarket::<T>::collateral_asset(SystemOrigin::Signed(depositor.clone()).into(), VKSM, true));
+			set_account_borrows::<T>(depositor, KSM, borrowed_amount.into());
+		}
+		let isolation = IsolationMode {
+			borrowable_assets: BoundedVec::truncate_from(vec![KSM]),
+			debt_ceiling: 1_000_000_000u32.into(),
+		};
+	}: _(SystemOrigin::Root, VKSM, Some(isolation.clone()), p)
+	verify {
+		assert_last_event::<T>(Event::<T>::IsolationModeUpdated { asset_id: VKSM, isolation: Some(isolation) }.into());
+	}
+
+	update_emode_category {
+		let caller: T::AccountId = whitelisted_caller();
+		let category = EModeCategory {
+			collateral_factor: Ratio::from_percent(90),
+			liquidation_threshold: Ratio::from_percent(93),
+			liquidate_incentive: Rate::from_inner(Rate::DIV / 100 * 105),
+			assets: BoundedVec::truncate_from(vec![KSM, VKSM]),
+		};
+		assert_ok!(LendMarket::<T>::update_emode_category(SystemOrigin::Root.into(), 1, Some(category.clone())));
+		assert_ok!(LendMarket::<T>::set_account_emode(SystemOrigin::Signed(caller).into(), Some(1)));
+		let category = EModeCategory { collateral_factor: Ratio::from_percent(91), ..category };
+	}: _(SystemOrigin::Root, 1, Some(category.clone()))
+	verify {
+		assert_last_event::<T>(Event::<T>::EModeCategoryUpdated { category_id: 1, category: Some(category) }.into());
+	}
+
+	set_account_emode {
+		let caller: T::AccountId = whitelisted_caller();
+		transfer_initial_balance::<T>(caller.clone());
+		let deposit_amount: u32 = 200_000_000;
+		let borrowed_amount: u32 = 100_000_000;
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), VKSM, pending_market_mock::<T>(VSKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), VKSM));
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(caller.clone()).into(), VKSM, deposit_amount.into()));
+		assert_ok!(LendMarket::<T>::collateral_asset(SystemOrigin::Signed(caller.clone()).into(), VKSM, true));
+		set_account_borrows::<T>(caller.clone(), KSM, borrowed_amount.into());
+		assert_ok!(LendMarket::<T>::update_emode_category(
+			SystemOrigin::Root.into(),
+			1,
+			Some(EModeCategory {
+				collateral_factor: Ratio::from_percent(90),
+				liquidation_threshold: Ratio::from_percent(93),
+				liquidate_incentive: Rate::from_inner(Rate::DIV / 100 * 105),
+				assets: BoundedVec::truncate_from(vec![KSM, VKSM]),
+			}),
+		));
+	}: _(SystemOrigin::Signed(caller.clone()), Some(1))
+	verify {
+		assert_last_event::<T>(Event::<T>::AccountEModeSet { who: caller, category_id: Some(1) }.into());
+	}
+
+	update_liquidation_auction_config {
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		let config = LiquidationAuctionConfig {
+			min_auction_value: 1u32.into(),
+			start_incentive: Rate::one(),
+			incentive_step: Rate::from_inner(Rate::DIV / 1000),
+		};
+	}: _(SystemOrigin::Root, KSM, Some(config))
+	verify {
+		assert_last_event::<T>(Event::<T>::LiquidationAuctionConfigUpdated { asset_id: KSM, config: Some(config) }.into());
+	}
+
+	start_liquidation_auction {
+		let alice: T::AccountId = account("Sample", 100, SEED);
+		let bob: T::AccountId = account("Sample", 101, SEED);
+		transfer_initial_balance::<T>(alice.clone());
+		let deposit_amount: u32 = 200_000_000;
+		let borrowed_amount: u32 = 200_000_000;
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), VKSM, pending_market_mock::<T>(VSKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), VKSM));
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(alice.clone()).into(), VKSM, deposit_amount.into()));
+		assert_ok!(LendMarket::<T>::collateral_asset(SystemOrigin::Signed(alice.clone()).into(), VKSM, true));
+		set_account_borrows::<T>(alice.clone(), KSM, borrowed_amount.into());
+		assert_ok!(LendMarket::<T>::update_liquidation_auction_config(
+			SystemOrigin::Root.into(),
+			KSM,
+			Some(LiquidationAuctionConfig {
+				min_auction_value: 1u32.into(),
+				start_incentive: Rate::one(),
+				incentive_step: Rate::from_inner(Rate::DIV / 1000),
+			}),
+		));
+	}: _(SystemOrigin::Signed(bob.clone()), alice.clone(), KSM)
+	verify {
+		assert!(LiquidationAuctions::<T>::contains_key(KSM, &alice));
+	}
+
+	close_liquidation_auction {
+		let alice: T::AccountId = account("Sample", 100, SEED);
+		let bob: T::AccountId = account("Sample", 101, SEED);
+		transfer_initial_balance::<T>(alice.clone());
+		let deposit_amount: u32 = 200_000_000;
+		let borrowed_amount: u32 = 50_000_000;
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), VKSM, pending_market_mock::<T>(VSKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), VKSM));
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(alice.clone()).into(), VKSM, deposit_amount.into()));
+		assert_ok!(LendMarket::<T>::collateral_asset(SystemOrigin::Signed(alice.clone()).into(), VKSM, true));
+		set_account_borrows::<T>(alice.clone(), KSM, borrowed_amount.into());
+		// The position is healthy again, the auction can be closed.
+		LiquidationAuctions::<T>::insert(KSM, &alice, frame_system::Pallet::<T>::block_number());
+	}: _(SystemOrigin::Signed(bob.clone()), alice.clone(), KSM)
+	verify {
+		assert!(!LiquidationAuctions::<T>::contains_key(KSM, &alice));
+	}
+
+	write_off_bad_debt {
+		let alice: T::AccountId = account("Sample", 100, SEED);
+		let bob: T::AccountId = account("Sample", 101, SEED);
+		transfer_initial_balance::<T>(alice.clone());
+		transfer_initial_balance::<T>(bob.clone());
+		let deposit_amount: u32 = 200_000_000;
+		let borrowed_amount: u32 = 100_000_000;
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(bob.clone()).into(), KSM, deposit_amount.into()));
+		set_account_borrows::<T>(alice.clone(), KSM, borrowed_amount.into());
+	}: _(SystemOrigin::Root, alice.clone(), KSM)
+	verify {
+		assert!(AccountBorrows::<T>::get(KSM, &alice).principal.is_zero());
+	}
 }
 
 impl_benchmark_test_suite!(LendMarket, crate::mock::new_test_ext(), crate::mock::Test);
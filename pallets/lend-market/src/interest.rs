@@ -31,6 +31,10 @@ impl<T: Config> Pallet<T> {
 			return Ok(());
 		}
 
+		if Self::market(asset_id)?.rate_model.is_staking_yield_model() {
+			Self::update_staking_yield(asset_id, now)?;
+		}
+
 		let (
 			borrow_rate,
 			supply_rate,
@@ -66,8 +70,10 @@ impl<T: Config> Pallet<T> {
 		let mut borrow_index = BorrowIndex::<T>::get(asset_id);
 
 		let util = Self::calc_utilization_ratio(total_cash, total_borrows, total_reserves)?;
-		let borrow_rate =
-			market.rate_model.get_borrow_rate(util).ok_or(ArithmeticError::Overflow)?;
+		let borrow_rate = market
+			.rate_model
+			.get_borrow_rate(util, StakingYield::<T>::get(asset_id))
+			.ok_or(ArithmeticError::Overflow)?;
 		let supply_rate =
 			InterestRateModel::get_supply_rate(borrow_rate, util, market.reserve_factor);
 
@@ -106,6 +112,53 @@ impl<T: Config> Pallet<T> {
 		))
 	}
 
+	/// Measure the realised staking APY of a vToken market.
+	///
+	/// The vToken exchange rate is snapshotted at the start of each period, once at least
+	/// `STAKING_YIELD_PERIOD` has elapsed its growth is annualised into `StakingYield`.
+	/// A decreasing exchange rate results in a zero yield.
+	pub(crate) fn update_staking_yield(asset_id: AssetIdOf<T>, now: Timestamp) -> DispatchResult {
+		let Some(exchange_rate) = Self::vtoken_exchange_rate(asset_id) else {
+			return Ok(());
+		};
+
+		if let Some((last_time, last_exchange_rate)) = StakingYieldSnapshot::<T>::get(asset_id) {
+			let delta_time = now.saturating_sub(last_time);
+			if delta_time < STAKING_YIELD_PERIOD {
+				return Ok(());
+			}
+			// (exchange_rate - last_exchange_rate) / last_exchange_rate *
+			// SECONDS_PER_YEAR / delta_time
+			let staking_yield = exchange_rate
+				.saturating_sub(last_exchange_rate)
+				.checked_div(&last_exchange_rate)
+				.and_then(|r| r.checked_mul(&FixedU128::saturating_from_integer(SECONDS_PER_YEAR)))
+				.and_then(|r| r.checked_div(&FixedU128::saturating_from_integer(delta_time)))
+				.ok_or(ArithmeticError::Overflow)?;
+			StakingYield::<T>::insert(asset_id, staking_yield);
+			Self::deposit_event(Event::<T>::StakingYieldUpdated { asset_id, staking_yield });
+		}
+		StakingYieldSnapshot::<T>::insert(asset_id, (now, exchange_rate));
+
+		Ok(())
+	}
+
+	/// The amount of underlying token one unit of vToken can be redeemed for
+	fn vtoken_exchange_rate(vtoken_id: AssetIdOf<T>) -> Option<Rate> {
+		let token_id = vtoken_id.to_token().ok()?;
+		let token_amount = T::VtokenMinting::get_currency_amount_by_v_currency_amount(
+			token_id,
+			vtoken_id,
+			Rate::DIV,
+		)
+		.ok()?;
+		if token_amount.is_zero() {
+			return None;
+		}
+
+		Some(Rate::from_inner(token_amount))
+	}
+
 	/// Update the exchange rate according to the totalCash, totalBorrows and totalSupply.
 	/// This function does not accrue interest before calculating the exchange rate.
 	/// exchangeRate = (totalCash + totalBorrows - totalReserves) / totalSupply
@@ -159,7 +212,7 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
-	fn accrued_interest(
+	pub(crate) fn accrued_interest(
 		borrow_rate: Rate,
 		amount: BalanceOf<T>,
 		delta_time: Timestamp,
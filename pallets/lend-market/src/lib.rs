@@ -26,11 +26,13 @@ use core::cmp::max;
 
 pub use crate::rate_model::*;
 use bifrost_primitives::{
-	Balance, CurrencyId, Liquidity, OraclePriceProvider, Price, Rate, Ratio, Shortfall, Timestamp,
+	AccountPosition, Balance, CurrencyId, Liquidity, MarketPosition, OraclePriceProvider, Price,
+	Rate, Ratio, Shortfall, Timestamp, VtokenMintingInterface,
 };
 use frame_support::{
 	pallet_prelude::*,
 	require_transactional,
+	storage::{with_transaction, TransactionOutcome},
 	traits::{
 		fungibles::{Inspect, Mutate},
 		tokens::{Fortitude, Preservation},
@@ -57,7 +59,10 @@ use sp_std::{result::Result, vec::Vec};
 
 use log;
 use sp_io::hashing::blake2_256;
-pub use types::{BorrowSnapshot, Deposits, EarnedSnapshot, Market, MarketState, RewardMarketState};
+pub use types::{
+	BorrowSnapshot, Deposits, EModeCategory, EarnedSnapshot, IsolationMode, Liquidation,
+	LiquidationAuctionConfig, Market, MarketState, RewardMarketState,
+};
 pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -79,12 +84,18 @@ pub mod weights;
 
 pub const MAX_EXCHANGE_RATE: u128 = 1_000_000_000_000_000_000; // 1
 pub const MIN_EXCHANGE_RATE: u128 = 20_000_000_000_000_000; // 0.02
+/// Minimum time span over which the vToken staking yield is measured
+pub const STAKING_YIELD_PERIOD: Timestamp = 7 * 24 * 60 * 60; // 7 days
+/// Maximum number of borrows written off by a liquidation exhausting the deposits of the
+/// borrower, the others are written off through `write_off_bad_debt`.
+pub const MAX_BAD_DEBT_WRITE_OFFS: u32 = 8;
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type AssetIdOf<T> =
 	<<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
 pub type BalanceOf<T> =
 	<<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+pub type EModeCategoryId = u8;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -128,6 +139,13 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MaxLengthLimit: Get<u32>;
+
+		/// The vToken exchange rate source of the staking yield interest rate model
+		type VtokenMinting: VtokenMintingInterface<
+			AccountIdOf<Self>,
+			AssetIdOf<Self>,
+			BalanceOf<Self>,
+		>;
 	}
 
 	#[pallet::error]
@@ -194,6 +212,36 @@ pub mod pallet {
 		MarketBondDoesNotExist,
 		/// Error converting Vec to BoundedVec.
 		ConversionError,
+		/// Isolated asset can't be used as collateral together with other assets
+		IsolatedCollateralConflict,
+		/// Asset can't be borrowed against the isolated collateral
+		NotBorrowableInIsolationMode,
+		/// Upper bound of the debt backed by the isolated collateral is exceeded
+		DebtCeilingExceeded,
+		/// More positions are checked than the witness of the call allows
+		InvalidPositionsWitness,
+		/// E-mode category does not exist
+		EModeCategoryDoesNotExist,
+		/// Asset can't be borrowed in the e-mode category of the account
+		NotBorrowableInEMode,
+		/// Liquidation auction of the position is already running
+		LiquidationAuctionAlreadyStarted,
+		/// Liquidation auction does not exist
+		LiquidationAuctionDoesNotExist,
+		/// Liquidation auctions are not enabled for the market
+		LiquidationAuctionNotEnabled,
+		/// Position is too small to be liquidated through an auction
+		InsufficientAuctionValue,
+		/// Position can still be liquidated, the auction can't be closed
+		PositionStillLiquidatable,
+		/// Borrower still has deposits, the debt can't be written off
+		DepositsNotExhausted,
+		/// Borrower has no debt to write off
+		NoBadDebt,
+		/// The price of the asset is paused by the circuit breaker
+		PricePaused,
+		/// E-mode category has opted in accounts, it can't be removed or lowered
+		EModeCategoryInUse,
 	}
 
 	#[pallet::event]
@@ -264,6 +312,56 @@ pub mod pallet {
 			asset_id: AssetIdOf<T>,
 			market_bond: Vec<AssetIdOf<T>>,
 		},
+		/// Isolation mode of a market has been updated
+		IsolationModeUpdated {
+			asset_id: AssetIdOf<T>,
+			isolation: Option<IsolationMode<BalanceOf<T>>>,
+		},
+		/// E-mode category has been updated
+		EModeCategoryUpdated {
+			category_id: EModeCategoryId,
+			category: Option<EModeCategory>,
+		},
+		/// Account has changed its e-mode category
+		AccountEModeSet {
+			who: T::AccountId,
+			category_id: Option<EModeCategoryId>,
+		},
+		/// The realised staking yield of a vToken market has been updated
+		StakingYieldUpdated {
+			asset_id: AssetIdOf<T>,
+			staking_yield: Rate,
+		},
+		/// Liquidation auction parameters of a market have been updated
+		LiquidationAuctionConfigUpdated {
+			asset_id: AssetIdOf<T>,
+			config: Option<LiquidationAuctionConfig<BalanceOf<T>>>,
+		},
+		/// A liquidation auction has been started for a borrow position
+		LiquidationAuctionStarted {
+			borrower: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			start_block: BlockNumberFor<T>,
+		},
+		/// A liquidation auction has been closed
+		LiquidationAuctionClosed {
+			borrower: T::AccountId,
+			asset_id: AssetIdOf<T>,
+		},
+		/// The debt of a borrower without collateral has been written off.
+		///
+		/// `reserves_used` is covered by the market reserves and `socialised_amount` by the
+		/// suppliers through the exchange rate. `remaining_debt` could not be absorbed without
+		/// pushing the exchange rate below its minimum and is still owed by the borrower.
+		BadDebtWrittenOff {
+			borrower: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			reserves_used: BalanceOf<T>,
+			socialised_amount: BalanceOf<T>,
+			remaining_debt: BalanceOf<T>,
+			exchange_rate_before: Rate,
+			exchange_rate_after: Rate,
+		},
 	}
 
 	/// The timestamp of the last calculation of accrued interest
@@ -435,7 +533,59 @@ pub mod pallet {
 	pub type MarketBond<T: Config> =
 		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, BoundedVec<AssetIdOf<T>, T::MaxLengthLimit>>;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	/// Total debt value backed by each isolated collateral asset
+	/// CurrencyId -> Balance
+	#[pallet::storage]
+	pub type IsolatedDebt<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+	/// Mapping of e-mode category id to the category
+	#[pallet::storage]
+	pub type EModeCategories<T: Config> =
+		StorageMap<_, Twox64Concat, EModeCategoryId, EModeCategory>;
+
+	/// The e-mode category each account opted in
+	#[pallet::storage]
+	pub type AccountEMode<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, EModeCategoryId>;
+
+	/// Number of accounts opted in each e-mode category
+	/// EModeCategoryId -> u32
+	#[pallet::storage]
+	pub type EModeCategoryAccounts<T: Config> =
+		StorageMap<_, Twox64Concat, EModeCategoryId, u32, ValueQuery>;
+
+	/// The vToken exchange rate observed at the start of the current staking yield period
+	/// CurrencyId -> (Timestamp, Rate)
+	#[pallet::storage]
+	pub type StakingYieldSnapshot<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, (Timestamp, Rate)>;
+
+	/// The annualised staking yield of vToken markets, measured over the last period
+	/// CurrencyId -> Rate
+	#[pallet::storage]
+	pub type StakingYield<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, Rate, ValueQuery>;
+
+	/// Liquidation auction parameters of each market
+	/// CurrencyId -> LiquidationAuctionConfig
+	#[pallet::storage]
+	pub type LiquidationAuctionConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, LiquidationAuctionConfig<BalanceOf<T>>>;
+
+	/// The start block of the running liquidation auctions
+	/// CurrencyId -> Borrower -> BlockNumber
+	#[pallet::storage]
+	pub type LiquidationAuctions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetIdOf<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumberFor<T>,
+	>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
@@ -621,6 +771,7 @@ pub mod pallet {
 					liquidate_incentive_reserved_factor,
 					supply_cap,
 					borrow_cap,
+					isolation: stored_market.isolation.clone(),
 				};
 				stored_market.clone()
 			})?;
@@ -941,7 +1092,7 @@ pub mod pallet {
 		/// - `repay_amount`: the amount to be repaid borrow.
 		/// - `collateral_asset_id`: The collateral to seize from the borrower.
 		#[pallet::call_index(17)]
-		#[pallet::weight(T::WeightInfo::liquidate_borrow())]
+		#[pallet::weight(Pallet::<T>::liquidate_borrow_weight(MAX_BAD_DEBT_WRITE_OFFS))]
 		#[transactional]
 		pub fn liquidate_borrow(
 			origin: OriginFor<T>,
@@ -951,20 +1102,14 @@ pub mod pallet {
 			collateral_asset_id: AssetIdOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			ensure!(
-				!LiquidationFreeCollaterals::<T>::get().contains(&collateral_asset_id),
-				Error::<T>::CollateralReserved
-			);
-			Self::accrue_interest(liquidation_asset_id)?;
-			Self::accrue_interest(collateral_asset_id)?;
-			Self::do_liquidate_borrow(
+			let write_offs = Self::liquidate_borrow_inner(
 				who,
 				borrower,
 				liquidation_asset_id,
 				repay_amount,
 				collateral_asset_id,
 			)?;
-			Ok(().into())
+			Ok(Some(Self::liquidate_borrow_weight(write_offs)).into())
 		}
 
 		/// Add reserves by transferring from payer.
@@ -1133,6 +1278,302 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::MarketBonded { asset_id, market_bond });
 			Ok(().into())
 		}
+
+		/// Updates the isolation mode of a stored market.
+		///
+		/// The asset can only be isolated if every account using it as collateral already
+		/// satisfies the isolation mode, the debt of those accounts is then counted in the
+		/// debt ceiling.
+		///
+		/// - `asset_id`: market related currency
+		/// - `isolation`: the new isolation mode, `None` to move the asset back to the shared
+		///   cross-collateral pool
+		/// - `positions_witness`: upper bound of the positions checked, the number of accounts
+		///   depositing the asset times the number of markets
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::update_isolation_mode(*positions_witness))]
+		#[transactional]
+		pub fn update_isolation_mode(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			isolation: Option<IsolationMode<BalanceOf<T>>>,
+			positions_witness: u32,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let (isolated_debt, positions) = match isolation {
+				Some(ref isolation) => {
+					let (isolated_debt, positions) =
+						Self::existing_isolated_debt(asset_id, isolation, positions_witness)?;
+					(Some(isolated_debt), positions)
+				},
+				None => (None, 0),
+			};
+			Self::mutate_market(asset_id, |stored_market| {
+				stored_market.isolation = isolation.clone();
+				stored_market.clone()
+			})?;
+			match isolated_debt {
+				Some(isolated_debt) => IsolatedDebt::<T>::insert(asset_id, isolated_debt),
+				None => IsolatedDebt::<T>::remove(asset_id),
+			}
+
+			Self::deposit_event(Event::<T>::IsolationModeUpdated { asset_id, isolation });
+			Ok(Some(T::WeightInfo::update_isolation_mode(positions)).into())
+		}
+
+		/// Registers, updates or removes an e-mode category.
+		///
+		/// A category with opted in accounts can't be removed, and its factors and assets
+		/// can only be raised.
+		///
+		/// - `category_id`: the category id
+		/// - `category`: the new category, `None` to remove it
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::update_emode_category())]
+		#[transactional]
+		pub fn update_emode_category(
+			origin: OriginFor<T>,
+			category_id: EModeCategoryId,
+			category: Option<EModeCategory>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Some(ref category) = category {
+				ensure!(
+					category.collateral_factor < Ratio::one() &&
+						category.liquidation_threshold < Ratio::one() &&
+						category.liquidation_threshold >= category.collateral_factor,
+					Error::<T>::InvalidFactor
+				);
+				// The incentive must not make a liquidation at the threshold take more
+				// collateral than the position holds.
+				ensure!(
+					category.liquidate_incentive >= Rate::one() &&
+						category
+							.liquidate_incentive
+							.saturating_mul(Rate::from(category.liquidation_threshold)) <=
+							Rate::one(),
+					Error::<T>::InvalidFactor
+				);
+			}
+			if EModeCategoryAccounts::<T>::get(category_id) > 0 {
+				let stored = EModeCategories::<T>::get(category_id)
+					.ok_or(Error::<T>::EModeCategoryDoesNotExist)?;
+				ensure!(
+					category.as_ref().map_or(false, |category| {
+						category.collateral_factor >= stored.collateral_factor &&
+							category.liquidation_threshold >= stored.liquidation_threshold &&
+							stored.assets.iter().all(|asset| category.assets.contains(asset))
+					}),
+					Error::<T>::EModeCategoryInUse
+				);
+			}
+			match category.clone() {
+				Some(category) => EModeCategories::<T>::insert(category_id, category),
+				None => EModeCategories::<T>::remove(category_id),
+			}
+
+			Self::deposit_event(Event::<T>::EModeCategoryUpdated { category_id, category });
+			Ok(().into())
+		}
+
+		/// Sender opts in or out of an e-mode category.
+		///
+		/// All the existing borrows must be in the category, and the account must not have
+		/// a shortfall after the change.
+		///
+		/// - `category_id`: the category to opt in, `None` to opt out
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::set_account_emode())]
+		#[transactional]
+		pub fn set_account_emode(
+			origin: OriginFor<T>,
+			category_id: Option<EModeCategoryId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			match category_id {
+				Some(category_id) => {
+					let category = EModeCategories::<T>::get(category_id)
+						.ok_or(Error::<T>::EModeCategoryDoesNotExist)?;
+					for (asset_id, _) in Self::active_markets() {
+						if !Self::current_borrow_balance(&who, asset_id)?.is_zero() {
+							ensure!(
+								category.assets.contains(&asset_id),
+								Error::<T>::NotBorrowableInEMode
+							);
+						}
+					}
+					if let Some(previous) = AccountEMode::<T>::get(&who) {
+						EModeCategoryAccounts::<T>::mutate(previous, |n| n.saturating_dec());
+					}
+					EModeCategoryAccounts::<T>::mutate(category_id, |n| n.saturating_inc());
+					AccountEMode::<T>::insert(&who, category_id);
+				},
+				None =>
+					if let Some(previous) = AccountEMode::<T>::take(&who) {
+						EModeCategoryAccounts::<T>::mutate(previous, |n| n.saturating_dec());
+					},
+			}
+			let (_, shortfall, _, _) = Self::get_account_liquidity(&who)?;
+			ensure!(shortfall.is_zero(), Error::<T>::InsufficientLiquidity);
+
+			Self::deposit_event(Event::<T>::AccountEModeSet { who, category_id });
+			Ok(().into())
+		}
+
+		/// The sender liquidates several borrow positions at once.
+		///
+		/// The batch is atomic, it fails if any of the liquidations fails.
+		///
+		/// - `liquidations`: the liquidations to execute, see `liquidate_borrow`
+		#[pallet::call_index(26)]
+		#[pallet::weight(
+			Pallet::<T>::liquidate_borrow_weight(MAX_BAD_DEBT_WRITE_OFFS)
+				.saturating_mul(liquidations.len() as u64)
+		)]
+		#[transactional]
+		pub fn batch_liquidate_borrow(
+			origin: OriginFor<T>,
+			liquidations: BoundedVec<Liquidation<T::AccountId, BalanceOf<T>>, T::MaxLengthLimit>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut actual_weight = Weight::zero();
+			for liquidation in liquidations {
+				let write_offs = Self::liquidate_borrow_inner(
+					who.clone(),
+					liquidation.borrower,
+					liquidation.liquidation_asset_id,
+					liquidation.repay_amount,
+					liquidation.collateral_asset_id,
+				)?;
+				actual_weight =
+					actual_weight.saturating_add(Self::liquidate_borrow_weight(write_offs));
+			}
+
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Updates the liquidation auction parameters of a stored market.
+		///
+		/// - `asset_id`: market related currency
+		/// - `config`: the new auction parameters, `None` to disable liquidation auctions
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::update_liquidation_auction_config())]
+		#[transactional]
+		pub fn update_liquidation_auction_config(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			config: Option<LiquidationAuctionConfig<BalanceOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let market = Self::market(asset_id)?;
+			match config {
+				Some(config) => {
+					ensure!(
+						config.start_incentive >= Rate::one() &&
+							config.start_incentive <= market.liquidate_incentive,
+						Error::<T>::InvalidFactor
+					);
+					LiquidationAuctionConfigs::<T>::insert(asset_id, config);
+				},
+				None => LiquidationAuctionConfigs::<T>::remove(asset_id),
+			}
+
+			Self::deposit_event(Event::<T>::LiquidationAuctionConfigUpdated { asset_id, config });
+			Ok(().into())
+		}
+
+		/// Starts a Dutch auction to liquidate a large borrow position.
+		///
+		/// Until the auction is closed, the position is liquidated at an incentive growing
+		/// every block from the `start_incentive` of the auction parameters.
+		///
+		/// - `borrower`: the borrower to be liquidated
+		/// - `liquidation_asset_id`: the borrowed asset to be liquidated
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::start_liquidation_auction())]
+		#[transactional]
+		pub fn start_liquidation_auction(
+			origin: OriginFor<T>,
+			borrower: T::AccountId,
+			liquidation_asset_id: AssetIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::ensure_active_market(liquidation_asset_id)?;
+			let config = LiquidationAuctionConfigs::<T>::get(liquidation_asset_id)
+				.ok_or(Error::<T>::LiquidationAuctionNotEnabled)?;
+			ensure!(
+				!LiquidationAuctions::<T>::contains_key(liquidation_asset_id, &borrower),
+				Error::<T>::LiquidationAuctionAlreadyStarted
+			);
+			Self::accrue_interest(liquidation_asset_id)?;
+			ensure!(Self::is_liquidatable(&borrower)?, Error::<T>::InsufficientShortfall);
+			ensure!(
+				Self::borrowed_value(&borrower, liquidation_asset_id)? >= config.min_auction_value,
+				Error::<T>::InsufficientAuctionValue
+			);
+
+			Self::do_start_liquidation_auction(borrower, liquidation_asset_id);
+			Ok(().into())
+		}
+
+		/// Closes the liquidation auction of a position which can no longer be liquidated.
+		///
+		/// - `borrower`: the borrower of the auctioned position
+		/// - `liquidation_asset_id`: the borrowed asset of the auctioned position
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::close_liquidation_auction())]
+		#[transactional]
+		pub fn close_liquidation_auction(
+			origin: OriginFor<T>,
+			borrower: T::AccountId,
+			liquidation_asset_id: AssetIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(
+				LiquidationAuctions::<T>::contains_key(liquidation_asset_id, &borrower),
+				Error::<T>::LiquidationAuctionDoesNotExist
+			);
+			Self::accrue_interest(liquidation_asset_id)?;
+			ensure!(
+				Self::liquidation_auction_closable(&borrower, liquidation_asset_id)?,
+				Error::<T>::PositionStillLiquidatable
+			);
+
+			LiquidationAuctions::<T>::remove(liquidation_asset_id, &borrower);
+			Self::deposit_event(Event::<T>::LiquidationAuctionClosed {
+				borrower,
+				asset_id: liquidation_asset_id,
+			});
+			Ok(().into())
+		}
+
+		/// Writes off the debt of a borrower whose deposits are exhausted, collateral or not.
+		///
+		/// The loss is covered by the market reserves first, the rest is socialised across
+		/// the suppliers by lowering the exchange rate.
+		///
+		/// - `borrower`: the borrower without deposits
+		/// - `asset_id`: the borrowed asset to write off
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::write_off_bad_debt())]
+		#[transactional]
+		pub fn write_off_bad_debt(
+			origin: OriginFor<T>,
+			borrower: T::AccountId,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::ensure_active_market(asset_id)?;
+			ensure!(Self::deposits_exhausted(&borrower), Error::<T>::DepositsNotExhausted);
+			Self::accrue_interest(asset_id)?;
+			ensure!(
+				!Self::current_borrow_balance(&borrower, asset_id)?.is_zero(),
+				Error::<T>::NoBadDebt
+			);
+			Self::do_write_off_bad_debt(&borrower, asset_id)?;
+
+			Ok(().into())
+		}
 	}
 }
 
@@ -1265,6 +1706,113 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Returns the ratio of the liquidation threshold value of the account's collaterals to its
+	/// borrowed value, or `None` if the account has no borrows.
+	pub fn get_account_health_factor(
+		account: &T::AccountId,
+	) -> Result<Option<FixedU128>, DispatchError> {
+		let total_borrow_value = Self::total_borrowed_value(account)?;
+		if total_borrow_value.is_zero() {
+			return Ok(None);
+		}
+		let total_collateral_value = Self::total_liquidation_threshold_value(account)?;
+
+		Ok(Some(
+			total_collateral_value
+				.checked_div(&total_borrow_value)
+				.ok_or(ArithmeticError::Underflow)?,
+		))
+	}
+
+	/// Returns the supplies, borrows and accrued rewards of the account along with its health
+	/// factor, and the health factor projected `seconds_ahead` later assuming the current
+	/// supply rates, borrow rates and prices remain unchanged.
+	pub fn get_account_position(
+		account: &T::AccountId,
+		seconds_ahead: Timestamp,
+	) -> Result<AccountPosition<BalanceOf<T>>, DispatchError> {
+		let isolated_collateral = Self::isolated_collateral(account).map(|(asset_id, _)| asset_id);
+		let mut markets = Vec::new();
+		let mut projected_collateral_value = FixedU128::zero();
+		let mut projected_borrow_value = FixedU128::zero();
+		for (asset_id, market) in Self::active_markets() {
+			let deposits = AccountDeposits::<T>::get(asset_id, account);
+			let borrow_balance = Self::current_borrow_balance(account, asset_id)?;
+			if deposits.voucher_balance.is_zero() && borrow_balance.is_zero() {
+				continue;
+			}
+			let exchange_rate = Self::exchange_rate_stored(asset_id)?;
+			let supply_balance =
+				Self::calc_underlying_amount(deposits.voucher_balance, exchange_rate)?;
+			let supply_rate = SupplyRate::<T>::get(asset_id);
+			let borrow_rate = BorrowRate::<T>::get(asset_id);
+
+			// Only the isolated collateral counts for an account in isolation mode.
+			if deposits.is_collateral &&
+				!supply_balance.is_zero() &&
+				isolated_collateral.map_or(true, |id| id == asset_id)
+			{
+				let projected_supply =
+					Self::accrued_interest(supply_rate, supply_balance, seconds_ahead)
+						.and_then(|r| r.checked_add(supply_balance))
+						.ok_or(ArithmeticError::Overflow)?;
+				let (_, liquidation_threshold) =
+					Self::collateral_factors(account, asset_id, &market);
+				projected_collateral_value = Self::get_asset_value(
+					asset_id,
+					liquidation_threshold.mul_ceil(projected_supply),
+				)?
+				.checked_add(&projected_collateral_value)
+				.ok_or(ArithmeticError::Overflow)?;
+			}
+			if !borrow_balance.is_zero() {
+				let projected_borrow =
+					Self::accrued_interest(borrow_rate, borrow_balance, seconds_ahead)
+						.and_then(|r| r.checked_add(borrow_balance))
+						.ok_or(ArithmeticError::Overflow)?;
+				projected_borrow_value = Self::get_asset_value(asset_id, projected_borrow)?
+					.checked_add(&projected_borrow_value)
+					.ok_or(ArithmeticError::Overflow)?;
+			}
+
+			markets.push(MarketPosition {
+				asset_id,
+				voucher_balance: deposits.voucher_balance,
+				supply_balance,
+				is_collateral: deposits.is_collateral,
+				borrow_balance,
+				supply_rate,
+				borrow_rate,
+			});
+		}
+
+		let projected_health_factor = if projected_borrow_value.is_zero() {
+			None
+		} else {
+			Some(
+				projected_collateral_value
+					.checked_div(&projected_borrow_value)
+					.ok_or(ArithmeticError::Underflow)?,
+			)
+		};
+
+		// The rewards not distributed to the account yet are included, the storage changes of
+		// distributing them are discarded.
+		let reward_accrued = with_transaction(|| {
+			let reward_accrued = Markets::<T>::iter_keys()
+				.try_for_each(|asset_id| Self::collect_market_reward(asset_id, account))
+				.map(|_| RewardAccrued::<T>::get(account));
+			TransactionOutcome::Rollback(reward_accrued)
+		})?;
+
+		Ok(AccountPosition {
+			markets,
+			reward_accrued,
+			health_factor: Self::get_account_health_factor(account)?,
+			projected_health_factor,
+		})
+	}
+
 	fn total_borrowed_value(borrower: &T::AccountId) -> Result<FixedU128, DispatchError> {
 		let mut total_borrow_value: FixedU128 = FixedU128::zero();
 		for (asset_id, _) in Self::active_markets() {
@@ -1298,7 +1846,8 @@ impl<T: Config> Pallet<T> {
 		let underlying_amount =
 			Self::calc_underlying_amount(deposits.voucher_balance, exchange_rate)?;
 		let market = Self::market(asset_id)?;
-		let effects_amount = market.collateral_factor.mul_ceil(underlying_amount);
+		let (collateral_factor, _) = Self::collateral_factors(supplier, asset_id, &market);
+		let effects_amount = collateral_factor.mul_ceil(underlying_amount);
 
 		Ok(BalanceOf::<T>::saturated_from(effects_amount))
 	}
@@ -1330,12 +1879,17 @@ impl<T: Config> Pallet<T> {
 		let underlying_amount =
 			Self::calc_underlying_amount(deposits.voucher_balance, exchange_rate)?;
 		let market = Self::market(asset_id)?;
-		let effects_amount = market.liquidation_threshold.mul_ceil(underlying_amount);
+		let (_, liquidation_threshold) = Self::collateral_factors(borrower, asset_id, &market);
+		let effects_amount = liquidation_threshold.mul_ceil(underlying_amount);
 
 		Self::get_asset_value(asset_id, effects_amount)
 	}
 
 	fn total_collateral_value(supplier: &T::AccountId) -> Result<FixedU128, DispatchError> {
+		// Only the isolated collateral counts for an account in isolation mode.
+		if let Some((asset_id, _)) = Self::isolated_collateral(supplier) {
+			return Self::collateral_asset_value(supplier, asset_id);
+		}
 		let mut total_asset_value: FixedU128 = FixedU128::zero();
 		for (asset_id, _market) in Self::active_markets() {
 			total_asset_value = total_asset_value
@@ -1346,9 +1900,18 @@ impl<T: Config> Pallet<T> {
 		Ok(total_asset_value)
 	}
 
+	/// Whether the account has no deposit left in any market, used as collateral or not
+	fn deposits_exhausted(account: &T::AccountId) -> bool {
+		Markets::<T>::iter_keys()
+			.all(|asset_id| AccountDeposits::<T>::get(asset_id, account).voucher_balance.is_zero())
+	}
+
 	fn total_liquidation_threshold_value(
 		borrower: &T::AccountId,
 	) -> Result<FixedU128, DispatchError> {
+		if let Some((asset_id, _)) = Self::isolated_collateral(borrower) {
+			return Self::liquidation_threshold_asset_value(borrower, asset_id);
+		}
 		let mut total_asset_value: FixedU128 = FixedU128::zero();
 		for (asset_id, _market) in Self::active_markets() {
 			total_asset_value = total_asset_value
@@ -1386,7 +1949,8 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let market = Self::market(asset_id)?;
-		let effects_amount = market.collateral_factor.mul_ceil(redeem_amount);
+		let (collateral_factor, _) = Self::collateral_factors(redeemer, asset_id, &market);
+		let effects_amount = collateral_factor.mul_ceil(redeem_amount);
 		let redeem_effects_value = Self::get_asset_value(asset_id, effects_amount)?;
 		log::trace!(
 			target: "lend-market::redeem_allowed",
@@ -1410,6 +1974,7 @@ impl<T: Config> Pallet<T> {
 		asset_id: AssetIdOf<T>,
 		voucher_amount: BalanceOf<T>,
 	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::ensure_price_not_paused(who, asset_id)?;
 		Self::redeem_allowed(asset_id, who, voucher_amount)?;
 		Self::update_reward_supply_index(asset_id)?;
 		Self::distribute_supplier_reward(asset_id, who)?;
@@ -1519,6 +2084,7 @@ impl<T: Config> Pallet<T> {
 			},
 		);
 		TotalBorrows::<T>::insert(asset_id, total_borrows_new);
+		Self::decrease_isolated_debt(borrower, asset_id, repay_amount)?;
 
 		Ok(())
 	}
@@ -1569,6 +2135,20 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Whether the account can be liquidated
+	fn is_liquidatable(borrower: &T::AccountId) -> Result<bool, DispatchError> {
+		let (liquidity, shortfall, lf_liquidity, _) =
+			Self::get_account_liquidation_threshold_liquidity(borrower)?;
+
+		// C_other >= B_other + B_dot_over
+		// C_other >= B_other + max(B_dot - C_lf, 0)
+		// C_other + C_lf >= B_other + B_dot - B_dot + C_lf + max(B_dot - C_lf, 0)
+		// C_all - B_all >= max(0, C_lf - B_dot)
+		// C_all - B_all >= 0 && C_all - B_all >= max(0, C_lf - B_dot)
+		// shortfall == 0 && liquidity > lf_liquidity
+		Ok(!shortfall.is_zero() || liquidity < lf_liquidity)
+	}
+
 	/// Checks if the liquidation should be allowed to occur
 	fn liquidate_borrow_allowed(
 		borrower: &T::AccountId,
@@ -1584,16 +2164,7 @@ impl<T: Config> Pallet<T> {
 			repay_amount,
 			market
 		);
-		let (liquidity, shortfall, lf_liquidity, _) =
-			Self::get_account_liquidation_threshold_liquidity(borrower)?;
-
-		// C_other >= B_other + B_dot_over
-		// C_other >= B_other + max(B_dot - C_lf, 0)
-		// C_other + C_lf >= B_other + B_dot - B_dot + C_lf + max(B_dot - C_lf, 0)
-		// C_all - B_all >= max(0, C_lf - B_dot)
-		// C_all - B_all >= 0 && C_all - B_all >= max(0, C_lf - B_dot)
-		// shortfall == 0 && liquidity > lf_liquidity
-		if shortfall.is_zero() && liquidity >= lf_liquidity {
+		if !Self::is_liquidatable(borrower)? {
 			return Err(Error::<T>::InsufficientShortfall.into());
 		}
 
@@ -1638,16 +2209,27 @@ impl<T: Config> Pallet<T> {
 		liquidation_asset_id: AssetIdOf<T>,
 		repay_amount: BalanceOf<T>,
 		collateral_asset_id: AssetIdOf<T>,
-	) -> DispatchResult {
+	) -> Result<u32, DispatchError> {
 		Self::ensure_active_market(liquidation_asset_id)?;
 		Self::ensure_active_market(collateral_asset_id)?;
 
-		let market = Self::market(liquidation_asset_id)?;
+		let mut market = Self::market(liquidation_asset_id)?;
+		market.liquidate_incentive = Self::liquidate_incentive(
+			&borrower,
+			liquidation_asset_id,
+			collateral_asset_id,
+			&market,
+		);
 
 		if borrower == liquidator {
 			return Err(Error::<T>::LiquidatorIsBorrower.into());
 		}
 		Self::liquidate_borrow_allowed(&borrower, liquidation_asset_id, repay_amount, &market)?;
+		if let Some(incentive) =
+			Self::liquidation_auction_incentive(&borrower, liquidation_asset_id, &market)?
+		{
+			market.liquidate_incentive = incentive;
+		}
 
 		let deposits = AccountDeposits::<T>::get(collateral_asset_id, &borrower);
 		if !deposits.is_collateral {
@@ -1695,9 +2277,191 @@ impl<T: Config> Pallet<T> {
 			&market,
 		)?;
 
+		// The remaining debt can't be liquidated anymore once the deposits are exhausted
+		let mut write_offs: u32 = 0;
+		if Self::deposits_exhausted(&borrower) {
+			for (asset_id, _) in Self::active_markets() {
+				if write_offs >= MAX_BAD_DEBT_WRITE_OFFS {
+					break;
+				}
+				if !AccountBorrows::<T>::get(asset_id, &borrower).principal.is_zero() {
+					Self::accrue_interest(asset_id)?;
+					Self::do_write_off_bad_debt(&borrower, asset_id)?;
+					write_offs += 1;
+				}
+			}
+		}
+
+		if LiquidationAuctions::<T>::contains_key(liquidation_asset_id, &borrower) &&
+			Self::liquidation_auction_closable(&borrower, liquidation_asset_id)?
+		{
+			LiquidationAuctions::<T>::remove(liquidation_asset_id, &borrower);
+			Self::deposit_event(Event::<T>::LiquidationAuctionClosed {
+				borrower,
+				asset_id: liquidation_asset_id,
+			});
+		}
+
+		Ok(write_offs)
+	}
+
+	/// Weight of a liquidation writing off `write_offs` borrows of the liquidated account.
+	pub fn liquidate_borrow_weight(write_offs: u32) -> Weight {
+		T::WeightInfo::liquidate_borrow()
+			.saturating_add(T::WeightInfo::write_off_bad_debt().saturating_mul(write_offs.into()))
+	}
+
+	/// Writes off the borrow balance of `borrower` in `asset_id`.
+	///
+	/// `TotalReserves` absorbs the loss first, the rest lowers the exchange rate of the
+	/// suppliers, down to `MIN_EXCHANGE_RATE` at most. Whatever can't be absorbed stays in
+	/// `AccountBorrows`.
+	#[require_transactional]
+	fn do_write_off_bad_debt(borrower: &T::AccountId, asset_id: AssetIdOf<T>) -> DispatchResult {
+		let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
+		let exchange_rate_before = Self::exchange_rate_stored(asset_id)?;
+		Self::update_reward_borrow_index(asset_id)?;
+		Self::distribute_borrower_reward(asset_id, borrower)?;
+
+		let total_supply = TotalSupply::<T>::get(asset_id);
+		let total_cash = Self::get_total_cash(asset_id);
+		let total_borrows = TotalBorrows::<T>::get(asset_id);
+		let total_reserves = TotalReserves::<T>::get(asset_id);
+
+		let reserves_used = total_reserves.min(account_borrows);
+		// The suppliers' share of the pool can't drop below the minimum exchange rate
+		let max_socialised_amount = if total_supply.is_zero() {
+			account_borrows
+		} else {
+			total_cash
+				.checked_add(total_borrows)
+				.and_then(|r| r.checked_sub(total_reserves))
+				.ok_or(ArithmeticError::Overflow)?
+				.saturating_sub(
+					Rate::from_inner(MIN_EXCHANGE_RATE).saturating_mul_int(total_supply),
+				)
+		};
+		let socialised_amount =
+			account_borrows.saturating_sub(reserves_used).min(max_socialised_amount);
+		let written_off = reserves_used.saturating_add(socialised_amount);
+		let remaining_debt = account_borrows.saturating_sub(written_off);
+
+		TotalReserves::<T>::insert(asset_id, total_reserves.saturating_sub(reserves_used));
+		// total_borrows can be less than account_borrows, see `do_repay_borrow_with_amount`
+		TotalBorrows::<T>::insert(asset_id, total_borrows.saturating_sub(written_off));
+		AccountBorrows::<T>::insert(
+			asset_id,
+			borrower,
+			BorrowSnapshot {
+				principal: remaining_debt,
+				borrow_index: BorrowIndex::<T>::get(asset_id),
+			},
+		);
+		Self::decrease_isolated_debt(borrower, asset_id, written_off)?;
+
+		let exchange_rate_after = Self::exchange_rate_stored(asset_id)?;
+		ExchangeRate::<T>::insert(asset_id, exchange_rate_after);
+
+		Self::deposit_event(Event::<T>::BadDebtWrittenOff {
+			borrower: borrower.clone(),
+			asset_id,
+			reserves_used,
+			socialised_amount,
+			remaining_debt,
+			exchange_rate_before,
+			exchange_rate_after,
+		});
+
 		Ok(())
 	}
 
+	/// Accrues interest of both markets and liquidates the borrower's position.
+	fn liquidate_borrow_inner(
+		liquidator: T::AccountId,
+		borrower: T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+		repay_amount: BalanceOf<T>,
+		collateral_asset_id: AssetIdOf<T>,
+	) -> Result<u32, DispatchError> {
+		ensure!(
+			!LiquidationFreeCollaterals::<T>::get().contains(&collateral_asset_id),
+			Error::<T>::CollateralReserved
+		);
+		Self::ensure_price_not_paused(&borrower, liquidation_asset_id)?;
+		Self::accrue_interest(liquidation_asset_id)?;
+		Self::accrue_interest(collateral_asset_id)?;
+		Self::do_liquidate_borrow(
+			liquidator,
+			borrower,
+			liquidation_asset_id,
+			repay_amount,
+			collateral_asset_id,
+		)
+	}
+
+	/// The value of the borrower's debt in `asset_id`, in the unit of `min_auction_value`
+	fn borrowed_value(
+		borrower: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
+		Ok(Self::get_asset_value(asset_id, account_borrows)?.into_inner())
+	}
+
+	fn do_start_liquidation_auction(borrower: T::AccountId, liquidation_asset_id: AssetIdOf<T>) {
+		let start_block = frame_system::Pallet::<T>::block_number();
+		LiquidationAuctions::<T>::insert(liquidation_asset_id, &borrower, start_block);
+		Self::deposit_event(Event::<T>::LiquidationAuctionStarted {
+			borrower,
+			asset_id: liquidation_asset_id,
+			start_block,
+		});
+	}
+
+	/// The liquidation incentive of the running auction of a position, `None` if the position
+	/// is liquidated at the fixed incentive of the market.
+	///
+	/// Positions above `min_auction_value` are only liquidated through an auction, the first
+	/// liquidation starts it at the `start_incentive` if nobody did before.
+	fn liquidation_auction_incentive(
+		borrower: &T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+		market: &Market<BalanceOf<T>>,
+	) -> Result<Option<Rate>, DispatchError> {
+		let Some(config) = LiquidationAuctionConfigs::<T>::get(liquidation_asset_id) else {
+			return Ok(None);
+		};
+		match LiquidationAuctions::<T>::get(liquidation_asset_id, borrower) {
+			Some(start_block) => {
+				let elapsed_blocks = frame_system::Pallet::<T>::block_number()
+					.saturating_sub(start_block)
+					.saturated_into::<u128>();
+				let incentive = config
+					.incentive_step
+					.saturating_mul(FixedU128::saturating_from_integer(elapsed_blocks))
+					.saturating_add(config.start_incentive);
+				Ok(Some(incentive.min(market.liquidate_incentive)))
+			},
+			None => {
+				if Self::borrowed_value(borrower, liquidation_asset_id)? < config.min_auction_value
+				{
+					return Ok(None);
+				}
+				Self::do_start_liquidation_auction(borrower.clone(), liquidation_asset_id);
+				Ok(Some(config.start_incentive.min(market.liquidate_incentive)))
+			},
+		}
+	}
+
+	/// An auction can be closed once the position is repaid or no longer liquidatable
+	fn liquidation_auction_closable(
+		borrower: &T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+	) -> Result<bool, DispatchError> {
+		Ok(Self::current_borrow_balance(borrower, liquidation_asset_id)?.is_zero() ||
+			!Self::is_liquidatable(borrower)?)
+	}
+
 	#[require_transactional]
 	fn liquidated_transfer(
 		liquidator: &T::AccountId,
@@ -1750,6 +2514,7 @@ impl<T: Config> Pallet<T> {
 			},
 		);
 		TotalBorrows::<T>::insert(liquidation_asset_id, total_borrows_new);
+		Self::decrease_isolated_debt(borrower, liquidation_asset_id, repay_amount)?;
 
 		// update supply index before modify supply balance.
 		Self::update_reward_supply_index(collateral_asset_id)?;
@@ -1927,6 +2692,184 @@ impl<T: Config> Pallet<T> {
 		Err(Error::<T>::InsufficientLiquidity.into())
 	}
 
+	// Returns the e-mode category the account opted in, if any.
+	fn account_emode_category(account: &T::AccountId) -> Option<EModeCategory> {
+		AccountEMode::<T>::get(account).and_then(EModeCategories::<T>::get)
+	}
+
+	// Returns the `(collateral_factor, liquidation_threshold)` applied to the `asset_id`
+	// collateral of the account, the ones of its e-mode category if the asset belongs to it.
+	fn collateral_factors(
+		account: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		market: &Market<BalanceOf<T>>,
+	) -> (Ratio, Ratio) {
+		match Self::account_emode_category(account) {
+			Some(category) if category.assets.contains(&asset_id) =>
+				(category.collateral_factor, category.liquidation_threshold),
+			_ => (market.collateral_factor, market.liquidation_threshold),
+		}
+	}
+
+	// Returns the liquidation incentive of the borrower, the one of its e-mode category if both
+	// the debt and the collateral belong to it.
+	fn liquidate_incentive(
+		borrower: &T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+		collateral_asset_id: AssetIdOf<T>,
+		market: &Market<BalanceOf<T>>,
+	) -> Rate {
+		match Self::account_emode_category(borrower) {
+			Some(category)
+				if category.assets.contains(&liquidation_asset_id) &&
+					category.assets.contains(&collateral_asset_id) =>
+				category.liquidate_incentive,
+			_ => market.liquidate_incentive,
+		}
+	}
+
+	// Ensures that neither the asset nor the collaterals and borrows of the account are paused by
+	// the price circuit breaker.
+	fn ensure_price_not_paused(account: &T::AccountId, asset_id: AssetIdOf<T>) -> DispatchResult {
+		let paused = T::OraclePriceProvider::is_price_paused(&asset_id) ||
+			Self::active_markets().any(|(id, _)| {
+				T::OraclePriceProvider::is_price_paused(&id) &&
+					(AccountDeposits::<T>::get(id, account).is_collateral ||
+						!AccountBorrows::<T>::get(id, account).principal.is_zero())
+			});
+		ensure!(!paused, Error::<T>::PricePaused);
+
+		Ok(())
+	}
+
+	// Ensures that an account in e-mode only borrows the assets of its category.
+	fn ensure_emode_borrow(borrower: &T::AccountId, asset_id: AssetIdOf<T>) -> DispatchResult {
+		if let Some(category) = Self::account_emode_category(borrower) {
+			ensure!(category.assets.contains(&asset_id), Error::<T>::NotBorrowableInEMode);
+		}
+
+		Ok(())
+	}
+
+	// Returns the isolated asset the account uses as collateral, if any.
+	fn isolated_collateral(
+		account: &T::AccountId,
+	) -> Option<(AssetIdOf<T>, IsolationMode<BalanceOf<T>>)> {
+		Self::active_markets().find_map(|(asset_id, market)| match market.isolation {
+			Some(isolation) if AccountDeposits::<T>::get(asset_id, account).is_collateral =>
+				Some((asset_id, isolation)),
+			_ => None,
+		})
+	}
+
+	// Returns the debt value of the accounts using the asset as collateral and the number of
+	// positions checked, ensuring that their positions are allowed under the isolation mode.
+	fn existing_isolated_debt(
+		asset_id: AssetIdOf<T>,
+		isolation: &IsolationMode<BalanceOf<T>>,
+		positions_witness: u32,
+	) -> Result<(BalanceOf<T>, u32), DispatchError> {
+		let markets: Vec<AssetIdOf<T>> = Markets::<T>::iter_keys().collect();
+		let mut isolated_debt: BalanceOf<T> = Zero::zero();
+		let mut positions: u32 = 0;
+		for (account, deposits) in AccountDeposits::<T>::iter_prefix(asset_id) {
+			// A deposit that isn't collateral is a single position, a collateral one is
+			// checked against every market.
+			let checked = if deposits.is_collateral { markets.len() as u32 } else { 1 };
+			positions = positions.saturating_add(checked);
+			ensure!(positions <= positions_witness, Error::<T>::InvalidPositionsWitness);
+			if !deposits.is_collateral {
+				continue;
+			}
+			for market_id in markets.iter().copied() {
+				ensure!(
+					market_id == asset_id ||
+						!AccountDeposits::<T>::get(market_id, &account).is_collateral,
+					Error::<T>::IsolatedCollateralConflict
+				);
+				let borrows = Self::current_borrow_balance(&account, market_id)?;
+				if borrows.is_zero() {
+					continue;
+				}
+				ensure!(
+					isolation.borrowable_assets.contains(&market_id),
+					Error::<T>::NotBorrowableInIsolationMode
+				);
+				let borrow_value = Self::get_asset_value(market_id, borrows)?.into_inner();
+				isolated_debt =
+					isolated_debt.checked_add(borrow_value).ok_or(ArithmeticError::Overflow)?;
+			}
+		}
+
+		Ok((isolated_debt, positions))
+	}
+
+	// Ensures that an isolated asset is never used as collateral together with other assets.
+	fn ensure_isolation_mode_collateral(
+		account: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		market: &Market<BalanceOf<T>>,
+	) -> DispatchResult {
+		if market.isolation.is_some() {
+			ensure!(
+				!Self::active_markets().any(|(id, _)| {
+					id != asset_id && AccountDeposits::<T>::get(id, account).is_collateral
+				}),
+				Error::<T>::IsolatedCollateralConflict
+			);
+		} else {
+			ensure!(
+				Self::isolated_collateral(account).is_none(),
+				Error::<T>::IsolatedCollateralConflict
+			);
+		}
+
+		Ok(())
+	}
+
+	// Ensures that an account in isolation mode only borrows the allowed assets and the
+	// debt backed by the isolated collateral stays under the debt ceiling, then records the
+	// new debt.
+	fn increase_isolated_debt(
+		borrower: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		borrow_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if let Some((isolated_asset_id, isolation)) = Self::isolated_collateral(borrower) {
+			ensure!(
+				isolation.borrowable_assets.contains(&asset_id),
+				Error::<T>::NotBorrowableInIsolationMode
+			);
+			let borrow_value = Self::get_asset_value(asset_id, borrow_amount)?.into_inner();
+			let isolated_debt_new = IsolatedDebt::<T>::get(isolated_asset_id)
+				.checked_add(borrow_value)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(isolated_debt_new <= isolation.debt_ceiling, Error::<T>::DebtCeilingExceeded);
+			IsolatedDebt::<T>::insert(isolated_asset_id, isolated_debt_new);
+		}
+
+		Ok(())
+	}
+
+	// Reduces the debt backed by the isolated collateral of the borrower after a repayment.
+	//
+	// The debt is recorded by value at borrowing time, so it saturates at zero once the
+	// repayments with interest or price changes exceed it.
+	fn decrease_isolated_debt(
+		borrower: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		repay_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if let Some((isolated_asset_id, _)) = Self::isolated_collateral(borrower) {
+			let repay_value = Self::get_asset_value(asset_id, repay_amount)?.into_inner();
+			IsolatedDebt::<T>::mutate(isolated_asset_id, |debt| {
+				*debt = debt.saturating_sub(repay_value);
+			});
+		}
+
+		Ok(())
+	}
+
 	pub fn calc_underlying_amount(
 		voucher_amount: BalanceOf<T>,
 		exchange_rate: Rate,
@@ -2112,10 +3055,13 @@ impl<T: Config> LendMarketTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for
 		amount: BalanceOf<T>,
 	) -> Result<(), DispatchError> {
 		Self::ensure_active_market(asset_id)?;
+		Self::ensure_price_not_paused(borrower, asset_id)?;
 
 		Self::accrue_interest(asset_id)?;
 		Self::borrow_allowed_for_market_bond(asset_id, borrower, amount)?;
 		Self::borrow_allowed(asset_id, borrower, amount)?;
+		Self::ensure_emode_borrow(borrower, asset_id)?;
+		Self::increase_isolated_debt(borrower, asset_id, amount)?;
 
 		// update borrow index after accrue interest.
 		Self::update_reward_borrow_index(asset_id)?;
@@ -2152,11 +3098,12 @@ impl<T: Config> LendMarketTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for
 		asset_id: AssetIdOf<T>,
 		enable: bool,
 	) -> Result<(), DispatchError> {
-		Self::ensure_active_market(asset_id)?;
+		let market = Self::ensure_active_market(asset_id)?;
 		ensure!(AccountDeposits::<T>::contains_key(asset_id, supplier), Error::<T>::NoDeposit);
 		let mut deposits = AccountDeposits::<T>::get(asset_id, supplier);
 		// turn on the collateral button
 		if enable {
+			Self::ensure_isolation_mode_collateral(supplier, asset_id, &market)?;
 			deposits.is_collateral = true;
 			AccountDeposits::<T>::insert(asset_id, supplier, deposits);
 			Self::deposit_event(Event::<T>::CollateralAssetAdded(supplier.clone(), asset_id));
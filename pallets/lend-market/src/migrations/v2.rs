@@ -0,0 +1,118 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::StorageVersion,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "lend-market::migration";
+
+/// Market before the isolation mode was introduced.
+#[derive(Clone, Decode, Encode, RuntimeDebug)]
+pub struct OldMarket<Balance> {
+	pub collateral_factor: Ratio,
+	pub liquidation_threshold: Ratio,
+	pub reserve_factor: Ratio,
+	pub close_factor: Ratio,
+	pub liquidate_incentive: Rate,
+	pub liquidate_incentive_reserved_factor: Ratio,
+	pub rate_model: InterestRateModel,
+	pub state: MarketState,
+	pub supply_cap: Balance,
+	pub borrow_cap: Balance,
+	pub lend_token_id: CurrencyId,
+}
+
+impl<Balance> From<OldMarket<Balance>> for Market<Balance> {
+	fn from(old: OldMarket<Balance>) -> Self {
+		Market {
+			collateral_factor: old.collateral_factor,
+			liquidation_threshold: old.liquidation_threshold,
+			reserve_factor: old.reserve_factor,
+			close_factor: old.close_factor,
+			liquidate_incentive: old.liquidate_incentive,
+			liquidate_incentive_reserved_factor: old.liquidate_incentive_reserved_factor,
+			rate_model: old.rate_model,
+			state: old.state,
+			supply_cap: old.supply_cap,
+			borrow_cap: old.borrow_cap,
+			lend_token_id: old.lend_token_id,
+			isolation: None,
+		}
+	}
+}
+
+pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		// Check the storage version
+		let onchain_version = Pallet::<T>::on_chain_storage_version();
+		if onchain_version == 1 {
+			log::info!(target: LOG_TARGET, "Start to migrate Markets storage...");
+			let mut markets_count = 0u64;
+			Markets::<T>::translate::<OldMarket<BalanceOf<T>>, _>(
+				|k: AssetIdOf<T>, old: OldMarket<BalanceOf<T>>| {
+					log::info!(target: LOG_TARGET, "Migrated market for {:?}...", k);
+					markets_count += 1;
+					Some(old.into())
+				},
+			);
+
+			// Update the storage version
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			// Return the consumed weight
+			Weight::from(T::DbWeight::get().reads_writes(markets_count + 1, markets_count + 1))
+		} else {
+			// We don't do anything here.
+			Weight::zero()
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		let markets_count = Markets::<T>::iter_keys().count();
+
+		// print out the pre-migrate storage count
+		log::info!(target: LOG_TARGET, "Markets pre-migrate storage count: {:?}", markets_count);
+		Ok((markets_count as u64).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(cnt: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let new_markets_count = Markets::<T>::iter().count();
+
+		let old_markets_count: u64 = Decode::decode(&mut cnt.as_slice())
+			.expect("the state parameter should be something that was generated by pre_upgrade");
+
+		// print out the post-migrate storage count
+		log::info!(target: LOG_TARGET, "Markets post-migrate storage count: {:?}", new_markets_count);
+
+		ensure!(
+			new_markets_count as u64 == old_markets_count,
+			"Markets Post-migration storage count does not match pre-migration count"
+		);
+
+		Ok(())
+	}
+}
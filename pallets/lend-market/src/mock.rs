@@ -29,7 +29,7 @@ use sp_runtime::{traits::IdentityLookup, AccountId32};
 use sp_std::vec::Vec;
 use std::{
 	cell::RefCell,
-	collections::HashMap,
+	collections::{BTreeMap, HashMap},
 	hash::{Hash, Hasher},
 };
 
@@ -223,6 +223,7 @@ impl MockOraclePriceProvider {
 					.collect()
 			)
 		};
+		pub static PAUSED: RefCell<Vec<CurrencyId>> = RefCell::new(vec![]);
 	}
 
 	pub fn set_price(asset_id: CurrencyId, price: Price) {
@@ -231,6 +232,16 @@ impl MockOraclePriceProvider {
 		});
 	}
 
+	pub fn set_paused(asset_id: CurrencyId, paused: bool) {
+		Self::PAUSED.with(|assets| {
+			let mut assets = assets.borrow_mut();
+			assets.retain(|&id| id != asset_id);
+			if paused {
+				assets.push(asset_id);
+			}
+		});
+	}
+
 	pub fn reset() {
 		Self::PRICES.with(|prices| {
 			for (_, val) in prices.borrow_mut().iter_mut() {
@@ -262,6 +273,84 @@ impl OraclePriceProvider for MockOraclePriceProvider {
 	) -> Option<(Balance, Price, Price)> {
 		todo!()
 	}
+
+	fn is_price_paused(asset_id: &CurrencyId) -> bool {
+		Self::PAUSED.with(|assets| assets.borrow().contains(asset_id))
+	}
+}
+
+pub struct MockVtokenMinting;
+
+impl MockVtokenMinting {
+	thread_local! {
+		pub static EXCHANGE_RATES: RefCell<BTreeMap<CurrencyId, Rate>> = RefCell::new(BTreeMap::new());
+	}
+
+	pub fn set_exchange_rate(vtoken_id: CurrencyId, exchange_rate: Rate) {
+		Self::EXCHANGE_RATES.with(|rates| {
+			rates.borrow_mut().insert(vtoken_id, exchange_rate);
+		});
+	}
+}
+
+impl VtokenMintingInterface<AccountId, CurrencyId, Balance> for MockVtokenMinting {
+	fn mint(
+		_exchanger: AccountId,
+		_token_id: CurrencyId,
+		_token_amount: Balance,
+		_remark: BoundedVec<u8, ConstU32<32>>,
+		_channel_id: Option<u32>,
+	) -> Result<Balance, DispatchError> {
+		todo!()
+	}
+
+	fn redeem(
+		_exchanger: AccountId,
+		_vtoken_id: CurrencyId,
+		_vtoken_amount: Balance,
+	) -> DispatchResultWithPostInfo {
+		todo!()
+	}
+
+	fn slpx_redeem(
+		_exchanger: AccountId,
+		_vtoken_id: CurrencyId,
+		_vtoken_amount: Balance,
+		_redeem: RedeemType<AccountId>,
+	) -> DispatchResultWithPostInfo {
+		todo!()
+	}
+
+	fn get_v_currency_amount_by_currency_amount(
+		_token_id: CurrencyId,
+		_vtoken_id: CurrencyId,
+		_token_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		todo!()
+	}
+
+	fn get_currency_amount_by_v_currency_amount(
+		_token_id: CurrencyId,
+		vtoken_id: CurrencyId,
+		vtoken_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Self::EXCHANGE_RATES.with(|rates| {
+			let exchange_rate = rates.borrow().get(&vtoken_id).copied().unwrap_or_default();
+			Ok(exchange_rate.saturating_mul_int(vtoken_amount))
+		})
+	}
+
+	fn get_token_pool(_currency_id: CurrencyId) -> Balance {
+		todo!()
+	}
+
+	fn get_minimums_redeem(_vtoken_id: CurrencyId) -> Balance {
+		todo!()
+	}
+
+	fn get_moonbeam_parachain_id() -> u32 {
+		todo!()
+	}
 }
 
 parameter_types! {
@@ -302,6 +391,11 @@ impl pallet_prices::Config for Test {
 	type RelayCurrency = RelayCurrency;
 	type Assets = Currencies;
 	type CurrencyIdConvert = AssetIdMaps<Test>;
+	type PriceSources = ();
+	type UnixTime = TimestampPallet;
+	type MaxAggregatedAssets = ConstU32<10>;
+	type MaxPriceObservations = ConstU32<10>;
+	type MaxCircuitBreakerAssets = ConstU32<10>;
 	type WeightInfo = ();
 }
 
@@ -323,6 +417,7 @@ impl Config for Test {
 	type RewardAssetId = RewardAssetId;
 	type LiquidationFreeAssetId = LiquidationFreeAssetId;
 	type MaxLengthLimit = MaxLengthLimit;
+	type VtokenMinting = MockVtokenMinting;
 }
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
@@ -477,6 +572,7 @@ pub const fn market_mock(lend_token_id: CurrencyId) -> Market<Balance> {
 		supply_cap: 1_000_000_000_000_000_000_000u128, // set to 1B
 		borrow_cap: 1_000_000_000_000_000_000_000u128, // set to 1B
 		lend_token_id,
+		isolation: None,
 	}
 }
 
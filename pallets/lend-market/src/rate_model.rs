@@ -24,6 +24,7 @@ use crate::*;
 pub enum InterestRateModel {
 	Jump(JumpModel),
 	Curve(CurveModel),
+	StakingYield(StakingYieldModel),
 }
 
 impl Default for InterestRateModel {
@@ -51,18 +52,39 @@ impl InterestRateModel {
 		Self::Curve(CurveModel::new_model(base_rate))
 	}
 
+	pub fn new_staking_yield_model(
+		base_spread: Rate,
+		full_spread: Rate,
+		max_staking_yield: Rate,
+	) -> Self {
+		Self::StakingYield(StakingYieldModel::new_model(
+			base_spread,
+			full_spread,
+			max_staking_yield,
+		))
+	}
+
 	pub fn check_model(&self) -> bool {
 		match self {
 			Self::Jump(jump) => jump.check_model(),
 			Self::Curve(curve) => curve.check_model(),
+			Self::StakingYield(staking_yield) => staking_yield.check_model(),
 		}
 	}
 
+	/// Whether the borrow rate depends on the staking yield of the market asset
+	pub fn is_staking_yield_model(&self) -> bool {
+		matches!(self, Self::StakingYield(_))
+	}
+
 	/// Calculates the current borrow interest rate
-	pub fn get_borrow_rate(&self, utilization: Ratio) -> Option<Rate> {
+	///
+	/// `staking_yield` is only taken into account by the staking yield model.
+	pub fn get_borrow_rate(&self, utilization: Ratio, staking_yield: Rate) -> Option<Rate> {
 		match self {
 			Self::Jump(jump) => jump.get_borrow_rate(utilization),
 			Self::Curve(curve) => curve.get_borrow_rate(utilization),
+			Self::StakingYield(model) => model.get_borrow_rate(utilization, staking_yield),
 		}
 	}
 
@@ -176,6 +198,59 @@ impl CurveModel {
 	}
 }
 
+/// The staking yield interest rate model
+///
+/// The borrow rate never drops below the realised staking APY of the underlying vToken, a
+/// spread growing linearly with utilization is charged on top of it.
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo)]
+pub struct StakingYieldModel {
+	/// The spread over the staking yield when utilization rate is 0
+	pub base_spread: Rate,
+	/// The spread over the staking yield when utilization rate is 100%
+	pub full_spread: Rate,
+	/// The staking yield taken into account is capped at this rate
+	pub max_staking_yield: Rate,
+}
+
+impl StakingYieldModel {
+	pub const MAX_BASE_SPREAD: Rate = Rate::from_inner(100_000_000_000_000_000); // 10%
+	pub const MAX_FULL_SPREAD: Rate = Rate::from_inner(500_000_000_000_000_000); // 50%
+	pub const MAX_STAKING_YIELD: Rate = Rate::from_inner(500_000_000_000_000_000); // 50%
+
+	/// Create a new staking yield model
+	pub fn new_model(
+		base_spread: Rate,
+		full_spread: Rate,
+		max_staking_yield: Rate,
+	) -> StakingYieldModel {
+		Self { base_spread, full_spread, max_staking_yield }
+	}
+
+	/// Check the staking yield model for sanity
+	pub fn check_model(&self) -> bool {
+		if self.base_spread > Self::MAX_BASE_SPREAD ||
+			self.full_spread > Self::MAX_FULL_SPREAD ||
+			self.max_staking_yield > Self::MAX_STAKING_YIELD
+		{
+			return false;
+		}
+
+		self.base_spread <= self.full_spread
+	}
+
+	/// Calculates the borrow interest rate of staking yield model
+	pub fn get_borrow_rate(&self, utilization: Ratio, staking_yield: Rate) -> Option<Rate> {
+		// min(staking_yield, max_staking_yield) + base_spread +
+		// utilization * (full_spread - base_spread)
+		self.full_spread
+			.checked_sub(&self.base_spread)?
+			.saturating_mul(utilization.into())
+			.checked_add(&self.base_spread)?
+			.checked_add(&staking_yield.min(self.max_staking_yield))
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -259,4 +334,36 @@ mod tests {
 			Rate::from_inner(154217728000000000)
 		);
 	}
+
+	#[test]
+	fn staking_yield_model_correctly_calculates_borrow_rate() {
+		let model = StakingYieldModel::new_model(
+			Rate::saturating_from_rational(1, 100),
+			Rate::saturating_from_rational(21, 100),
+			Rate::saturating_from_rational(30, 100),
+		);
+		assert!(model.check_model());
+
+		// 15% staking yield + 1% base spread + 50% * 20%
+		assert_eq!(
+			model
+				.get_borrow_rate(Ratio::from_percent(50), Rate::saturating_from_rational(15, 100))
+				.unwrap(),
+			Rate::saturating_from_rational(26, 100)
+		);
+		// staking yield is capped at 30%
+		assert_eq!(
+			model
+				.get_borrow_rate(Ratio::zero(), Rate::saturating_from_rational(80, 100))
+				.unwrap(),
+			Rate::saturating_from_rational(31, 100)
+		);
+		// base spread must not exceed full spread
+		assert!(!StakingYieldModel::new_model(
+			Rate::saturating_from_rational(5, 100),
+			Rate::saturating_from_rational(4, 100),
+			Rate::zero(),
+		)
+		.check_model());
+	}
 }
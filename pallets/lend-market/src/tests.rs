@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bad_debt;
 mod edge_cases;
+mod emode;
 mod interest_rate;
+mod isolation_mode;
 mod lend_tokens;
 mod liquidate_borrow;
 mod market;
@@ -666,6 +669,111 @@ fn get_account_liquidation_threshold_liquidity_works() {
 	})
 }
 
+#[test]
+fn get_account_position_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LendMarket::add_market_bond(
+			RuntimeOrigin::root(),
+			KSM,
+			vec![DOT, BNC, KSM, DOT_U, PHA]
+		));
+		LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)).unwrap();
+		LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT_U, unit(220)).unwrap();
+		LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT_U, true).unwrap();
+		LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)).unwrap();
+		BorrowRate::<Test>::insert(KSM, Rate::saturating_from_rational(10, 100));
+		SupplyRate::<Test>::insert(DOT_U, Rate::zero());
+
+		let position =
+			LendMarket::get_account_position(&ALICE, bifrost_primitives::SECONDS_PER_YEAR).unwrap();
+		assert_eq!(position.markets.len(), 2);
+		let dot_u = position.markets.iter().find(|m| m.asset_id == DOT_U).unwrap();
+		assert_eq!(dot_u.voucher_balance, unit(11000));
+		assert_eq!(dot_u.supply_balance, unit(220));
+		assert!(dot_u.is_collateral);
+		assert_eq!(dot_u.borrow_balance, 0);
+		let ksm = position.markets.iter().find(|m| m.asset_id == KSM).unwrap();
+		assert_eq!(ksm.supply_balance, 0);
+		assert_eq!(ksm.borrow_balance, unit(100));
+		assert_eq!(ksm.borrow_rate, Rate::saturating_from_rational(10, 100));
+		assert_eq!(position.reward_accrued, 0);
+		// 220 * 0.55 / 100 = 1.21
+		assert_eq!(position.health_factor, Some(FixedU128::saturating_from_rational(121, 100)));
+		// The debt grows to 110 KSM within a year: 220 * 0.55 / 110 = 1.1
+		assert_eq!(
+			position.projected_health_factor,
+			Some(FixedU128::saturating_from_rational(11, 10))
+		);
+
+		let position = LendMarket::get_account_position(&BOB, 0).unwrap();
+		assert_eq!(position.markets.len(), 1);
+		assert_eq!(position.health_factor, None);
+		assert_eq!(position.projected_health_factor, None);
+	})
+}
+
+#[test]
+fn get_account_position_includes_undistributed_reward() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LendMarket::add_market_bond(
+			RuntimeOrigin::root(),
+			DOT,
+			vec![DOT, BNC, KSM, DOT_U, PHA]
+		));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+
+		_run_to_block(10);
+		assert_ok!(LendMarket::update_market_reward_speed(
+			RuntimeOrigin::root(),
+			DOT,
+			Some(unit(1)),
+			None,
+		));
+
+		_run_to_block(20);
+		let position = LendMarket::get_account_position(&ALICE, 0).unwrap();
+		assert_eq!(position.reward_accrued, unit(10));
+		// The reward is not distributed by the query
+		assert_eq!(RewardAccrued::<Test>::get(ALICE), 0);
+		assert_eq!(RewardSupplyState::<Test>::get(DOT).block, 10);
+		assert_eq!(RewardSupplierIndex::<Test>::get(DOT, ALICE), 0);
+	})
+}
+
+#[test]
+fn paused_price_rejects_borrow_redeem_and_liquidate() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LendMarket::add_market_bond(
+			RuntimeOrigin::root(),
+			KSM,
+			vec![DOT, BNC, KSM, DOT_U, PHA]
+		));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT_U, unit(200)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT_U, true));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(50)));
+
+		MockOraclePriceProvider::set_paused(DOT_U, true);
+		assert_noop!(
+			LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(10)),
+			Error::<Test>::PricePaused
+		);
+		assert_noop!(
+			LendMarket::redeem(RuntimeOrigin::signed(ALICE), DOT_U, unit(10)),
+			Error::<Test>::PricePaused
+		);
+		assert_noop!(
+			LendMarket::liquidate_borrow(RuntimeOrigin::signed(BOB), ALICE, KSM, unit(10), DOT_U),
+			Error::<Test>::PricePaused
+		);
+		// The position of Bob doesn't depend on the price of DOT_U
+		assert_ok!(LendMarket::redeem(RuntimeOrigin::signed(BOB), KSM, unit(10)));
+
+		MockOraclePriceProvider::set_paused(DOT_U, false);
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(10)));
+	})
+}
+
 #[test]
 fn lf_borrow_allowed_works() {
 	new_test_ext().execute_with(|| {
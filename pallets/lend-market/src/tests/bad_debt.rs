@@ -0,0 +1,125 @@
+use crate::{
+	mock::*, tests::unit, AccountBorrows, AccountDeposits, Error, ExchangeRate, TotalBorrows,
+	TotalReserves,
+};
+use bifrost_primitives::Rate;
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::fungibles::Inspect};
+use sp_runtime::FixedPointNumber;
+
+fn bad_debt_setup() {
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), KSM, vec![DOT, BNC, KSM, DOT_U]));
+	// Bob deposits 200 KSM
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+	// Alice deposits 220 DOT_U as collateral and borrows 100 KSM
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT_U, unit(220)));
+	assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT_U, true));
+	assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+	assert_ok!(LendMarket::add_reserves(RuntimeOrigin::root(), BOB, KSM, unit(10)));
+}
+
+#[test]
+fn write_off_bad_debt_requires_exhausted_deposits() {
+	new_test_ext().execute_with(|| {
+		bad_debt_setup();
+		assert_noop!(
+			LendMarket::write_off_bad_debt(RuntimeOrigin::signed(BOB), ALICE, KSM),
+			BadOrigin
+		);
+		assert_noop!(
+			LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE, KSM),
+			Error::<Test>::DepositsNotExhausted
+		);
+
+		// Alice loses all her collateral, but still has a deposit which is not collateral
+		AccountDeposits::<Test>::mutate(DOT_U, ALICE, |deposits| deposits.voucher_balance = 0);
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(10)));
+		assert_noop!(
+			LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE, KSM),
+			Error::<Test>::DepositsNotExhausted
+		);
+		assert_noop!(
+			LendMarket::write_off_bad_debt(RuntimeOrigin::root(), DAVE, KSM),
+			Error::<Test>::NoBadDebt
+		);
+	})
+}
+
+#[test]
+fn bad_debt_is_written_off_once_collateral_is_exhausted() {
+	new_test_ext().execute_with(|| {
+		bad_debt_setup();
+		// Raise the exchange rate of KSM from 0.02 to 0.03, so that losses can be socialised
+		assert_ok!(<Test as Config>::Assets::transfer(
+			RuntimeOrigin::signed(BOB),
+			LendMarket::account_id(),
+			KSM,
+			unit(100),
+		));
+		assert_eq!(
+			LendMarket::exchange_rate_stored(KSM).unwrap(),
+			Rate::saturating_from_rational(3, 100)
+		);
+		MockOraclePriceProvider::set_price(KSM, 5.into());
+		// Bob seizes all the collateral of Alice: 40 * 5 * 1.1 = 220
+		assert_ok!(LendMarket::liquidate_borrow(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			KSM,
+			unit(40),
+			DOT_U
+		));
+		assert_eq!(AccountDeposits::<Test>::get(DOT_U, ALICE).voucher_balance, 0);
+
+		// The remaining 60 KSM of debt is written off:
+		// 10 KSM are covered by the reserves and 50 KSM are socialised
+		// exchange rate = (250 + 0 - 0) / 10000 = 0.025
+		assert_eq!(AccountBorrows::<Test>::get(KSM, ALICE).principal, 0);
+		assert_eq!(TotalBorrows::<Test>::get(KSM), 0);
+		assert_eq!(TotalReserves::<Test>::get(KSM), 0);
+		assert_eq!(ExchangeRate::<Test>::get(KSM), Rate::saturating_from_rational(25, 1000));
+		// Bob supplied 200 KSM, and gets 200 * 0.025 / 0.02 = 250 KSM back
+		// Bob KSM: 1000 - 200 - 10 - 100 - 40 + 250 = 900
+		assert_ok!(LendMarket::redeem_all(RuntimeOrigin::signed(BOB), KSM));
+		assert_eq!(<Test as Config>::Assets::balance(KSM, &BOB), unit(900));
+	})
+}
+
+#[test]
+fn socialised_loss_is_bounded_by_min_exchange_rate() {
+	new_test_ext().execute_with(|| {
+		bad_debt_setup();
+		// Alice loses all her collateral
+		AccountDeposits::<Test>::mutate(DOT_U, ALICE, |deposits| deposits.voucher_balance = 0);
+
+		// The exchange rate is already at its minimum of 0.02, only the 10 KSM of reserves can
+		// absorb the loss
+		assert_ok!(LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE, KSM));
+		assert_eq!(AccountBorrows::<Test>::get(KSM, ALICE).principal, unit(90));
+		assert_eq!(TotalBorrows::<Test>::get(KSM), unit(90));
+		assert_eq!(TotalReserves::<Test>::get(KSM), 0);
+		assert_eq!(ExchangeRate::<Test>::get(KSM), Rate::saturating_from_rational(2, 100));
+	})
+}
+
+#[test]
+fn bad_debt_is_not_written_off_while_deposits_remain() {
+	new_test_ext().execute_with(|| {
+		bad_debt_setup();
+		// Alice also supplies 10 DOT without using it as collateral
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(10)));
+		MockOraclePriceProvider::set_price(KSM, 5.into());
+		// Bob seizes all the collateral of Alice: 40 * 5 * 1.1 = 220
+		assert_ok!(LendMarket::liquidate_borrow(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			KSM,
+			unit(40),
+			DOT_U
+		));
+		assert_eq!(AccountDeposits::<Test>::get(DOT_U, ALICE).voucher_balance, 0);
+
+		// The remaining 60 KSM of debt stays until the DOT deposit is used
+		assert_eq!(AccountBorrows::<Test>::get(KSM, ALICE).principal, unit(60));
+		assert_eq!(TotalReserves::<Test>::get(KSM), unit(10));
+	})
+}
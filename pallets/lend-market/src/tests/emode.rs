@@ -0,0 +1,151 @@
+use crate::{
+	mock::{new_test_ext, LendMarket, RuntimeOrigin, Test, ALICE, BOB, DAVE, DOT, DOT_U, KSM},
+	tests::unit,
+	AccountEMode, EModeCategory, EModeCategoryAccounts, Error,
+};
+use bifrost_primitives::{Rate, Ratio};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::FixedPointNumber;
+
+fn emode_setup() {
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), KSM, vec![DOT]));
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), DOT_U, vec![DOT]));
+	assert_ok!(LendMarket::update_emode_category(
+		RuntimeOrigin::root(),
+		1,
+		Some(EModeCategory {
+			collateral_factor: Ratio::from_percent(90),
+			liquidation_threshold: Ratio::from_percent(93),
+			liquidate_incentive: Rate::saturating_from_rational(105, 100),
+			assets: BoundedVec::truncate_from(vec![DOT, KSM]),
+		}),
+	));
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(DAVE), DOT_U, unit(200)));
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(100)));
+	assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT, true));
+}
+
+#[test]
+fn update_emode_category_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LendMarket::update_emode_category(
+				RuntimeOrigin::root(),
+				1,
+				Some(EModeCategory {
+					collateral_factor: Ratio::from_percent(95),
+					liquidation_threshold: Ratio::from_percent(93),
+					liquidate_incentive: Rate::saturating_from_rational(105, 100),
+					assets: BoundedVec::truncate_from(vec![DOT, KSM]),
+				}),
+			),
+			Error::<Test>::InvalidFactor
+		);
+		emode_setup();
+		assert_noop!(
+			LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), Some(2)),
+			Error::<Test>::EModeCategoryDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn emode_raises_collateral_factor() {
+	new_test_ext().execute_with(|| {
+		emode_setup();
+		assert_noop!(
+			LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(60)),
+			Error::<Test>::InsufficientLiquidity
+		);
+
+		assert_ok!(LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), Some(1)));
+		assert_eq!(AccountEMode::<Test>::get(ALICE), Some(1));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(80)));
+		assert_noop!(
+			LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT_U, unit(5)),
+			Error::<Test>::NotBorrowableInEMode
+		);
+
+		// Leaving e-mode would put the account in shortfall
+		assert_noop!(
+			LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), None),
+			Error::<Test>::InsufficientLiquidity
+		);
+	})
+}
+
+#[test]
+fn entering_emode_requires_borrows_in_category() {
+	new_test_ext().execute_with(|| {
+		emode_setup();
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT_U, unit(10)));
+		assert_noop!(
+			LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), Some(1)),
+			Error::<Test>::NotBorrowableInEMode
+		);
+	})
+}
+
+#[test]
+fn emode_category_in_use_cannot_be_lowered() {
+	new_test_ext().execute_with(|| {
+		emode_setup();
+		let category = EModeCategory {
+			collateral_factor: Ratio::from_percent(90),
+			liquidation_threshold: Ratio::from_percent(93),
+			liquidate_incentive: Rate::saturating_from_rational(110, 100),
+			assets: BoundedVec::truncate_from(vec![DOT, KSM]),
+		};
+		// 93% * 110% > 100%
+		assert_noop!(
+			LendMarket::update_emode_category(RuntimeOrigin::root(), 1, Some(category.clone())),
+			Error::<Test>::InvalidFactor
+		);
+
+		assert_ok!(LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), Some(1)));
+		assert_eq!(EModeCategoryAccounts::<Test>::get(1), 1);
+		assert_noop!(
+			LendMarket::update_emode_category(RuntimeOrigin::root(), 1, None),
+			Error::<Test>::EModeCategoryInUse
+		);
+		assert_noop!(
+			LendMarket::update_emode_category(
+				RuntimeOrigin::root(),
+				1,
+				Some(EModeCategory {
+					collateral_factor: Ratio::from_percent(80),
+					liquidate_incentive: Rate::saturating_from_rational(105, 100),
+					..category.clone()
+				}),
+			),
+			Error::<Test>::EModeCategoryInUse
+		);
+		assert_noop!(
+			LendMarket::update_emode_category(
+				RuntimeOrigin::root(),
+				1,
+				Some(EModeCategory {
+					liquidate_incentive: Rate::saturating_from_rational(105, 100),
+					assets: BoundedVec::truncate_from(vec![DOT]),
+					..category.clone()
+				}),
+			),
+			Error::<Test>::EModeCategoryInUse
+		);
+		assert_ok!(LendMarket::update_emode_category(
+			RuntimeOrigin::root(),
+			1,
+			Some(EModeCategory {
+				collateral_factor: Ratio::from_percent(91),
+				liquidate_incentive: Rate::saturating_from_rational(105, 100),
+				assets: BoundedVec::truncate_from(vec![DOT, KSM, DOT_U]),
+				..category
+			}),
+		));
+
+		assert_ok!(LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), None));
+		assert_eq!(EModeCategoryAccounts::<Test>::get(1), 0);
+		assert_ok!(LendMarket::update_emode_category(RuntimeOrigin::root(), 1, None));
+	})
+}
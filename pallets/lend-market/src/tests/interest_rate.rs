@@ -1,8 +1,8 @@
-use crate::{mock::*, tests::LendMarket, Markets};
+use crate::{mock::*, tests::LendMarket, Markets, StakingYield, StakingYieldSnapshot};
 use bifrost_primitives::{Rate, Ratio, SECONDS_PER_YEAR};
 use frame_support::assert_ok;
 use sp_runtime::{
-	traits::{CheckedDiv, One, Saturating},
+	traits::{CheckedDiv, One, Saturating, Zero},
 	FixedPointNumber,
 };
 
@@ -446,3 +446,50 @@ fn a_market_can_only_accrue_interest_once_in_a_block() {
 		assert_eq!(BorrowIndex::<Test>::get(DOT), Rate::from_inner(1000000003805175038),);
 	})
 }
+
+#[test]
+fn staking_yield_model_tracks_vtoken_exchange_rate() {
+	new_test_ext().execute_with(|| {
+		let mut market = market_mock(LVDOT);
+		market.rate_model = InterestRateModel::new_staking_yield_model(
+			Rate::saturating_from_rational(1, 100),
+			Rate::saturating_from_rational(21, 100),
+			Rate::saturating_from_rational(30, 100),
+		);
+		assert_ok!(LendMarket::add_market(RuntimeOrigin::root(), VDOT, market));
+		assert_ok!(LendMarket::activate_market(RuntimeOrigin::root(), VDOT));
+		MockVtokenMinting::set_exchange_rate(VDOT, Rate::one());
+
+		// The first accrual initializes the market, the second one takes the snapshot
+		assert_ok!(LendMarket::accrue_interest(VDOT));
+		TimestampPallet::set_timestamp(12000);
+		assert_ok!(LendMarket::accrue_interest(VDOT));
+		assert_eq!(StakingYieldSnapshot::<Test>::get(VDOT), Some((12, Rate::one())));
+		assert_eq!(StakingYield::<Test>::get(VDOT), Rate::zero());
+
+		// Not enough time elapsed to measure the yield
+		MockVtokenMinting::set_exchange_rate(VDOT, Rate::saturating_from_rational(101, 100));
+		TimestampPallet::set_timestamp(18000);
+		assert_ok!(LendMarket::accrue_interest(VDOT));
+		assert_eq!(StakingYieldSnapshot::<Test>::get(VDOT), Some((12, Rate::one())));
+
+		// 1% exchange rate growth over a tenth of a year is a 10% yield
+		let now = 12 + SECONDS_PER_YEAR / 10;
+		TimestampPallet::set_timestamp(now * 1000);
+		assert_ok!(LendMarket::accrue_interest(VDOT));
+		assert_eq!(StakingYield::<Test>::get(VDOT), Rate::saturating_from_rational(10, 100));
+		assert_eq!(
+			StakingYieldSnapshot::<Test>::get(VDOT),
+			Some((now, Rate::saturating_from_rational(101, 100)))
+		);
+		// No borrows, the borrow rate is the staking yield plus the base spread
+		assert_eq!(BorrowRate::<Test>::get(VDOT), Rate::saturating_from_rational(11, 100));
+
+		// A decreasing exchange rate yields nothing
+		MockVtokenMinting::set_exchange_rate(VDOT, Rate::one());
+		TimestampPallet::set_timestamp((now + STAKING_YIELD_PERIOD) * 1000);
+		assert_ok!(LendMarket::accrue_interest(VDOT));
+		assert_eq!(StakingYield::<Test>::get(VDOT), Rate::zero());
+		assert_eq!(BorrowRate::<Test>::get(VDOT), Rate::saturating_from_rational(1, 100));
+	})
+}
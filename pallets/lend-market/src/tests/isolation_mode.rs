@@ -0,0 +1,142 @@
+use crate::{
+	mock::{new_test_ext, LendMarket, RuntimeOrigin, Test, ALICE, DAVE, DOT, DOT_U, KSM},
+	tests::unit,
+	Error, IsolatedDebt, IsolationMode,
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+const POSITIONS_WITNESS: u32 = 100;
+
+fn isolate_ksm() {
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), DOT, vec![KSM]));
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), DOT_U, vec![KSM]));
+	assert_ok!(LendMarket::update_isolation_mode(
+		RuntimeOrigin::root(),
+		KSM,
+		Some(IsolationMode {
+			borrowable_assets: BoundedVec::truncate_from(vec![DOT]),
+			debt_ceiling: unit(50),
+		}),
+		POSITIONS_WITNESS,
+	));
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(DAVE), DOT, unit(200)));
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(DAVE), DOT_U, unit(200)));
+}
+
+#[test]
+fn isolated_collateral_cannot_be_mixed() {
+	new_test_ext().execute_with(|| {
+		isolate_ksm();
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), KSM, unit(200)));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), KSM, true));
+		assert_noop!(
+			LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT, true),
+			Error::<Test>::IsolatedCollateralConflict
+		);
+
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), KSM, false));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT, true));
+		assert_noop!(
+			LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), KSM, true),
+			Error::<Test>::IsolatedCollateralConflict
+		);
+	})
+}
+
+#[test]
+fn isolated_collateral_borrow_works() {
+	new_test_ext().execute_with(|| {
+		isolate_ksm();
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), KSM, unit(200)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), KSM, true));
+
+		assert_noop!(
+			LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT_U, unit(10)),
+			Error::<Test>::NotBorrowableInIsolationMode
+		);
+		assert_noop!(
+			LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(60)),
+			Error::<Test>::DebtCeilingExceeded
+		);
+
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(40)));
+		assert_eq!(IsolatedDebt::<Test>::get(KSM), unit(40));
+		assert_noop!(
+			LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(20)),
+			Error::<Test>::DebtCeilingExceeded
+		);
+
+		assert_ok!(LendMarket::repay_borrow(RuntimeOrigin::signed(ALICE), DOT, unit(10)));
+		assert_eq!(IsolatedDebt::<Test>::get(KSM), unit(30));
+	})
+}
+
+#[test]
+fn isolating_an_asset_in_use_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), DOT, vec![KSM]));
+		assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), DOT_U, vec![KSM]));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(DAVE), DOT, unit(200)));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(DAVE), DOT_U, unit(200)));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), KSM, unit(200)));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), KSM, true));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT, true));
+		let isolation = IsolationMode {
+			borrowable_assets: BoundedVec::truncate_from(vec![DOT]),
+			debt_ceiling: unit(50),
+		};
+
+		// KSM is used as collateral together with DOT.
+		assert_noop!(
+			LendMarket::update_isolation_mode(
+				RuntimeOrigin::root(),
+				KSM,
+				Some(isolation.clone()),
+				POSITIONS_WITNESS
+			),
+			Error::<Test>::IsolatedCollateralConflict
+		);
+
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT, false));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT_U, unit(10)));
+		assert_noop!(
+			LendMarket::update_isolation_mode(
+				RuntimeOrigin::root(),
+				KSM,
+				Some(isolation.clone()),
+				POSITIONS_WITNESS
+			),
+			Error::<Test>::NotBorrowableInIsolationMode
+		);
+
+		assert_ok!(LendMarket::repay_borrow_all(RuntimeOrigin::signed(ALICE), DOT_U));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(20)));
+		// ALICE's collateral is checked against every market.
+		assert_noop!(
+			LendMarket::update_isolation_mode(
+				RuntimeOrigin::root(),
+				KSM,
+				Some(isolation.clone()),
+				1
+			),
+			Error::<Test>::InvalidPositionsWitness
+		);
+		assert_ok!(LendMarket::update_isolation_mode(
+			RuntimeOrigin::root(),
+			KSM,
+			Some(isolation),
+			POSITIONS_WITNESS
+		));
+		// The debt taken before the asset was isolated counts in the debt ceiling.
+		assert_eq!(IsolatedDebt::<Test>::get(KSM), unit(20));
+		assert_noop!(
+			LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(40)),
+			Error::<Test>::DebtCeilingExceeded
+		);
+
+		assert_ok!(LendMarket::update_isolation_mode(RuntimeOrigin::root(), KSM, None, 0));
+		assert_eq!(IsolatedDebt::<Test>::get(KSM), 0);
+	})
+}
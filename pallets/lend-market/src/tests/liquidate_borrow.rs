@@ -4,10 +4,10 @@ use crate::{
 		KSM, *,
 	},
 	tests::unit,
-	Error, MarketState,
+	Error, Liquidation, LiquidationAuctionConfig, LiquidationAuctions, MarketState,
 };
 use bifrost_primitives::Rate;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, BoundedVec};
 use sp_runtime::FixedPointNumber;
 
 #[test]
@@ -270,6 +270,162 @@ fn liquidator_must_not_be_borrower() {
 	})
 }
 
+#[test]
+fn batch_liquidate_borrow_works() {
+	new_test_ext().execute_with(|| {
+		alice_borrows_100_ksm_against_dot_u();
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(100)));
+		// Dave deposits 200 DOT_U as collateral and borrows 100 KSM
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(DAVE), DOT_U, unit(200)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(DAVE), DOT_U, true));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(DAVE), KSM, unit(100)));
+		MockOraclePriceProvider::set_price(KSM, 2.into());
+
+		let liquidation = |borrower| Liquidation {
+			borrower,
+			liquidation_asset_id: KSM,
+			repay_amount: unit(50),
+			collateral_asset_id: DOT_U,
+		};
+		// The batch fails as a whole, Charlie has nothing to liquidate
+		assert_noop!(
+			LendMarket::batch_liquidate_borrow(
+				RuntimeOrigin::signed(BOB),
+				BoundedVec::truncate_from(vec![liquidation(ALICE), liquidation(CHARLIE)])
+			),
+			Error::<Test>::InsufficientShortfall
+		);
+		assert_ok!(LendMarket::batch_liquidate_borrow(
+			RuntimeOrigin::signed(BOB),
+			BoundedVec::truncate_from(vec![liquidation(ALICE), liquidation(DAVE)])
+		));
+
+		assert_eq!(AccountBorrows::<Test>::get(KSM, ALICE).principal, unit(50));
+		assert_eq!(AccountBorrows::<Test>::get(KSM, DAVE).principal, unit(50));
+		// Bob KSM: cash - deposit - repay = 1000 - 300 - 50 - 50 = 600
+		assert_eq!(<Test as Config>::Assets::balance(KSM, &BOB), unit(600));
+		// Bob DOT_U collateral: 2 * (110 - 110 / 1.1 * 0.03) = 214
+		assert_eq!(
+			ExchangeRate::<Test>::get(DOT_U)
+				.saturating_mul_int(AccountDeposits::<Test>::get(DOT_U, BOB).voucher_balance),
+			unit(214),
+		);
+	})
+}
+
+#[test]
+fn liquidation_auction_works() {
+	new_test_ext().execute_with(|| {
+		alice_borrows_100_ksm_against_dot_u();
+		MockOraclePriceProvider::set_price(KSM, 2.into());
+		assert_noop!(
+			LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM),
+			Error::<Test>::LiquidationAuctionNotEnabled
+		);
+		assert_ok!(LendMarket::update_liquidation_auction_config(
+			RuntimeOrigin::root(),
+			KSM,
+			Some(LiquidationAuctionConfig {
+				min_auction_value: unit(150),
+				start_incentive: Rate::one(),
+				incentive_step: Rate::saturating_from_rational(1, 100),
+			}),
+		));
+
+		// Alice borrows $200 of KSM, above the auction threshold
+		System::set_block_number(10);
+		assert_ok!(LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM));
+		assert_eq!(LiquidationAuctions::<Test>::get(KSM, ALICE), Some(10));
+		assert_noop!(
+			LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM),
+			Error::<Test>::LiquidationAuctionAlreadyStarted
+		);
+		assert_noop!(
+			LendMarket::close_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM),
+			Error::<Test>::PositionStillLiquidatable
+		);
+
+		// The incentive grows by 1% every block
+		System::set_block_number(15);
+		assert_ok!(LendMarket::liquidate_borrow(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			KSM,
+			unit(50),
+			DOT_U
+		));
+		// incentive = repay KSM value * 1.05 = (50 * 2) * 1.05 = 105
+		// Alice DOT_U collateral: deposit - incentive = 200 - 105 = 95
+		// Bob DOT_U collateral: incentive = 105 - (105 / 1.05 * 0.03) = 102
+		assert_eq!(
+			ExchangeRate::<Test>::get(DOT_U)
+				.saturating_mul_int(AccountDeposits::<Test>::get(DOT_U, ALICE).voucher_balance),
+			unit(95),
+		);
+		assert_eq!(
+			ExchangeRate::<Test>::get(DOT_U)
+				.saturating_mul_int(AccountDeposits::<Test>::get(DOT_U, BOB).voucher_balance),
+			unit(102),
+		);
+
+		// KSM price recovers, the position is no longer liquidatable
+		MockOraclePriceProvider::set_price(KSM, 1.into());
+		assert_ok!(LendMarket::close_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM));
+		assert!(!LiquidationAuctions::<Test>::contains_key(KSM, ALICE));
+	})
+}
+
+#[test]
+fn liquidation_starts_auction_of_large_position() {
+	new_test_ext().execute_with(|| {
+		alice_borrows_100_ksm_against_dot_u();
+		MockOraclePriceProvider::set_price(KSM, 2.into());
+		assert_ok!(LendMarket::update_liquidation_auction_config(
+			RuntimeOrigin::root(),
+			KSM,
+			Some(LiquidationAuctionConfig {
+				min_auction_value: unit(150),
+				start_incentive: Rate::one(),
+				incentive_step: Rate::saturating_from_rational(1, 100),
+			}),
+		));
+
+		// Alice borrows $200 of KSM, above the auction threshold
+		System::set_block_number(10);
+		assert_ok!(LendMarket::liquidate_borrow(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			KSM,
+			unit(50),
+			DOT_U
+		));
+		assert_eq!(LiquidationAuctions::<Test>::get(KSM, ALICE), Some(10));
+		// incentive = repay KSM value * 1.00 = (50 * 2) * 1.00 = 100
+		// Alice DOT_U collateral: deposit - incentive = 200 - 100 = 100
+		// Bob DOT_U collateral: incentive = 100 - (100 / 1.00 * 0.03) = 97
+		assert_eq!(
+			ExchangeRate::<Test>::get(DOT_U)
+				.saturating_mul_int(AccountDeposits::<Test>::get(DOT_U, ALICE).voucher_balance),
+			unit(100),
+		);
+		assert_eq!(
+			ExchangeRate::<Test>::get(DOT_U)
+				.saturating_mul_int(AccountDeposits::<Test>::get(DOT_U, BOB).voucher_balance),
+			unit(97),
+		);
+	})
+}
+
+fn alice_borrows_100_ksm_against_dot_u() {
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), KSM, vec![DOT, BNC, KSM, DOT_U]));
+	// Bob deposits 200 KSM
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+	// Alice deposits 200 DOT_U as collateral
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT_U, unit(200)));
+	assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT_U, true));
+	alice_borrows_100_ksm();
+}
+
 fn alice_borrows_100_ksm() {
 	assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
 }
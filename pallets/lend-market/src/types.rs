@@ -87,6 +87,91 @@ pub struct Market<Balance> {
 	pub borrow_cap: Balance,
 	/// Lend token asset id
 	pub lend_token_id: CurrencyId,
+	/// Isolation mode of the market, `None` if the asset is part of the shared
+	/// cross-collateral pool
+	pub isolation: Option<IsolationMode<Balance>>,
+}
+
+/// Maximum number of assets which can be borrowed against an isolated collateral.
+pub const MAX_ISOLATION_BORROWABLE_ASSETS: u32 = 16;
+
+/// Isolation mode of a market.
+///
+/// An isolated asset can only be used as collateral on its own, to borrow the assets in
+/// `borrowable_assets`, and the total debt backed by it can't exceed `debt_ceiling`.
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[derive(
+	Clone,
+	PartialEq,
+	Eq,
+	parity_scale_codec::Decode,
+	parity_scale_codec::Encode,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub struct IsolationMode<Balance> {
+	/// Assets which can be borrowed against the isolated collateral
+	pub borrowable_assets: BoundedVec<CurrencyId, ConstU32<MAX_ISOLATION_BORROWABLE_ASSETS>>,
+	/// Upper bound of the total debt value backed by the isolated collateral, in the same
+	/// unit as the asset value (price with 18 decimals times amount)
+	pub debt_ceiling: Balance,
+}
+
+/// Maximum number of correlated assets of an e-mode category.
+pub const MAX_EMODE_CATEGORY_ASSETS: u32 = 16;
+
+/// Efficiency mode category.
+///
+/// Accounts opted in a category get the higher collateral factor and liquidation threshold
+/// of the category for the collaterals in `assets`, and can only borrow `assets`.
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[derive(
+	Clone,
+	PartialEq,
+	Eq,
+	parity_scale_codec::Decode,
+	parity_scale_codec::Encode,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub struct EModeCategory {
+	/// The collateral utilization ratio inside the category
+	pub collateral_factor: Ratio,
+	/// The liquidation threshold inside the category
+	pub liquidation_threshold: Ratio,
+	/// Liquidation incentive ratio inside the category
+	pub liquidate_incentive: Rate,
+	/// Correlated assets of the category
+	pub assets: BoundedVec<CurrencyId, ConstU32<MAX_EMODE_CATEGORY_ASSETS>>,
+}
+
+/// Dutch auction parameters for liquidating large positions of a market.
+///
+/// The liquidation incentive of an auction starts at `start_incentive` and grows by
+/// `incentive_step` every block, up to the `liquidate_incentive` of the market.
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo)]
+pub struct LiquidationAuctionConfig<Balance> {
+	/// Borrow value from which a position must be liquidated through an auction, in the
+	/// same unit as the asset value (price with 18 decimals times amount)
+	pub min_auction_value: Balance,
+	/// Liquidation incentive when the auction starts
+	pub start_incentive: Rate,
+	/// Liquidation incentive increase per block
+	pub incentive_step: Rate,
+}
+
+/// A single liquidation of a batch
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct Liquidation<AccountId, Balance> {
+	/// The borrower to be liquidated
+	pub borrower: AccountId,
+	/// The asset to be liquidated
+	pub liquidation_asset_id: CurrencyId,
+	/// The amount to be repaid borrow
+	pub repay_amount: Balance,
+	/// The collateral to seize from the borrower
+	pub collateral_asset_id: CurrencyId,
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
@@ -69,6 +69,13 @@ pub trait WeightInfo {
 	fn withdraw_missing_reward() -> Weight;
 	fn collateral_asset() -> Weight;
 	fn update_liquidation_free_collateral() -> Weight;
+	fn update_isolation_mode(p: u32, ) -> Weight;
+	fn update_emode_category() -> Weight;
+	fn set_account_emode() -> Weight;
+	fn update_liquidation_auction_config() -> Weight;
+	fn start_liquidation_auction() -> Weight;
+	fn close_liquidation_auction() -> Weight;
+	fn write_off_bad_debt() -> Weight;
 }
 
 /// Weights for loans using the Substrate node and recommended hardware.
@@ -571,14 +578,18 @@ impl<T: frame_system::Config> WeightInfo for BifrostWeight<T> {
 	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardSupplierIndex (r:3 w:3)
 	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof Skipped: LendMarket LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: LendMarket LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
 	fn liquidate_borrow() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5226`
 		//  Estimated: `201519`
 		// Minimum execution time: 762_145_000 picoseconds.
 		Weight::from_parts(766_944_000, 201519)
-			.saturating_add(T::DbWeight::get().reads(44_u64))
-			.saturating_add(T::DbWeight::get().writes(21_u64))
+			.saturating_add(T::DbWeight::get().reads(46_u64))
+			.saturating_add(T::DbWeight::get().writes(22_u64))
 	}
 	/// Storage: Loans Markets (r:2 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
@@ -681,6 +692,42 @@ impl<T: frame_system::Config> WeightInfo for BifrostWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: LendMarket Markets (r:3 w:1)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountDeposits (r:3 w:0)
+	/// Proof Skipped: LendMarket AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountBorrows (r:2 w:0)
+	/// Proof Skipped: LendMarket AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket BorrowIndex (r:1 w:0)
+	/// Proof Skipped: LendMarket BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Prices EmergencyPrice (r:1 w:0)
+	/// Proof Skipped: Prices EmergencyPrice (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
+	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket IsolatedDebt (r:0 w:1)
+	/// Proof Skipped: LendMarket IsolatedDebt (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `p` is `[2, 200]`.
+	fn update_isolation_mode(p: u32, ) -> Weight {
+		Weight::from_parts(21_000_000, 6302)
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2500).saturating_mul(p.into()))
+	}
+	/// Storage: LendMarket EModeCategoryAccounts (r:1 w:0)
+	/// Proof Skipped: LendMarket EModeCategoryAccounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket EModeCategories (r:1 w:1)
+	/// Proof Skipped: LendMarket EModeCategories (max_values: None, max_size: None, mode: Measured)
+	fn update_emode_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `3783`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3783)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	fn collateral_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2857`
@@ -690,6 +737,116 @@ impl<T: frame_system::Config> WeightInfo for BifrostWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: LendMarket EModeCategories (r:1 w:0)
+	/// Proof Skipped: LendMarket EModeCategories (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket Markets (r:6 w:0)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountBorrows (r:5 w:0)
+	/// Proof Skipped: LendMarket AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountEMode (r:1 w:1)
+	/// Proof Skipped: LendMarket AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket EModeCategoryAccounts (r:2 w:2)
+	/// Proof Skipped: LendMarket EModeCategoryAccounts (max_values: None, max_size: None, mode: Measured)
+	fn set_account_emode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6977`
+		//  Estimated: `13955`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(52_000_000, 13955)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: LendMarket LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof Skipped: LendMarket LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket Markets (r:1 w:0)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LiquidationAuctionConfigs (r:0 w:1)
+	/// Proof Skipped: LendMarket LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	fn update_liquidation_auction_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1504`
+		//  Estimated: `3979`
+		Weight::from_parts(43_437_000, 3979)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LendMarket LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: LendMarket LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket Markets (r:6 w:0)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: LendMarket LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountBorrows (r:5 w:0)
+	/// Proof Skipped: LendMarket AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountDeposits (r:2 w:0)
+	/// Proof Skipped: LendMarket AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket BorrowIndex (r:1 w:1)
+	/// Proof Skipped: LendMarket BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	fn start_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7010`
+		//  Estimated: `14021`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(65_000_000, 14021)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: LendMarket LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: LendMarket LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket Markets (r:6 w:0)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: LendMarket LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountBorrows (r:5 w:0)
+	/// Proof Skipped: LendMarket AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountDeposits (r:2 w:0)
+	/// Proof Skipped: LendMarket AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket BorrowIndex (r:1 w:1)
+	/// Proof Skipped: LendMarket BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	fn close_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7010`
+		//  Estimated: `14021`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(62_000_000, 14021)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: LendMarket Markets (r:6 w:0)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountDeposits (r:5 w:0)
+	/// Proof Skipped: LendMarket AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: LendMarket LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket TotalSupply (r:1 w:0)
+	/// Proof Skipped: LendMarket TotalSupply (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket TotalBorrows (r:1 w:1)
+	/// Proof Skipped: LendMarket TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket TotalReserves (r:1 w:1)
+	/// Proof Skipped: LendMarket TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountBorrows (r:1 w:1)
+	/// Proof Skipped: LendMarket AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket BorrowIndex (r:1 w:1)
+	/// Proof Skipped: LendMarket BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: LendMarket RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: LendMarket RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: LendMarket RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket RewardAccrued (r:1 w:1)
+	/// Proof Skipped: LendMarket RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket ExchangeRate (r:0 w:1)
+	/// Proof Skipped: LendMarket ExchangeRate (max_values: None, max_size: None, mode: Measured)
+	fn write_off_bad_debt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10742`
+		//  Estimated: `21485`
+		// Minimum execution time: 98_000_000 picoseconds.
+		Weight::from_parts(99_000_000, 21485)
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -1191,14 +1348,18 @@ impl WeightInfo for () {
 	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Loans RewardSupplierIndex (r:3 w:3)
 	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof Skipped: LendMarket LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: LendMarket LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
 	fn liquidate_borrow() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `5226`
 		//  Estimated: `201519`
 		// Minimum execution time: 762_145_000 picoseconds.
 		Weight::from_parts(766_944_000, 201519)
-			.saturating_add(RocksDbWeight::get().reads(44_u64))
-			.saturating_add(RocksDbWeight::get().writes(21_u64))
+			.saturating_add(RocksDbWeight::get().reads(46_u64))
+			.saturating_add(RocksDbWeight::get().writes(22_u64))
 	}
 	/// Storage: Loans Markets (r:2 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
@@ -1301,6 +1462,42 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: LendMarket Markets (r:3 w:1)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountDeposits (r:3 w:0)
+	/// Proof Skipped: LendMarket AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountBorrows (r:2 w:0)
+	/// Proof Skipped: LendMarket AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket BorrowIndex (r:1 w:0)
+	/// Proof Skipped: LendMarket BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Prices EmergencyPrice (r:1 w:0)
+	/// Proof Skipped: Prices EmergencyPrice (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
+	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket IsolatedDebt (r:0 w:1)
+	/// Proof Skipped: LendMarket IsolatedDebt (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `p` is `[2, 200]`.
+	fn update_isolation_mode(p: u32, ) -> Weight {
+		Weight::from_parts(21_000_000, 6302)
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2500).saturating_mul(p.into()))
+	}
+	/// Storage: LendMarket EModeCategoryAccounts (r:1 w:0)
+	/// Proof Skipped: LendMarket EModeCategoryAccounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket EModeCategories (r:1 w:1)
+	/// Proof Skipped: LendMarket EModeCategories (max_values: None, max_size: None, mode: Measured)
+	fn update_emode_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `3783`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 3783)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	fn collateral_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2857`
@@ -1310,4 +1507,114 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: LendMarket EModeCategories (r:1 w:0)
+	/// Proof Skipped: LendMarket EModeCategories (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket Markets (r:6 w:0)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountBorrows (r:5 w:0)
+	/// Proof Skipped: LendMarket AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountEMode (r:1 w:1)
+	/// Proof Skipped: LendMarket AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket EModeCategoryAccounts (r:2 w:2)
+	/// Proof Skipped: LendMarket EModeCategoryAccounts (max_values: None, max_size: None, mode: Measured)
+	fn set_account_emode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6977`
+		//  Estimated: `13955`
+		// Minimum execution time: 48_000_000 picoseconds.
+		Weight::from_parts(52_000_000, 13955)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: LendMarket LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof Skipped: LendMarket LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket Markets (r:1 w:0)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LiquidationAuctionConfigs (r:0 w:1)
+	/// Proof Skipped: LendMarket LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	fn update_liquidation_auction_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1504`
+		//  Estimated: `3979`
+		Weight::from_parts(43_437_000, 3979)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LendMarket LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: LendMarket LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket Markets (r:6 w:0)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: LendMarket LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountBorrows (r:5 w:0)
+	/// Proof Skipped: LendMarket AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountDeposits (r:2 w:0)
+	/// Proof Skipped: LendMarket AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket BorrowIndex (r:1 w:1)
+	/// Proof Skipped: LendMarket BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	fn start_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7010`
+		//  Estimated: `14021`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(65_000_000, 14021)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: LendMarket LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: LendMarket LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket Markets (r:6 w:0)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: LendMarket LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountBorrows (r:5 w:0)
+	/// Proof Skipped: LendMarket AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountDeposits (r:2 w:0)
+	/// Proof Skipped: LendMarket AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket BorrowIndex (r:1 w:1)
+	/// Proof Skipped: LendMarket BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	fn close_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7010`
+		//  Estimated: `14021`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(62_000_000, 14021)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: LendMarket Markets (r:6 w:0)
+	/// Proof Skipped: LendMarket Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountDeposits (r:5 w:0)
+	/// Proof Skipped: LendMarket AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: LendMarket LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket TotalSupply (r:1 w:0)
+	/// Proof Skipped: LendMarket TotalSupply (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket TotalBorrows (r:1 w:1)
+	/// Proof Skipped: LendMarket TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket TotalReserves (r:1 w:1)
+	/// Proof Skipped: LendMarket TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket AccountBorrows (r:1 w:1)
+	/// Proof Skipped: LendMarket AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket BorrowIndex (r:1 w:1)
+	/// Proof Skipped: LendMarket BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: LendMarket RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket RewardBorrowSpeed (r:1 w:0)
+	/// Proof Skipped: LendMarket RewardBorrowSpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: LendMarket RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket RewardAccrued (r:1 w:1)
+	/// Proof Skipped: LendMarket RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket ExchangeRate (r:0 w:1)
+	/// Proof Skipped: LendMarket ExchangeRate (max_values: None, max_size: None, mode: Measured)
+	fn write_off_bad_debt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10742`
+		//  Estimated: `21485`
+		// Minimum execution time: 98_000_000 picoseconds.
+		Weight::from_parts(99_000_000, 21485)
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
 }
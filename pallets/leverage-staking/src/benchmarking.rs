@@ -50,6 +50,7 @@ pub const fn market_mock(lend_token_id: CurrencyId) -> Market<Balance> {
 		supply_cap: 1_000_000_000_000_000_000_000u128, // set to 1B
 		borrow_cap: 1_000_000_000_000_000_000_000u128, // set to 1B
 		lend_token_id,
+		isolation: None,
 	}
 }
 
@@ -182,7 +183,37 @@ mod benchmarks {
 		let rate = FixedU128::from_inner(unit(990_000));
 
 		#[extrinsic_call]
-		Pallet::<T>::flash_loan_deposit(SystemOrigin::Signed(caller.clone()), coin0.into(), rate);
+		Pallet::<T>::flash_loan_deposit(
+			SystemOrigin::Signed(caller.clone()),
+			coin0.into(),
+			rate,
+			Some(FixedU128::one()),
+			Some(Permill::from_percent(10)),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn flash_loan_repay() -> Result<(), BenchmarkError> {
+		init::<T>()?;
+		let caller: AccountIdOf<T> = account("caller", 1, SEED);
+		let coin0 = KSM;
+		assert_ok!(Pallet::<T>::flash_loan_deposit(
+			SystemOrigin::Signed(caller.clone()).into(),
+			coin0.into(),
+			FixedU128::from_inner(unit(990_000)),
+			None,
+			None
+		));
+
+		#[extrinsic_call]
+		Pallet::<T>::flash_loan_repay(
+			SystemOrigin::Signed(caller.clone()),
+			coin0.into(),
+			FixedU128::zero(),
+			Some(Permill::from_percent(10)),
+		);
 
 		Ok(())
 	}
@@ -44,8 +44,8 @@ pub use pallet_traits::{
 };
 pub use parity_scale_codec::{Decode, Encode};
 use sp_runtime::{
-	traits::{CheckedSub, Zero},
-	ArithmeticError, FixedPointNumber, FixedU128, SaturatedConversion,
+	traits::{CheckedSub, Saturating, Zero},
+	ArithmeticError, FixedPointNumber, FixedU128, PerThing, Permill, SaturatedConversion,
 };
 use sp_std::{cmp::Ordering, marker::PhantomData};
 pub use weights::WeightInfo;
@@ -91,6 +91,12 @@ pub mod pallet {
 		ArgumentsError,
 		/// Not support token type
 		NotSupportTokenType,
+		/// The target rate must be lower than the current leverage rate
+		RateTooHigh,
+		/// The health factor of the resulting position is lower than required
+		HealthFactorTooLow,
+		/// The conversion between vtoken and token exceeds the max slippage
+		ExceededMaxSlippage,
 	}
 
 	#[pallet::event]
@@ -107,6 +113,36 @@ pub mod pallet {
 			/// The new leverage rate.
 			new_rate: Rate,
 		},
+		/// User's leverage has been reduced or the position has been closed.
+		FlashLoanRepaid {
+			/// Account who reduce the leverage rate.
+			who: AccountIdOf<T>,
+			/// The asset id of the token.
+			asset_id: AssetIdOf<T>,
+			/// The old leverage rate.
+			old_rate: Rate,
+			/// The new leverage rate.
+			new_rate: Rate,
+			/// The amount of borrowed token which has been repaid.
+			repaid_amount: BalanceOf<T>,
+			/// The amount of vtoken redeemed back to the account when the position is closed.
+			withdrawn_amount: BalanceOf<T>,
+		},
+		/// The resulting leverage position of the user.
+		PositionUpdated {
+			/// Account who owns the position.
+			who: AccountIdOf<T>,
+			/// The asset id of the token.
+			asset_id: AssetIdOf<T>,
+			/// The vtoken collateral supplied to lend-market, in underlying amount.
+			collateral: BalanceOf<T>,
+			/// The current borrows of the token.
+			borrows: BalanceOf<T>,
+			/// The current leverage rate.
+			rate: Rate,
+			/// The health factor of the account, `None` if there is no borrows.
+			health_factor: Option<FixedU128>,
+		},
 	}
 
 	#[pallet::call]
@@ -117,16 +153,48 @@ pub mod pallet {
 		///
 		/// - `asset_id`: The asset id of the token
 		/// - `rate`: Leverage rate
+		/// - `min_health_factor`: Revert if the resulting health factor is lower than it
+		/// - `max_slippage`: Revert if the conversion between vtoken and token loses more than it
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::flash_loan_deposit())]
 		pub fn flash_loan_deposit(
 			origin: OriginFor<T>,
 			asset_id: AssetIdOf<T>,
 			rate: Rate,
+			min_health_factor: Option<FixedU128>,
+			max_slippage: Option<Permill>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			Pallet::<T>::flash_loan_deposit_inner(who, asset_id, rate)
+			Pallet::<T>::flash_loan_deposit_inner(
+				who,
+				asset_id,
+				rate,
+				min_health_factor,
+				max_slippage,
+			)
+		}
+
+		/// Repay flash loan
+		///
+		/// Redeem part of the vtoken collateral, swap it back to the token and repay the
+		/// borrows until the leverage rate drops to `rate`. A zero `rate` closes the position
+		/// and returns the remaining vtoken collateral to the account.
+		///
+		/// - `asset_id`: The asset id of the token
+		/// - `rate`: Target leverage rate, must be lower than the current one
+		/// - `max_slippage`: Revert if swapping vtoken back to token loses more than it
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::flash_loan_repay())]
+		pub fn flash_loan_repay(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			rate: Rate,
+			max_slippage: Option<Permill>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Pallet::<T>::flash_loan_repay_inner(who, asset_id, rate, max_slippage)
 		}
 	}
 }
@@ -137,6 +205,8 @@ impl<T: Config> Pallet<T> {
 		who: AccountIdOf<T>,
 		asset_id: AssetIdOf<T>,
 		rate: Rate,
+		min_health_factor: Option<FixedU128>,
+		max_slippage: Option<Permill>,
 	) -> DispatchResult {
 		let vtoken_id = T::CurrencyIdConversion::convert_to_vtoken(asset_id)
 			.map_err(|_| Error::<T>::NotSupportTokenType)?;
@@ -145,33 +215,14 @@ impl<T: Config> Pallet<T> {
 		if !deposits.is_collateral {
 			T::LendMarket::do_collateral_asset(&who, vtoken_id, true)?;
 		}
-		let account_deposits = Self::current_collateral_amount(&who, vtoken_id)?;
-		let account_borrows = lend_market::Pallet::<T>::get_current_borrow_balance(&who, asset_id)?;
-
-		// Formula
-		// current_rate = account_borrows / (
-		// get_currency_amount_by_v_currency_amount(account_deposits) - account_borrows )
-		let deposits_token_value = T::VtokenMinting::get_currency_amount_by_v_currency_amount(
-			asset_id,
-			vtoken_id,
-			account_deposits,
-		)?;
-		let base_token_value = deposits_token_value
-			.checked_sub(account_borrows)
-			.ok_or(ArithmeticError::Overflow)?;
-		let current_rate = FixedU128::saturating_from_rational(account_borrows, base_token_value);
+		let (current_rate, account_borrows, base_token_value) =
+			Self::current_leverage_rate(&who, asset_id, vtoken_id)?;
 
 		match rate.cmp(&current_rate) {
 			Ordering::Less => {
-				let reduce_amount = if rate.is_zero() {
-					account_borrows
-				} else {
-					current_rate
-						.checked_sub(&rate)
-						.and_then(|r| r.checked_mul_int(base_token_value))
-						.ok_or(ArithmeticError::Overflow)?
-				};
-				Self::reduce_leverage(&who, asset_id, vtoken_id, reduce_amount)?;
+				let reduce_amount =
+					Self::reduce_amount(rate, current_rate, account_borrows, base_token_value)?;
+				Self::reduce_leverage(&who, asset_id, vtoken_id, reduce_amount, max_slippage)?;
 			},
 			Ordering::Equal => return Err(Error::<T>::ArgumentsError.into()),
 			Ordering::Greater => {
@@ -179,23 +230,150 @@ impl<T: Config> Pallet<T> {
 					.checked_sub(&current_rate)
 					.and_then(|r| r.checked_mul_int(base_token_value))
 					.ok_or(ArithmeticError::Overflow)?;
-				Self::increase_leverage(&who, asset_id, vtoken_id, increase_amount)?;
+				Self::increase_leverage(&who, asset_id, vtoken_id, increase_amount, max_slippage)?;
 			},
 		}
+
+		if let Some(min_health_factor) = min_health_factor {
+			let (_, shortfall, _, _) =
+				lend_market::Pallet::<T>::get_account_liquidation_threshold_liquidity(&who)?;
+			ensure!(shortfall.is_zero(), Error::<T>::HealthFactorTooLow);
+			if let Some(health_factor) = lend_market::Pallet::<T>::get_account_health_factor(&who)?
+			{
+				ensure!(health_factor >= min_health_factor, Error::<T>::HealthFactorTooLow);
+			}
+		}
+
 		Self::deposit_event(Event::<T>::FlashLoanDeposited {
-			who,
+			who: who.clone(),
+			asset_id,
+			old_rate: current_rate,
+			new_rate: rate,
+		});
+		Self::deposit_position_event(who, asset_id, vtoken_id)
+	}
+
+	#[transactional]
+	pub fn flash_loan_repay_inner(
+		who: AccountIdOf<T>,
+		asset_id: AssetIdOf<T>,
+		rate: Rate,
+		max_slippage: Option<Permill>,
+	) -> DispatchResult {
+		let vtoken_id = T::CurrencyIdConversion::convert_to_vtoken(asset_id)
+			.map_err(|_| Error::<T>::NotSupportTokenType)?;
+
+		let (current_rate, account_borrows, base_token_value) =
+			Self::current_leverage_rate(&who, asset_id, vtoken_id)?;
+		ensure!(rate < current_rate, Error::<T>::RateTooHigh);
+
+		let repaid_amount =
+			Self::reduce_amount(rate, current_rate, account_borrows, base_token_value)?;
+		Self::reduce_leverage(&who, asset_id, vtoken_id, repaid_amount, max_slippage)?;
+
+		// Closing the position, give back all of the remaining collateral.
+		let withdrawn_amount = if rate.is_zero() {
+			lend_market::Pallet::<T>::do_redeem_all(&who, vtoken_id)?
+		} else {
+			Zero::zero()
+		};
+
+		Self::deposit_event(Event::<T>::FlashLoanRepaid {
+			who: who.clone(),
 			asset_id,
 			old_rate: current_rate,
 			new_rate: rate,
+			repaid_amount,
+			withdrawn_amount,
+		});
+		Self::deposit_position_event(who, asset_id, vtoken_id)
+	}
+
+	fn deposit_position_event(
+		who: AccountIdOf<T>,
+		asset_id: AssetIdOf<T>,
+		vtoken_id: AssetIdOf<T>,
+	) -> DispatchResult {
+		let collateral = Self::current_collateral_amount(&who, vtoken_id)?;
+		let borrows = lend_market::Pallet::<T>::current_borrow_balance(&who, asset_id)?;
+		let rate = if borrows.is_zero() {
+			Rate::zero()
+		} else {
+			Self::current_leverage_rate(&who, asset_id, vtoken_id)?.0
+		};
+		let health_factor = lend_market::Pallet::<T>::get_account_health_factor(&who)?;
+
+		Self::deposit_event(Event::<T>::PositionUpdated {
+			who,
+			asset_id,
+			collateral,
+			borrows,
+			rate,
+			health_factor,
 		});
 		Ok(())
 	}
 
+	/// Ensure `actual_amount` is not less than `expected_amount` by more than `max_slippage`.
+	fn ensure_slippage(
+		expected_amount: BalanceOf<T>,
+		actual_amount: BalanceOf<T>,
+		max_slippage: Option<Permill>,
+	) -> DispatchResult {
+		if let Some(max_slippage) = max_slippage {
+			let min_amount = Permill::one().saturating_sub(max_slippage).mul_floor(expected_amount);
+			ensure!(actual_amount >= min_amount, Error::<T>::ExceededMaxSlippage);
+		}
+		Ok(())
+	}
+
+	/// Returns `(current_rate, account_borrows, base_token_value)` of the position.
+	fn current_leverage_rate(
+		who: &AccountIdOf<T>,
+		asset_id: AssetIdOf<T>,
+		vtoken_id: AssetIdOf<T>,
+	) -> Result<(Rate, BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let account_deposits = Self::current_collateral_amount(who, vtoken_id)?;
+		let account_borrows = lend_market::Pallet::<T>::get_current_borrow_balance(who, asset_id)?;
+
+		// Formula
+		// current_rate = account_borrows / (
+		// get_currency_amount_by_v_currency_amount(account_deposits) - account_borrows )
+		let deposits_token_value = T::VtokenMinting::get_currency_amount_by_v_currency_amount(
+			asset_id,
+			vtoken_id,
+			account_deposits,
+		)?;
+		let base_token_value = deposits_token_value
+			.checked_sub(account_borrows)
+			.ok_or(ArithmeticError::Overflow)?;
+		let current_rate = FixedU128::saturating_from_rational(account_borrows, base_token_value);
+
+		Ok((current_rate, account_borrows, base_token_value))
+	}
+
+	/// The amount of borrows to repay when lowering the leverage from `current_rate` to `rate`.
+	fn reduce_amount(
+		rate: Rate,
+		current_rate: Rate,
+		account_borrows: BalanceOf<T>,
+		base_token_value: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		if rate.is_zero() {
+			return Ok(account_borrows);
+		}
+		Ok(current_rate
+			.checked_sub(&rate)
+			.and_then(|r| r.checked_mul_int(base_token_value))
+			.ok_or(ArithmeticError::Overflow)?)
+	}
+
 	fn reduce_leverage(
 		who: &T::AccountId,
 		asset_id: AssetIdOf<T>,
 		vtoken_id: AssetIdOf<T>,
 		reduce_amount: BalanceOf<T>,
+		max_slippage: Option<Permill>,
 	) -> DispatchResult {
 		let (pool_id, currency_id_in, currency_id_out) =
 			T::StablePoolHandler::get_pool_id(&vtoken_id, &asset_id)
@@ -210,6 +388,12 @@ impl<T: Config> Pallet<T> {
 			currency_id_out,
 			reduce_amount,
 		)?;
+		let redeem_token_value = T::VtokenMinting::get_currency_amount_by_v_currency_amount(
+			asset_id,
+			vtoken_id,
+			redeem_amount,
+		)?;
+		Self::ensure_slippage(redeem_token_value, reduce_amount, max_slippage)?;
 		// Do redeem
 		T::LendMarket::do_redeem(&who, vtoken_id, redeem_amount)?;
 
@@ -237,6 +421,7 @@ impl<T: Config> Pallet<T> {
 		asset_id: AssetIdOf<T>,
 		vtoken_id: AssetIdOf<T>,
 		increase_amount: BalanceOf<T>,
+		max_slippage: Option<Permill>,
 	) -> DispatchResult {
 		<T as lend_market::Config>::Assets::mint_into(asset_id, &who, increase_amount)?;
 		let vtoken_value = T::VtokenMinting::mint(
@@ -246,6 +431,12 @@ impl<T: Config> Pallet<T> {
 			BoundedVec::default(),
 			None,
 		)?;
+		let vtoken_token_value = T::VtokenMinting::get_currency_amount_by_v_currency_amount(
+			asset_id,
+			vtoken_id,
+			vtoken_value,
+		)?;
+		Self::ensure_slippage(increase_amount, vtoken_token_value, max_slippage)?;
 		T::LendMarket::do_mint(&who, vtoken_id, vtoken_value)?;
 		T::LendMarket::do_borrow(&who, asset_id, increase_amount)?;
 		<T as lend_market::Config>::Assets::burn_from(
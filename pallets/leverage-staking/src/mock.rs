@@ -23,8 +23,9 @@ pub use bifrost_primitives::{
 	currency::*, Balance, CurrencyId, CurrencyIdMapping, SlpOperator, SlpxOperator, TokenSymbol,
 };
 use bifrost_primitives::{
-	BifrostEntranceAccount, BifrostExitAccount, IncentivePoolAccount, LendMarketPalletId, Moment,
-	MoonbeamChainId, OraclePriceProvider, Price, PriceDetail, Ratio, StableAssetPalletId,
+	BifrostEntranceAccount, BifrostExitAccount, IncentivePoolAccount, InstantRedeemBufferPalletId,
+	LendMarketPalletId, Moment, MoonbeamChainId, OraclePriceProvider, Price, PriceDetail, Ratio,
+	StableAssetPalletId,
 };
 use bifrost_runtime_common::milli;
 use frame_support::{
@@ -37,7 +38,10 @@ use orml_traits::{
 	location::RelativeReserveProvider, parameter_type_with_key, DataFeeder, DataProvider,
 	DataProviderExtended,
 };
-use sp_runtime::{traits::IdentityLookup, BuildStorage, FixedPointNumber};
+use sp_runtime::{
+	traits::{IdentifyAccount, IdentityLookup, Lazy, Verify},
+	BuildStorage, FixedPointNumber,
+};
 use std::{
 	cell::RefCell,
 	collections::HashMap,
@@ -309,10 +313,36 @@ impl bifrost_vtoken_minting::Config for Test {
 	type BifrostSlpx = SlpxInterface;
 	type ChannelCommission = ();
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<100>;
+	type OffchainSignature = MockSignature;
+	type SigningPublicKey = MockSigner;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = ();
 }
 
+// Permits are not used in these tests, so no signature is valid.
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct MockSignature;
+
+impl Verify for MockSignature {
+	type Signer = MockSigner;
+
+	fn verify<L: Lazy<[u8]>>(&self, _msg: L, _signer: &u128) -> bool {
+		false
+	}
+}
+
+pub struct MockSigner(u128);
+
+impl IdentifyAccount for MockSigner {
+	type AccountId = u128;
+
+	fn into_account(self) -> u128 {
+		self.0
+	}
+}
+
 pub struct Slp;
 // Functions to be called by other pallets.
 impl SlpOperator<CurrencyId> for Slp {
@@ -428,6 +458,10 @@ impl OraclePriceProvider for MockOraclePriceProvider {
 	) -> Option<(Balance, Price, Price)> {
 		todo!()
 	}
+
+	fn is_price_paused(_asset_id: &CurrencyId) -> bool {
+		false
+	}
 }
 
 parameter_types! {
@@ -448,6 +482,7 @@ impl lend_market::Config for Test {
 	type RewardAssetId = RewardAssetId;
 	type LiquidationFreeAssetId = LiquidationFreeAssetId;
 	type MaxLengthLimit = MaxLengthLimit;
+	type VtokenMinting = VtokenMinting;
 }
 
 impl pallet_prices::Config for Test {
@@ -458,6 +493,11 @@ impl pallet_prices::Config for Test {
 	type RelayCurrency = RelayCurrencyId;
 	type Assets = Currencies;
 	type CurrencyIdConvert = AssetIdMaps<Test>;
+	type PriceSources = ();
+	type UnixTime = TimestampPallet;
+	type MaxAggregatedAssets = ConstU32<10>;
+	type MaxPriceObservations = ConstU32<10>;
+	type MaxCircuitBreakerAssets = ConstU32<10>;
 	type WeightInfo = ();
 }
 
@@ -580,5 +620,6 @@ pub const fn market_mock(lend_token_id: CurrencyId) -> Market<Balance> {
 		supply_cap: 1_000_000_000_000_000_000_000u128, // set to 1B
 		borrow_cap: 1_000_000_000_000_000_000_000u128, // set to 1B
 		lend_token_id,
+		isolation: None,
 	}
 }
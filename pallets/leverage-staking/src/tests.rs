@@ -75,6 +75,8 @@ fn increase_leverage_should_not_work() {
 				RuntimeOrigin::signed(1),
 				DOT,
 				FixedU128::from_inner(unit(1_000_100)),
+				None,
+				None,
 			),
 			lend_market::Error::<Test>::InsufficientLiquidity
 		);
@@ -89,6 +91,8 @@ fn increase_leverage_should_work() {
 			RuntimeOrigin::signed(1),
 			DOT,
 			FixedU128::from_inner(unit(100_000)),
+			None,
+			None,
 		));
 		assert_eq!(
 			AccountDeposits::<Test>::get(VDOT, 1),
@@ -102,6 +106,8 @@ fn increase_leverage_should_work() {
 			RuntimeOrigin::signed(1),
 			DOT,
 			FixedU128::from_inner(unit(800_000)),
+			None,
+			None,
 		));
 		assert_eq!(
 			AccountDeposits::<Test>::get(VDOT, 1),
@@ -115,6 +121,8 @@ fn increase_leverage_should_work() {
 			RuntimeOrigin::signed(1),
 			DOT,
 			FixedU128::from_inner(unit(900_000)),
+			None,
+			None,
 		));
 		assert_eq!(
 			AccountBorrows::<Test>::get(DOT, 1),
@@ -140,6 +148,8 @@ fn reduce_leverage_should_work() {
 			RuntimeOrigin::signed(1),
 			DOT,
 			FixedU128::from_inner(unit(900_000)),
+			None,
+			None,
 		));
 		assert_eq!(
 			AccountBorrows::<Test>::get(DOT, 1),
@@ -155,6 +165,8 @@ fn reduce_leverage_should_work() {
 			RuntimeOrigin::signed(1),
 			DOT,
 			FixedU128::from_inner(unit(800_000)),
+			None,
+			None,
 		));
 		assert_eq!(Tokens::balance(VDOT, &1), 9999999900000);
 		assert_eq!(Tokens::balance(DOT, &1), 990000000000098);
@@ -170,6 +182,8 @@ fn reduce_leverage_should_work() {
 			RuntimeOrigin::signed(1),
 			DOT,
 			FixedU128::from_inner(0),
+			None,
+			None,
 		));
 		assert_eq!(Tokens::balance(VDOT, &1), 9999999900000);
 		assert_eq!(Tokens::balance(DOT, &1), 990000000000196);
@@ -183,3 +197,105 @@ fn reduce_leverage_should_work() {
 		);
 	});
 }
+
+#[test]
+fn flash_loan_repay_should_not_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		init();
+		assert_ok!(LeverageStaking::flash_loan_deposit(
+			RuntimeOrigin::signed(1),
+			DOT,
+			FixedU128::from_inner(unit(800_000)),
+			None,
+			None,
+		));
+		assert_noop!(
+			LeverageStaking::flash_loan_repay(
+				RuntimeOrigin::signed(1),
+				DOT,
+				FixedU128::from_inner(unit(800_000)),
+				None,
+			),
+			Error::<Test>::RateTooHigh
+		);
+		assert_noop!(
+			LeverageStaking::flash_loan_repay(
+				RuntimeOrigin::signed(1),
+				DOT,
+				FixedU128::from_inner(unit(900_000)),
+				None,
+			),
+			Error::<Test>::RateTooHigh
+		);
+	});
+}
+
+#[test]
+fn flash_loan_repay_should_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		init();
+		assert_ok!(LeverageStaking::flash_loan_deposit(
+			RuntimeOrigin::signed(1),
+			DOT,
+			FixedU128::from_inner(unit(900_000)),
+			None,
+			None,
+		));
+		assert_ok!(LeverageStaking::flash_loan_repay(
+			RuntimeOrigin::signed(1),
+			DOT,
+			FixedU128::from_inner(unit(800_000)),
+			None,
+		));
+		assert_eq!(
+			AccountBorrows::<Test>::get(DOT, 1),
+			BorrowSnapshot { principal: 80_000, borrow_index: 1.into() },
+		);
+		assert_eq!(
+			AccountDeposits::<Test>::get(VDOT, 1),
+			Deposits { voucher_balance: 8994050, is_collateral: true },
+		);
+
+		// Close the position.
+		assert_ok!(LeverageStaking::flash_loan_repay(
+			RuntimeOrigin::signed(1),
+			DOT,
+			FixedU128::from_inner(0),
+			None,
+		));
+		assert_eq!(
+			AccountBorrows::<Test>::get(DOT, 1),
+			BorrowSnapshot { principal: 0, borrow_index: 1.into() },
+		);
+		assert!(!AccountDeposits::<Test>::contains_key(VDOT, 1));
+		assert!(Tokens::balance(VDOT, &1) > 9999999900000);
+	});
+}
+
+#[test]
+fn flash_loan_deposit_with_bounds_should_work() {
+	ExtBuilder::default().new_test_ext().build().execute_with(|| {
+		init();
+		assert_noop!(
+			LeverageStaking::flash_loan_deposit(
+				RuntimeOrigin::signed(1),
+				DOT,
+				FixedU128::from_inner(unit(900_000)),
+				Some(FixedU128::from(100)),
+				None,
+			),
+			Error::<Test>::HealthFactorTooLow
+		);
+		assert_ok!(LeverageStaking::flash_loan_deposit(
+			RuntimeOrigin::signed(1),
+			DOT,
+			FixedU128::from_inner(unit(900_000)),
+			Some(FixedU128::from_inner(1)),
+			Some(Permill::from_percent(50)),
+		));
+		assert_eq!(
+			AccountBorrows::<Test>::get(DOT, 1),
+			BorrowSnapshot { principal: 90_000, borrow_index: 1.into() },
+		);
+	});
+}
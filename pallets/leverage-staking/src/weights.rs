@@ -51,6 +51,7 @@ use frame_support::{
 
 pub trait WeightInfo {
 	fn flash_loan_deposit() -> Weight;
+	fn flash_loan_repay() -> Weight;
 }
 
 /// Weight functions for `leverage_staking`.
@@ -120,6 +121,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(41))
 			.saturating_add(T::DbWeight::get().writes(21))
 	}
+	/// Composed of the benchmarked `LendMarket::repay_borrow`, `LendMarket::redeem`,
+	/// `LendMarket::redeem_all` and `StableAsset::swap` weights, the calls a full close goes
+	/// through.
+	fn flash_loan_repay() -> Weight {
+		Weight::from_parts(1_211_765_000, 231326)
+			.saturating_add(T::DbWeight::get().reads(58))
+			.saturating_add(T::DbWeight::get().writes(29))
+	}
 }
 
 // For backwards compatibility and tests
@@ -188,4 +197,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(41))
 			.saturating_add(RocksDbWeight::get().writes(21))
 	}
+	/// Composed of the benchmarked `LendMarket::repay_borrow`, `LendMarket::redeem`,
+	/// `LendMarket::redeem_all` and `StableAsset::swap` weights, the calls a full close goes
+	/// through.
+	fn flash_loan_repay() -> Weight {
+		Weight::from_parts(1_211_765_000, 231326)
+			.saturating_add(RocksDbWeight::get().reads(58))
+			.saturating_add(RocksDbWeight::get().writes(29))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,121 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prices pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{Pallet as Prices, *};
+use bifrost_primitives::DOT;
+use frame_benchmarking::v1::{benchmarks, BenchmarkError};
+use sp_runtime::traits::One;
+use sp_std::vec;
+
+fn aggregation_config_mock() -> AggregationConfig {
+	AggregationConfig { twap_period: 3_600_000, max_deviation: Permill::from_percent(10) }
+}
+
+fn aggregated_asset_mock<T: Config>(asset_id: CurrencyId) {
+	AggregationConfigs::<T>::insert(asset_id, aggregation_config_mock());
+	let _ = T::Source::feed_value(
+		None,
+		asset_id,
+		TimeStampedPrice { value: Price::one(), timestamp: 0 },
+	);
+	AggregatedPrices::<T>::insert(
+		asset_id,
+		AggregatedPrice { median: Price::one(), twap: Price::one(), cumulative: 0, timestamp: 0 },
+	);
+	PriceObservations::<T>::insert(
+		asset_id,
+		BoundedVec::truncate_from(vec![
+			PriceObservation { timestamp: 0, cumulative: 0 };
+			T::MaxPriceObservations::get() as usize
+		]),
+	);
+}
+
+fn circuit_breaker_config_mock() -> CircuitBreakerConfig {
+	CircuitBreakerConfig {
+		max_age: 3_600_000,
+		max_change: Permill::from_percent(10),
+		resume_grace_period: 3_600_000,
+	}
+}
+
+fn paused_asset_mock<T: Config>(asset_id: CurrencyId) {
+	aggregated_asset_mock::<T>(asset_id);
+	CircuitBreakerConfigs::<T>::insert(asset_id, circuit_breaker_config_mock());
+	LastPrices::<T>::insert(asset_id, Price::one());
+	PausedAssets::<T>::insert(asset_id, (PauseReason::StalePrice, 0));
+}
+
+benchmarks! {
+	set_aggregation_config {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		aggregated_asset_mock::<T>(DOT);
+	}: _<T::RuntimeOrigin>(origin, DOT, AggregationConfig { twap_period: 7_200_000, ..aggregation_config_mock() })
+	verify {
+		assert_eq!(PriceObservations::<T>::get(DOT).len(), 0);
+	}
+
+	remove_aggregation_config {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		aggregated_asset_mock::<T>(DOT);
+	}: _<T::RuntimeOrigin>(origin, DOT)
+	verify {
+		assert_eq!(AggregationConfigs::<T>::get(DOT), None);
+	}
+
+	aggregate_prices {
+		let n in 0 .. T::MaxAggregatedAssets::get();
+		for i in 0 .. n {
+			aggregated_asset_mock::<T>(CurrencyId::ForeignAsset(i));
+		}
+	}: {
+		Prices::<T>::on_initialize(BlockNumberFor::<T>::from(1u32));
+	}
+
+	set_circuit_breaker_config {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, DOT, circuit_breaker_config_mock())
+	verify {
+		assert_eq!(CircuitBreakerConfigs::<T>::get(DOT), Some(circuit_breaker_config_mock()));
+	}
+
+	remove_circuit_breaker_config {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		paused_asset_mock::<T>(DOT);
+	}: _<T::RuntimeOrigin>(origin, DOT)
+	verify {
+		assert_eq!(PausedAssets::<T>::get(DOT), None);
+	}
+
+	resume_asset {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		paused_asset_mock::<T>(DOT);
+	}: _<T::RuntimeOrigin>(origin, DOT)
+	verify {
+		assert_eq!(PausedAssets::<T>::get(DOT), None);
+	}
+
+	check_circuit_breaker {
+		paused_asset_mock::<T>(DOT);
+		ResumedUntil::<T>::insert(DOT, 0);
+	}: {
+		Prices::<T>::check_circuit_breaker(DOT, circuit_breaker_config_mock(), 1);
+	}
+
+	impl_benchmark_test_suite!(Prices, crate::mock::new_test_ext(), crate::mock::Test);
+}
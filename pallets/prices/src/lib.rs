@@ -19,21 +19,36 @@
 //! This pallet provides the price from Oracle Module by implementing the
 //! `OraclePriceProvider` trait. In case of emergency, the price can be set directly
 //! by Oracle Collective.
+//!
+//! The price of an asset with an aggregation config is aggregated every block from the Oracle
+//! Module and the `PriceSources`. The median of the quoted prices is served, unless it deviates
+//! from the time-weighted average price by more than the configured maximum deviation, in
+//! which case the time-weighted average price is served. The time-weighted average price is
+//! taken over the twap period from the cumulative median price, observed at most
+//! `MaxPriceObservations` times per period.
+//!
+//! The circuit breaker of an asset pauses it when its oracle price gets older than the maximum
+//! age, or when its price changes by more than the maximum change within a block. The asset is
+//! resumed once a fresh price arrives, or by governance for the resume grace period. An asset
+//! without any price is not paused.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use bifrost_asset_registry::AssetMetadata;
 use bifrost_primitives::{
-	Balance, CurrencyId, CurrencyIdMapping, OraclePriceProvider, Price, PriceDetail,
+	Balance, CurrencyId, CurrencyIdMapping, Moment, OraclePriceProvider, Price, PriceDetail,
 	TimeStampedPrice, TokenInfo,
 };
-use frame_support::{dispatch::DispatchClass, pallet_prelude::*, transactional};
+use frame_support::{dispatch::DispatchClass, pallet_prelude::*, traits::UnixTime, transactional};
 use frame_system::pallet_prelude::*;
 use log;
 use orml_oracle::{DataFeeder, DataProvider, DataProviderExtended};
 pub use pallet::*;
 use pallet_traits::*;
-use sp_runtime::{traits::CheckedDiv, FixedU128};
+use sp_runtime::{
+	traits::{CheckedAdd, CheckedDiv, SaturatedConversion, Saturating, Zero},
+	FixedPointNumber, FixedU128, Permill,
+};
 use sp_std::vec::Vec;
 use xcm::v3::MultiLocation;
 
@@ -42,8 +57,65 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod sources;
 pub mod weights;
 
+pub use sources::{StablePoolPriceSource, VtokenPriceSource};
+
+/// How the price of an asset is aggregated from its sources.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AggregationConfig {
+	/// The period over which the time-weighted average price is taken, in milliseconds.
+	pub twap_period: Moment,
+	/// The maximum deviation of the median price from the time-weighted average price.
+	pub max_deviation: Permill,
+}
+
+/// The aggregated price of an asset.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AggregatedPrice {
+	/// The median of the prices quoted by the sources.
+	pub median: Price,
+	/// The time-weighted average of the median price over the twap period.
+	pub twap: Price,
+	/// The sum of the median prices weighted by the milliseconds they were served, wrapping
+	/// on overflow.
+	pub cumulative: u128,
+	/// The time of the aggregation, in milliseconds.
+	pub timestamp: Moment,
+}
+
+/// An observation of the cumulative median price of an asset.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PriceObservation {
+	/// The time of the observation, in milliseconds.
+	pub timestamp: Moment,
+	/// The cumulative median price at the time of the observation.
+	pub cumulative: u128,
+}
+
+/// When the circuit breaker of an asset pauses it.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CircuitBreakerConfig {
+	/// The maximum age of the oracle price, in milliseconds.
+	pub max_age: Moment,
+	/// The maximum change of the price from one block to the next.
+	pub max_change: Permill,
+	/// How long an asset resumed by governance is not paused again, in milliseconds.
+	pub resume_grace_period: Moment,
+}
+
+/// Why an asset is paused.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PauseReason {
+	/// The oracle price is older than the maximum age.
+	StalePrice,
+	/// The price changed by more than the maximum change within a block.
+	ExcessivePriceChange,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -85,6 +157,25 @@ pub mod pallet {
 			AssetMetadata<BalanceOf<Self>>,
 		>;
 
+		/// The price sources aggregated with `Source`.
+		type PriceSources: PriceSource<CurrencyId, Price>;
+
+		/// Time used for the time-weighted average prices.
+		type UnixTime: UnixTime;
+
+		/// The maximum number of assets whose price is aggregated.
+		#[pallet::constant]
+		type MaxAggregatedAssets: Get<u32>;
+
+		/// The maximum number of observations of the cumulative price of an asset within its
+		/// twap period.
+		#[pallet::constant]
+		type MaxPriceObservations: Get<u32>;
+
+		/// The maximum number of assets checked by the circuit breaker.
+		#[pallet::constant]
+		type MaxCircuitBreakerAssets: Get<u32>;
+
 		/// Weight information
 		type WeightInfo: WeightInfo;
 	}
@@ -96,6 +187,35 @@ pub mod pallet {
 		SetPrice(CurrencyId, Price),
 		/// Reset emergency price. \[asset_id\]
 		ResetPrice(CurrencyId),
+		/// Set the aggregation config of an asset. \[asset_id, config\]
+		AggregationConfigSet(CurrencyId, AggregationConfig),
+		/// Remove the aggregation config of an asset. \[asset_id\]
+		AggregationConfigRemoved(CurrencyId),
+		/// The median price deviates from the time-weighted average price by more than the
+		/// maximum deviation. \[asset_id, median, twap\]
+		PriceDeviationExceeded(CurrencyId, Price, Price),
+		/// Set the circuit breaker config of an asset. \[asset_id, config\]
+		CircuitBreakerConfigSet(CurrencyId, CircuitBreakerConfig),
+		/// Remove the circuit breaker config of an asset. \[asset_id\]
+		CircuitBreakerConfigRemoved(CurrencyId),
+		/// The circuit breaker paused an asset. \[asset_id, reason\]
+		AssetPaused(CurrencyId, PauseReason),
+		/// A paused asset is resumed. \[asset_id\]
+		AssetResumed(CurrencyId),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The aggregation config is invalid
+		InvalidAggregationConfig,
+		/// The prices of too many assets are aggregated
+		TooManyAggregatedAssets,
+		/// The circuit breaker config is invalid
+		InvalidCircuitBreakerConfig,
+		/// The circuit breaker checks too many assets
+		TooManyCircuitBreakerAssets,
+		/// The asset is not paused
+		AssetNotPaused,
 	}
 
 	/// Mapping from currency id to it's emergency price
@@ -108,6 +228,45 @@ pub mod pallet {
 	pub type ForeignToNativeAsset<T: Config> =
 		StorageMap<_, Twox64Concat, CurrencyId, CurrencyId, OptionQuery>;
 
+	/// Mapping from currency id to the config of its price aggregation
+	#[pallet::storage]
+	pub type AggregationConfigs<T: Config> =
+		CountedStorageMap<_, Twox64Concat, CurrencyId, AggregationConfig, OptionQuery>;
+
+	/// Mapping from currency id to its aggregated price
+	#[pallet::storage]
+	pub type AggregatedPrices<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, AggregatedPrice, OptionQuery>;
+
+	/// Mapping from currency id to the observations of its cumulative price, oldest first
+	#[pallet::storage]
+	pub type PriceObservations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		CurrencyId,
+		BoundedVec<PriceObservation, T::MaxPriceObservations>,
+		ValueQuery,
+	>;
+
+	/// Mapping from currency id to the config of its circuit breaker
+	#[pallet::storage]
+	pub type CircuitBreakerConfigs<T: Config> =
+		CountedStorageMap<_, Twox64Concat, CurrencyId, CircuitBreakerConfig, OptionQuery>;
+
+	/// Mapping from currency id to its price checked by the circuit breaker in the last block
+	#[pallet::storage]
+	pub type LastPrices<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
+
+	/// Mapping from paused currency id to the reason and the timestamp of the oracle price that
+	/// paused it
+	#[pallet::storage]
+	pub type PausedAssets<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, (PauseReason, Moment), OptionQuery>;
+
+	/// Mapping from currency id resumed by governance to the end of its resume grace period
+	#[pallet::storage]
+	pub type ResumedUntil<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Moment, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -132,6 +291,30 @@ pub mod pallet {
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let now = T::UnixTime::now().as_millis().saturated_into::<Moment>();
+			// The number of aggregated assets is bounded by `MaxAggregatedAssets`.
+			let mut weight = T::WeightInfo::aggregate_prices(AggregationConfigs::<T>::count());
+			for (asset_id, config) in AggregationConfigs::<T>::iter() {
+				Self::aggregate_price(asset_id, config, now);
+			}
+			// The number of assets checked by the circuit breaker is bounded by
+			// `MaxCircuitBreakerAssets`.
+			let circuit_breaker_assets = CircuitBreakerConfigs::<T>::count();
+			weight.saturating_accrue(
+				T::WeightInfo::check_circuit_breaker()
+					.saturating_mul(circuit_breaker_assets.into()),
+			);
+			for (asset_id, config) in CircuitBreakerConfigs::<T>::iter() {
+				Self::check_circuit_breaker(asset_id, config, now);
+			}
+
+			weight.saturating_add(T::DbWeight::get().reads(2))
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Set emergency price
@@ -178,6 +361,116 @@ pub mod pallet {
 			ForeignToNativeAsset::<T>::insert(foreign_asset_id, asset_id);
 			Ok(().into())
 		}
+
+		/// Set the price aggregation config of an asset
+		#[pallet::call_index(3)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_aggregation_config(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_aggregation_config(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+			config: AggregationConfig,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				!config.twap_period.is_zero() &&
+					config.twap_period >= T::MaxPriceObservations::get().into(),
+				Error::<T>::InvalidAggregationConfig
+			);
+			ensure!(
+				AggregationConfigs::<T>::contains_key(asset_id) ||
+					AggregationConfigs::<T>::count() < T::MaxAggregatedAssets::get(),
+				Error::<T>::TooManyAggregatedAssets
+			);
+			// The observations of the previous twap period are not comparable.
+			if AggregationConfigs::<T>::get(asset_id)
+				.map_or(false, |old| old.twap_period != config.twap_period)
+			{
+				PriceObservations::<T>::remove(asset_id);
+			}
+			AggregationConfigs::<T>::insert(asset_id, config);
+			Self::deposit_event(Event::AggregationConfigSet(asset_id, config));
+			Ok(().into())
+		}
+
+		/// Remove the price aggregation config of an asset
+		#[pallet::call_index(4)]
+		#[pallet::weight((<T as Config>::WeightInfo::remove_aggregation_config(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn remove_aggregation_config(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			AggregationConfigs::<T>::remove(asset_id);
+			AggregatedPrices::<T>::remove(asset_id);
+			PriceObservations::<T>::remove(asset_id);
+			Self::deposit_event(Event::AggregationConfigRemoved(asset_id));
+			Ok(().into())
+		}
+
+		/// Set the circuit breaker config of an asset
+		#[pallet::call_index(5)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_circuit_breaker_config(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_circuit_breaker_config(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+			config: CircuitBreakerConfig,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(!config.max_age.is_zero(), Error::<T>::InvalidCircuitBreakerConfig);
+			ensure!(
+				CircuitBreakerConfigs::<T>::contains_key(asset_id) ||
+					CircuitBreakerConfigs::<T>::count() < T::MaxCircuitBreakerAssets::get(),
+				Error::<T>::TooManyCircuitBreakerAssets
+			);
+			CircuitBreakerConfigs::<T>::insert(asset_id, config);
+			Self::deposit_event(Event::CircuitBreakerConfigSet(asset_id, config));
+			Ok(().into())
+		}
+
+		/// Remove the circuit breaker config of an asset, resuming it if paused
+		#[pallet::call_index(6)]
+		#[pallet::weight((<T as Config>::WeightInfo::remove_circuit_breaker_config(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn remove_circuit_breaker_config(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			CircuitBreakerConfigs::<T>::remove(asset_id);
+			LastPrices::<T>::remove(asset_id);
+			ResumedUntil::<T>::remove(asset_id);
+			if PausedAssets::<T>::take(asset_id).is_some() {
+				Self::deposit_event(Event::AssetResumed(asset_id));
+			}
+			Self::deposit_event(Event::CircuitBreakerConfigRemoved(asset_id));
+			Ok(().into())
+		}
+
+		/// Resume a paused asset, the current price becomes the reference of the next maximum
+		/// change check and the asset is not paused again within the resume grace period
+		#[pallet::call_index(7)]
+		#[pallet::weight((<T as Config>::WeightInfo::resume_asset(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn resume_asset(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			PausedAssets::<T>::take(asset_id).ok_or(Error::<T>::AssetNotPaused)?;
+			match Self::get_storage_price(&asset_id) {
+				Some(price) => LastPrices::<T>::insert(asset_id, price),
+				None => LastPrices::<T>::remove(asset_id),
+			}
+			if let Some(config) = CircuitBreakerConfigs::<T>::get(asset_id) {
+				let now = T::UnixTime::now().as_millis().saturated_into::<Moment>();
+				ResumedUntil::<T>::insert(asset_id, now.saturating_add(config.resume_grace_period));
+			}
+			Self::deposit_event(Event::AssetResumed(asset_id));
+			Ok(().into())
+		}
 	}
 }
 
@@ -198,9 +491,161 @@ impl<T: Config> Pallet<T> {
 
 	fn get_storage_price(asset_id: &CurrencyId) -> Option<Price> {
 		EmergencyPrice::<T>::get(asset_id)
+			.or_else(|| Self::get_aggregated_price(asset_id).map(|price| price.value))
 			.or_else(|| T::Source::get(asset_id).and_then(|price| Some(price.value)))
 	}
 
+	// Aggregate the prices quoted by the oracle and the price sources into the median price,
+	// and take the time-weighted average of the median price over the twap period.
+	fn aggregate_price(asset_id: CurrencyId, config: AggregationConfig, now: Moment) {
+		let mut prices = Vec::new();
+		if let Some(price) = T::Source::get(&asset_id) {
+			prices.push(price.value);
+		}
+		T::PriceSources::collect_prices(&asset_id, &mut prices);
+		let Some(median) = Self::median(prices) else {
+			return;
+		};
+
+		// The last median price was served since the last aggregation.
+		let last = AggregatedPrices::<T>::get(asset_id);
+		let cumulative = last.map_or(0, |last| {
+			let elapsed = now.saturating_sub(last.timestamp);
+			last.cumulative
+				.wrapping_add(last.median.into_inner().wrapping_mul(elapsed.into()))
+		});
+
+		// The cumulative price is observed once per interval, the observations cover the twap
+		// period.
+		let interval = config.twap_period / Moment::from(T::MaxPriceObservations::get().max(1));
+		let mut observations = PriceObservations::<T>::get(asset_id);
+		if observations
+			.last()
+			.map_or(true, |last| now.saturating_sub(last.timestamp) >= interval)
+		{
+			if !observations.is_empty() &&
+				observations.len() >= T::MaxPriceObservations::get() as usize
+			{
+				observations.remove(0);
+			}
+			let _ = observations.try_push(PriceObservation { timestamp: now, cumulative });
+			PriceObservations::<T>::insert(asset_id, &observations);
+		}
+
+		let window_start = now.saturating_sub(config.twap_period);
+		let twap = match observations.iter().find(|o| o.timestamp >= window_start) {
+			Some(first) if first.timestamp < now => Price::from_inner(
+				cumulative.wrapping_sub(first.cumulative) / u128::from(now - first.timestamp),
+			),
+			_ => last.map_or(median, |last| last.median),
+		};
+		if Self::exceeds_max_deviation(median, twap, config.max_deviation) {
+			Self::deposit_event(Event::PriceDeviationExceeded(asset_id, median, twap));
+		}
+
+		AggregatedPrices::<T>::insert(
+			asset_id,
+			AggregatedPrice { median, twap, cumulative, timestamp: now },
+		);
+	}
+
+	// Pause the asset if its oracle price is stale or its price changed too much since the last
+	// block, and resume it once a fresh price passes both checks.
+	fn check_circuit_breaker(asset_id: CurrencyId, config: CircuitBreakerConfig, now: Moment) {
+		// The emergency price set by governance overrides the circuit breaker.
+		if EmergencyPrice::<T>::contains_key(asset_id) {
+			LastPrices::<T>::remove(asset_id);
+			if PausedAssets::<T>::take(asset_id).is_some() {
+				Self::deposit_event(Event::AssetResumed(asset_id));
+			}
+			return;
+		}
+
+		let oracle_price = T::Source::get(&asset_id);
+		let aggregated_price = Self::get_aggregated_price(&asset_id);
+		// The age of an asset without oracle price is the age of its aggregated price, an asset
+		// without any price has no price to protect.
+		let (Some(price), Some(timestamp)) = (
+			aggregated_price.as_ref().or(oracle_price.as_ref()).map(|price| price.value),
+			oracle_price.as_ref().or(aggregated_price.as_ref()).map(|price| price.timestamp),
+		) else {
+			LastPrices::<T>::remove(asset_id);
+			return;
+		};
+		let price_changed = LastPrices::<T>::get(asset_id).map_or(false, |last_price| {
+			Self::exceeds_max_deviation(price, last_price, config.max_change)
+		});
+		let reason = if now.saturating_sub(timestamp) > config.max_age {
+			Some(PauseReason::StalePrice)
+		} else if price_changed {
+			Some(PauseReason::ExcessivePriceChange)
+		} else {
+			None
+		};
+		LastPrices::<T>::insert(asset_id, price);
+
+		// An asset resumed by governance is not paused again within the resume grace period.
+		let reason = match ResumedUntil::<T>::get(asset_id) {
+			Some(until) if now < until => None,
+			Some(_) => {
+				ResumedUntil::<T>::remove(asset_id);
+				reason
+			},
+			None => reason,
+		};
+
+		match (reason, PausedAssets::<T>::get(asset_id)) {
+			(Some(reason), None) => {
+				PausedAssets::<T>::insert(asset_id, (reason, timestamp));
+				Self::deposit_event(Event::AssetPaused(asset_id, reason));
+			},
+			(None, Some((_, paused_timestamp))) if timestamp > paused_timestamp => {
+				PausedAssets::<T>::remove(asset_id);
+				Self::deposit_event(Event::AssetResumed(asset_id));
+			},
+			_ => (),
+		}
+	}
+
+	// The aggregated price of an asset: the median price, or the time-weighted average price if
+	// the median deviates from it by more than the maximum deviation.
+	fn get_aggregated_price(asset_id: &CurrencyId) -> Option<TimeStampedPrice> {
+		let config = AggregationConfigs::<T>::get(asset_id)?;
+		let price = AggregatedPrices::<T>::get(asset_id)?;
+		let value = if Self::exceeds_max_deviation(price.median, price.twap, config.max_deviation) {
+			price.twap
+		} else {
+			price.median
+		};
+
+		Some(TimeStampedPrice { value, timestamp: price.timestamp })
+	}
+
+	fn exceeds_max_deviation(price: Price, reference: Price, max_deviation: Permill) -> bool {
+		let deviation = if price >= reference {
+			price.saturating_sub(reference)
+		} else {
+			reference.saturating_sub(price)
+		};
+
+		deviation > Price::from_inner(max_deviation * reference.into_inner())
+	}
+
+	fn median(mut prices: Vec<Price>) -> Option<Price> {
+		if prices.is_empty() {
+			return None;
+		}
+		prices.sort();
+		let mid = prices.len() / 2;
+		if prices.len() % 2 == 0 {
+			prices[mid - 1]
+				.checked_add(&prices[mid])
+				.and_then(|sum| sum.checked_div(&Price::saturating_from_integer(2)))
+		} else {
+			Some(prices[mid])
+		}
+	}
+
 	fn get_asset_mantissa(asset_id: &CurrencyId) -> Option<u128> {
 		10u128.checked_pow(
 			asset_id
@@ -240,13 +685,21 @@ impl<T: Config> OraclePriceProvider for Pallet<T> {
 		// if emergency price exists, return it
 		Self::get_emergency_price(asset_id).or_else(|| {
 			let mantissa = Self::get_asset_mantissa(asset_id)?;
-			T::Source::get(&T::RelayCurrency::get())
-				.and_then(|base_price| Self::get_special_asset_price(*asset_id, base_price))
+			Self::get_aggregated_price(asset_id)
+				.or_else(|| {
+					T::Source::get(&T::RelayCurrency::get())
+						.and_then(|base_price| Self::get_special_asset_price(*asset_id, base_price))
+				})
 				.or_else(|| T::Source::get(asset_id))
 				.and_then(|price| Self::normalize_detail_price(price, mantissa))
 		})
 	}
 
+	/// Whether the circuit breaker paused the asset.
+	fn is_price_paused(asset_id: &CurrencyId) -> bool {
+		PausedAssets::<T>::contains_key(asset_id)
+	}
+
 	/// Get the amount of currencies according to the input price data.
 	/// Parameters:
 	/// - `currency_in`: The currency to be converted.
@@ -21,6 +21,7 @@ use frame_support::{
 };
 use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy};
 use sp_runtime::{traits::IdentityLookup, FixedPointNumber};
+use std::{cell::RefCell, collections::BTreeMap, time::Duration};
 
 use bifrost_asset_registry::AssetIdMaps;
 pub use bifrost_primitives::{
@@ -51,8 +52,19 @@ impl frame_system::Config for Test {
 
 pub type TimeStampedPrice = orml_oracle::TimestampedValue<Price, Moment>;
 pub struct MockDataProvider;
+impl MockDataProvider {
+	pub fn set_price(asset_id: CurrencyId, price: TimeStampedPrice) {
+		ORACLE_PRICES.with(|oracle_prices| oracle_prices.borrow_mut().insert(asset_id, price));
+	}
+}
+
 impl DataProvider<CurrencyId, TimeStampedPrice> for MockDataProvider {
 	fn get(asset_id: &CurrencyId) -> Option<TimeStampedPrice> {
+		if let Some(price) =
+			ORACLE_PRICES.with(|oracle_prices| oracle_prices.borrow().get(asset_id).cloned())
+		{
+			return Some(price);
+		}
 		match *asset_id {
 			BNC => Some(TimeStampedPrice {
 				value: Price::from_inner(200_000_000_000_000_000),
@@ -109,6 +121,41 @@ impl DataFeeder<CurrencyId, TimeStampedPrice, AccountId> for MockDataProvider {
 	}
 }
 
+thread_local! {
+	static ORACLE_PRICES: RefCell<BTreeMap<CurrencyId, TimeStampedPrice>> =
+		RefCell::new(BTreeMap::new());
+	static SOURCE_PRICES: RefCell<BTreeMap<CurrencyId, Vec<Price>>> = RefCell::new(BTreeMap::new());
+	static NOW: RefCell<Moment> = RefCell::new(0);
+}
+
+pub struct MockPriceSource;
+impl MockPriceSource {
+	pub fn set_prices(asset_id: CurrencyId, prices: Vec<Price>) {
+		SOURCE_PRICES.with(|source_prices| source_prices.borrow_mut().insert(asset_id, prices));
+	}
+}
+
+impl PriceSource<CurrencyId, Price> for MockPriceSource {
+	fn collect_prices(asset_id: &CurrencyId, prices: &mut Vec<Price>) {
+		SOURCE_PRICES.with(|source_prices| {
+			prices.extend(source_prices.borrow().get(asset_id).cloned().unwrap_or_default())
+		});
+	}
+}
+
+pub struct MockUnixTime;
+impl MockUnixTime {
+	pub fn set_now(now: Moment) {
+		NOW.with(|n| *n.borrow_mut() = now);
+	}
+}
+
+impl UnixTime for MockUnixTime {
+	fn now() -> Duration {
+		Duration::from_millis(NOW.with(|n| *n.borrow()))
+	}
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
@@ -179,6 +226,11 @@ impl crate::Config for Test {
 	type RelayCurrency = RelayCurrency;
 	type CurrencyIdConvert = AssetIdMaps<Test>;
 	type Assets = Currencies;
+	type PriceSources = MockPriceSource;
+	type UnixTime = MockUnixTime;
+	type MaxAggregatedAssets = ConstU32<10>;
+	type MaxPriceObservations = ConstU32<10>;
+	type MaxCircuitBreakerAssets = ConstU32<10>;
 	type WeightInfo = ();
 }
 
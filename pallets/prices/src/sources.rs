@@ -0,0 +1,100 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Price sources aggregated with the oracle.
+
+use crate::*;
+use bifrost_primitives::VtokenMintingInterface;
+use bifrost_stable_pool::traits::StablePoolHandler;
+use sp_runtime::{
+	traits::{CheckedMul, Zero},
+	FixedPointNumber,
+};
+
+/// Quotes a vToken at the oracle price of its token multiplied by the vToken exchange rate.
+pub struct VtokenPriceSource<T, VtokenMinting>(PhantomData<(T, VtokenMinting)>);
+
+impl<T, VtokenMinting> PriceSource<CurrencyId, Price> for VtokenPriceSource<T, VtokenMinting>
+where
+	T: Config,
+	VtokenMinting: VtokenMintingInterface<T::AccountId, CurrencyId, Balance>,
+{
+	fn collect_prices(asset_id: &CurrencyId, prices: &mut Vec<Price>) {
+		if let Some(price) = Self::vtoken_price(asset_id) {
+			prices.push(price);
+		}
+	}
+}
+
+impl<T, VtokenMinting> VtokenPriceSource<T, VtokenMinting>
+where
+	T: Config,
+	VtokenMinting: VtokenMintingInterface<T::AccountId, CurrencyId, Balance>,
+{
+	fn vtoken_price(vtoken_id: &CurrencyId) -> Option<Price> {
+		let token_id = vtoken_id.to_token().ok()?;
+		let token_price = T::Source::get(&token_id)?.value;
+		// The token amount of one vToken, the vToken has the same decimals as its token.
+		let token_amount = VtokenMinting::get_currency_amount_by_v_currency_amount(
+			token_id,
+			*vtoken_id,
+			Price::DIV,
+		)
+		.ok()?;
+		if token_amount.is_zero() {
+			return None;
+		}
+
+		token_price.checked_mul(&Price::from_inner(token_amount))
+	}
+}
+
+/// Quotes an asset at the stable pool spot price against its token or vToken, valued at the
+/// oracle price of the latter.
+pub struct StablePoolPriceSource<T, StablePool>(PhantomData<(T, StablePool)>);
+
+impl<T, StablePool> PriceSource<CurrencyId, Price> for StablePoolPriceSource<T, StablePool>
+where
+	T: Config,
+	StablePool: StablePoolHandler<Balance = Balance, CurrencyId = CurrencyId>,
+{
+	fn collect_prices(asset_id: &CurrencyId, prices: &mut Vec<Price>) {
+		if let Some(price) = Self::spot_price(asset_id) {
+			prices.push(price);
+		}
+	}
+}
+
+impl<T, StablePool> StablePoolPriceSource<T, StablePool>
+where
+	T: Config,
+	StablePool: StablePoolHandler<Balance = Balance, CurrencyId = CurrencyId>,
+{
+	fn spot_price(asset_id: &CurrencyId) -> Option<Price> {
+		let quote_id = asset_id.to_token().or_else(|_| asset_id.to_vtoken()).ok()?;
+		let (pool_id, index_in, index_out) = StablePool::get_pool_id(asset_id, &quote_id)?;
+		let quote_price = T::Source::get(&quote_id)?.value;
+		// Swap one unit of the asset for the quote asset.
+		let amount_in = Pallet::<T>::get_asset_mantissa(asset_id)?;
+		let amount_out =
+			StablePool::get_swap_output(pool_id, index_in, index_out, amount_in).ok()?;
+		let quote_mantissa = Pallet::<T>::get_asset_mantissa(&quote_id)?;
+
+		quote_price.checked_mul(&Price::checked_from_rational(amount_out, quote_mantissa)?)
+	}
+}
@@ -16,7 +16,7 @@
 
 use super::*;
 use bifrost_asset_registry::AssetMetadata;
-use bifrost_primitives::{BNC, MANTA, VKSM};
+use bifrost_primitives::{BNC, DOT_U, MANTA, VKSM};
 use frame_support::{assert_noop, assert_ok};
 use mock::{RuntimeEvent, *};
 use sp_runtime::{traits::BadOrigin, FixedPointNumber};
@@ -293,3 +293,322 @@ fn get_oracle_amount_by_currency_and_amount_in() {
 		);
 	});
 }
+
+#[test]
+fn aggregated_price_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let config =
+			AggregationConfig { twap_period: 10_000, max_deviation: Permill::from_percent(10) };
+		assert_noop!(
+			Prices::set_aggregation_config(RuntimeOrigin::signed(CHARLIE), DOT, config),
+			BadOrigin
+		);
+		assert_noop!(
+			Prices::set_aggregation_config(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				AggregationConfig { twap_period: 0, ..config }
+			),
+			Error::<Test>::InvalidAggregationConfig
+		);
+		assert_ok!(Prices::set_aggregation_config(RuntimeOrigin::signed(ALICE), DOT, config));
+
+		// median of [100, 102, 90]
+		MockPriceSource::set_prices(
+			DOT,
+			vec![Price::saturating_from_integer(102), Price::saturating_from_integer(90)],
+		);
+		Prices::on_initialize(1);
+		assert_eq!(
+			AggregatedPrices::<Test>::get(DOT),
+			Some(AggregatedPrice {
+				median: Price::saturating_from_integer(100),
+				twap: Price::saturating_from_integer(100),
+				cumulative: 0,
+				timestamp: 0,
+			})
+		);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 0))
+		);
+
+		// median of [100, 105, 110], twap = 100 * 5_000 / 5_000 = 100
+		MockPriceSource::set_prices(
+			DOT,
+			vec![Price::saturating_from_integer(105), Price::saturating_from_integer(110)],
+		);
+		MockUnixTime::set_now(5_000);
+		Prices::on_initialize(2);
+		assert_eq!(
+			AggregatedPrices::<Test>::get(DOT).unwrap().twap,
+			Price::saturating_from_integer(100)
+		);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_500_000_000 * PRICE_ONE), 5_000))
+		);
+
+		// median of [100, 150, 160], twap = (100 * 5_000 + 105 * 5_000) / 10_000 = 102.5,
+		// the median deviates from the twap by more than 10%, the twap is served
+		MockPriceSource::set_prices(
+			DOT,
+			vec![Price::saturating_from_integer(150), Price::saturating_from_integer(160)],
+		);
+		MockUnixTime::set_now(10_000);
+		Prices::on_initialize(3);
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::PriceDeviationExceeded(
+			DOT,
+			Price::saturating_from_integer(150),
+			Price::saturating_from_rational(205, 2),
+		)));
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_250_000_000 * PRICE_ONE), 10_000))
+		);
+		assert_eq!(
+			Prices::get_oracle_amount_by_currency_and_amount_in(&DOT, 1, &DOT),
+			Some((
+				1,
+				Price::saturating_from_rational(205, 2),
+				Price::saturating_from_rational(205, 2)
+			))
+		);
+
+		// The prices older than the twap period are left out, twap = 150 * 10_000 / 10_000
+		MockUnixTime::set_now(20_000);
+		Prices::on_initialize(4);
+		assert_eq!(PriceObservations::<Test>::get(DOT).len(), 4);
+		assert_eq!(
+			AggregatedPrices::<Test>::get(DOT).unwrap().twap,
+			Price::saturating_from_integer(150)
+		);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(15_000_000_000 * PRICE_ONE), 20_000))
+		);
+
+		assert_ok!(Prices::remove_aggregation_config(RuntimeOrigin::signed(ALICE), DOT));
+		assert_eq!(AggregatedPrices::<Test>::get(DOT), None);
+		assert_eq!(PriceObservations::<Test>::get(DOT).len(), 0);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 0))
+		);
+	});
+}
+
+#[test]
+fn price_observations_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let config =
+			AggregationConfig { twap_period: 10_000, max_deviation: Permill::from_percent(10) };
+		assert_noop!(
+			Prices::set_aggregation_config(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				AggregationConfig { twap_period: 9, ..config }
+			),
+			Error::<Test>::InvalidAggregationConfig
+		);
+		assert_ok!(Prices::set_aggregation_config(RuntimeOrigin::signed(ALICE), DOT, config));
+
+		// The cumulative price is observed at most once every 1_000 milliseconds
+		for now in 0..30u64 {
+			MockUnixTime::set_now(now * 500);
+			Prices::on_initialize(now + 1);
+		}
+		let observations = PriceObservations::<Test>::get(DOT);
+		assert_eq!(observations.len(), 10);
+		assert_eq!(observations.first().unwrap().timestamp, 5_000);
+		assert_eq!(observations.last().unwrap().timestamp, 14_000);
+		assert_eq!(
+			AggregatedPrices::<Test>::get(DOT).unwrap().twap,
+			Price::saturating_from_integer(100)
+		);
+	});
+}
+
+#[test]
+fn aggregated_assets_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let config =
+			AggregationConfig { twap_period: 10_000, max_deviation: Permill::from_percent(10) };
+		for asset_id in 0..10u32 {
+			assert_ok!(Prices::set_aggregation_config(
+				RuntimeOrigin::signed(ALICE),
+				CurrencyId::ForeignAsset(asset_id),
+				config
+			));
+		}
+		assert_noop!(
+			Prices::set_aggregation_config(RuntimeOrigin::signed(ALICE), DOT, config),
+			Error::<Test>::TooManyAggregatedAssets
+		);
+		// The config of an aggregated asset can still be updated
+		assert_ok!(Prices::set_aggregation_config(
+			RuntimeOrigin::signed(ALICE),
+			CurrencyId::ForeignAsset(0),
+			AggregationConfig { max_deviation: Permill::from_percent(5), ..config }
+		));
+		assert_ok!(Prices::remove_aggregation_config(
+			RuntimeOrigin::signed(ALICE),
+			CurrencyId::ForeignAsset(0)
+		));
+		assert_ok!(Prices::set_aggregation_config(RuntimeOrigin::signed(ALICE), DOT, config));
+	});
+}
+
+#[test]
+fn circuit_breaker_assets_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let config = CircuitBreakerConfig {
+			max_age: 10_000,
+			max_change: Permill::from_percent(10),
+			resume_grace_period: 5_000,
+		};
+		for asset_id in 0..10u32 {
+			assert_ok!(Prices::set_circuit_breaker_config(
+				RuntimeOrigin::signed(ALICE),
+				CurrencyId::ForeignAsset(asset_id),
+				config
+			));
+		}
+		assert_noop!(
+			Prices::set_circuit_breaker_config(RuntimeOrigin::signed(ALICE), DOT, config),
+			Error::<Test>::TooManyCircuitBreakerAssets
+		);
+		// The config of a checked asset can still be updated
+		assert_ok!(Prices::set_circuit_breaker_config(
+			RuntimeOrigin::signed(ALICE),
+			CurrencyId::ForeignAsset(0),
+			CircuitBreakerConfig { max_change: Permill::from_percent(5), ..config }
+		));
+		assert_ok!(Prices::remove_circuit_breaker_config(
+			RuntimeOrigin::signed(ALICE),
+			CurrencyId::ForeignAsset(0)
+		));
+		assert_ok!(Prices::set_circuit_breaker_config(RuntimeOrigin::signed(ALICE), DOT, config));
+	});
+}
+
+#[test]
+fn circuit_breaker_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let config = CircuitBreakerConfig {
+			max_age: 10_000,
+			max_change: Permill::from_percent(10),
+			resume_grace_period: 5_000,
+		};
+		assert_noop!(
+			Prices::set_circuit_breaker_config(RuntimeOrigin::signed(CHARLIE), DOT, config),
+			BadOrigin
+		);
+		assert_noop!(
+			Prices::set_circuit_breaker_config(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				CircuitBreakerConfig { max_age: 0, ..config }
+			),
+			Error::<Test>::InvalidCircuitBreakerConfig
+		);
+		assert_ok!(Prices::set_circuit_breaker_config(RuntimeOrigin::signed(ALICE), DOT, config));
+
+		MockUnixTime::set_now(5_000);
+		Prices::on_initialize(1);
+		assert!(!Prices::is_price_paused(&DOT));
+		assert_eq!(LastPrices::<Test>::get(DOT), Some(Price::saturating_from_integer(100)));
+
+		// the price changes by 15% within a block
+		MockDataProvider::set_price(
+			DOT,
+			orml_oracle::TimestampedValue {
+				value: Price::saturating_from_integer(115),
+				timestamp: 6_000,
+			},
+		);
+		MockUnixTime::set_now(6_000);
+		Prices::on_initialize(2);
+		assert!(Prices::is_price_paused(&DOT));
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::AssetPaused(
+			DOT,
+			PauseReason::ExcessivePriceChange,
+		)));
+
+		// no fresh price arrives
+		MockUnixTime::set_now(7_000);
+		Prices::on_initialize(3);
+		assert!(Prices::is_price_paused(&DOT));
+
+		// a fresh price resumes the asset
+		MockDataProvider::set_price(
+			DOT,
+			orml_oracle::TimestampedValue {
+				value: Price::saturating_from_integer(116),
+				timestamp: 7_000,
+			},
+		);
+		Prices::on_initialize(4);
+		assert!(!Prices::is_price_paused(&DOT));
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::AssetResumed(DOT)));
+
+		// the price gets stale
+		MockUnixTime::set_now(17_001);
+		Prices::on_initialize(5);
+		assert_eq!(PausedAssets::<Test>::get(DOT), Some((PauseReason::StalePrice, 7_000)));
+
+		// governance overrides the circuit breaker
+		assert_noop!(Prices::resume_asset(RuntimeOrigin::signed(CHARLIE), DOT), BadOrigin);
+		assert_ok!(Prices::resume_asset(RuntimeOrigin::signed(ALICE), DOT));
+		assert!(!Prices::is_price_paused(&DOT));
+		assert_noop!(
+			Prices::resume_asset(RuntimeOrigin::signed(ALICE), DOT),
+			Error::<Test>::AssetNotPaused
+		);
+		// the asset is not paused again within the resume grace period
+		MockUnixTime::set_now(22_000);
+		Prices::on_initialize(6);
+		assert!(!Prices::is_price_paused(&DOT));
+		MockUnixTime::set_now(22_001);
+		Prices::on_initialize(7);
+		assert_eq!(PausedAssets::<Test>::get(DOT), Some((PauseReason::StalePrice, 7_000)));
+		assert_eq!(ResumedUntil::<Test>::get(DOT), None);
+		assert_ok!(Prices::set_price(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Price::saturating_from_integer(116)
+		));
+		Prices::on_initialize(8);
+		assert!(!Prices::is_price_paused(&DOT));
+	});
+}
+
+#[test]
+fn circuit_breaker_does_not_pause_asset_without_price() {
+	new_test_ext().execute_with(|| {
+		let config = CircuitBreakerConfig {
+			max_age: 10_000,
+			max_change: Permill::from_percent(10),
+			resume_grace_period: 5_000,
+		};
+		assert_ok!(Prices::set_circuit_breaker_config(RuntimeOrigin::signed(ALICE), DOT_U, config));
+
+		MockUnixTime::set_now(20_000);
+		Prices::on_initialize(1);
+		assert!(!Prices::is_price_paused(&DOT_U));
+		assert_eq!(LastPrices::<Test>::get(DOT_U), None);
+
+		// the age of the aggregated price is checked without an oracle price
+		assert_ok!(Prices::set_aggregation_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT_U,
+			AggregationConfig { twap_period: 10_000, max_deviation: Permill::from_percent(10) }
+		));
+		MockPriceSource::set_prices(DOT_U, vec![Price::saturating_from_integer(1)]);
+		Prices::on_initialize(2);
+		assert!(!Prices::is_price_paused(&DOT_U));
+		assert_eq!(LastPrices::<Test>::get(DOT_U), Some(Price::saturating_from_integer(1)));
+	});
+}
@@ -29,6 +29,13 @@ pub trait WeightInfo {
 	fn set_price() -> Weight;
 	fn reset_price() -> Weight;
 	fn set_foreign_asset() -> Weight;
+	fn set_aggregation_config() -> Weight;
+	fn remove_aggregation_config() -> Weight;
+	fn aggregate_prices(n: u32) -> Weight;
+	fn set_circuit_breaker_config() -> Weight;
+	fn remove_circuit_breaker_config() -> Weight;
+	fn resume_asset() -> Weight;
+	fn check_circuit_breaker() -> Weight;
 }
 
 /// Weights for pallet_prices using the Substrate node and recommended hardware.
@@ -49,6 +56,45 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	fn set_aggregation_config() -> Weight {
+		Weight::from_parts(27_416_000 as u64, 3979)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn remove_aggregation_config() -> Weight {
+		Weight::from_parts(29_532_000 as u64, 3979)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	/// The range of component `n` is `[0, 20]`.
+	fn aggregate_prices(n: u32) -> Weight {
+		Weight::from_parts(4_862_000 as u64, 1489)
+			.saturating_add(Weight::from_parts(53_174_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((7 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_parts(0, 9005).saturating_mul(n as u64))
+	}
+	fn set_circuit_breaker_config() -> Weight {
+		Weight::from_parts(21_904_000 as u64, 3979)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn remove_circuit_breaker_config() -> Weight {
+		Weight::from_parts(26_935_000 as u64, 3979)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	fn resume_asset() -> Weight {
+		Weight::from_parts(38_271_000 as u64, 4687)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn check_circuit_breaker() -> Weight {
+		Weight::from_parts(41_806_000 as u64, 4687)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -68,4 +114,43 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn set_aggregation_config() -> Weight {
+		Weight::from_parts(27_416_000 as u64, 3979)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn remove_aggregation_config() -> Weight {
+		Weight::from_parts(29_532_000 as u64, 3979)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	/// The range of component `n` is `[0, 20]`.
+	fn aggregate_prices(n: u32) -> Weight {
+		Weight::from_parts(4_862_000 as u64, 1489)
+			.saturating_add(Weight::from_parts(53_174_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((7 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(Weight::from_parts(0, 9005).saturating_mul(n as u64))
+	}
+	fn set_circuit_breaker_config() -> Weight {
+		Weight::from_parts(21_904_000 as u64, 3979)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn remove_circuit_breaker_config() -> Weight {
+		Weight::from_parts(26_935_000 as u64, 3979)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	fn resume_asset() -> Weight {
+		Weight::from_parts(38_271_000 as u64, 4687)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn check_circuit_breaker() -> Weight {
+		Weight::from_parts(41_806_000 as u64, 4687)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
 }
@@ -25,8 +25,8 @@ use bifrost_primitives::{
 	Amount, Balance, BifrostCrowdloanId, BifrostEntranceAccount, BifrostExitAccount,
 	BuybackPalletId,
 	CurrencyId::{self, *},
-	IncentivePoolAccount, MessageId, MockXcmExecutor, ParaId, SlpOperator, SlpxOperator,
-	StableAssetPalletId,
+	IncentivePoolAccount, InstantRedeemBufferPalletId, MessageId, MockXcmExecutor, ParaId,
+	SlpOperator, SlpxOperator, StableAssetPalletId,
 	TokenSymbol::{self, *},
 	ZenlinkPalletId, ASG, KSM, KUSD, VKSM,
 };
@@ -427,7 +427,11 @@ impl bifrost_vtoken_minting::Config for Test {
 	type BifrostSlpx = SlpxInterface;
 	type ChannelCommission = ();
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<100>;
+	type OffchainSignature = sp_runtime::MultiSignature;
+	type SigningPublicKey = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = ();
 }
 
@@ -23,24 +23,26 @@ use crate::{
 		AstarDappStakingLedger, AstarDappStakingPendingStatus, AstarValidator, DappStaking,
 	},
 	common::types::{Ledger, PendingStatus, StakingProtocol, Validator, XcmFee},
+	moonbeam_parachain_staking::types::{MoonbeamParachainStakingPendingStatus, ParachainStaking},
+	polkadot_staking::types::{PolkadotStaking, PolkadotStakingPendingStatus, RewardDestination},
 	Pallet as SlpV2,
 };
 use frame_benchmarking::v2::*;
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
 use sp_core::{crypto::Ss58Codec, H160};
-use sp_runtime::{AccountId32 as AccountId, Permill};
+use sp_runtime::{AccountId32 as AccountId, Percent, Permill};
 use xcm::v4::MaybeErrorCode;
 
 pub const STAKING_PROTOCOL: StakingProtocol = StakingProtocol::AstarDappStaking;
 
-fn do_set_protocol_configuration<T: Config>()
+fn do_set_protocol_configuration<T: Config>(staking_protocol: StakingProtocol)
 where
 	<T as frame_system::Config>::AccountId: From<sp_runtime::AccountId32>,
 {
 	assert_ok!(SlpV2::<T>::set_protocol_configuration(
 		RawOrigin::Root.into(),
-		STAKING_PROTOCOL,
+		staking_protocol,
 		ProtocolConfiguration {
 			xcm_task_fee: XcmFee { weight: Weight::zero(), fee: 100 },
 			protocol_fee_rate: Permill::from_perthousand(100),
@@ -169,7 +171,7 @@ mod benchmarks {
 				.into(),
 		);
 		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), STAKING_PROTOCOL, None));
-		do_set_protocol_configuration::<T>();
+		do_set_protocol_configuration::<T>(STAKING_PROTOCOL);
 		#[extrinsic_call]
 		_(RawOrigin::Root, STAKING_PROTOCOL, delegator, 1000);
 		Ok(())
@@ -203,7 +205,7 @@ mod benchmarks {
 				.into(),
 		);
 		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), STAKING_PROTOCOL, None));
-		do_set_protocol_configuration::<T>();
+		do_set_protocol_configuration::<T>(STAKING_PROTOCOL);
 		let task = DappStaking::Lock(100);
 		#[extrinsic_call]
 		_(RawOrigin::Root, delegator, task);
@@ -218,7 +220,7 @@ mod benchmarks {
 				.into(),
 		);
 		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), STAKING_PROTOCOL, None));
-		do_set_protocol_configuration::<T>();
+		do_set_protocol_configuration::<T>(STAKING_PROTOCOL);
 
 		PendingStatusByQueryId::<T>::insert(
 			0,
@@ -232,5 +234,84 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn polkadot_staking() -> Result<(), BenchmarkError> {
+		let staking_protocol = StakingProtocol::PolkadotStaking;
+		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), staking_protocol, None));
+		do_set_protocol_configuration::<T>(staking_protocol);
+		let delegator = DelegatorByStakingProtocolAndDelegatorIndex::<T>::get(staking_protocol, 0)
+			.ok_or(BenchmarkError::Stop("Delegator not found"))?;
+		let task = PolkadotStaking::Bond(100, RewardDestination::Staked);
+		#[extrinsic_call]
+		_(RawOrigin::Root, delegator, task);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn notify_polkadot_staking() -> Result<(), BenchmarkError> {
+		let staking_protocol = StakingProtocol::PolkadotStaking;
+		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), staking_protocol, None));
+		do_set_protocol_configuration::<T>(staking_protocol);
+		let delegator = DelegatorByStakingProtocolAndDelegatorIndex::<T>::get(staking_protocol, 0)
+			.ok_or(BenchmarkError::Stop("Delegator not found"))?;
+
+		PendingStatusByQueryId::<T>::insert(
+			0,
+			PendingStatus::PolkadotStaking(PolkadotStakingPendingStatus::Bond(delegator, 100)),
+		);
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0, xcm::v4::Response::DispatchResult(MaybeErrorCode::Success));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn moonbeam_parachain_staking() -> Result<(), BenchmarkError> {
+		let staking_protocol = StakingProtocol::MoonbeamParachainStaking;
+		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), staking_protocol, None));
+		do_set_protocol_configuration::<T>(staking_protocol);
+		let delegator = DelegatorByStakingProtocolAndDelegatorIndex::<T>::get(staking_protocol, 0)
+			.ok_or(BenchmarkError::Stop("Delegator not found"))?;
+		assert_ok!(SlpV2::<T>::add_validator(
+			RawOrigin::Root.into(),
+			staking_protocol,
+			delegator.clone(),
+			Validator::MoonbeamParachainStaking(H160::zero())
+		));
+		let task = ParachainStaking::Delegate(H160::zero(), 100, Percent::zero(), 0, 0, 0);
+		#[extrinsic_call]
+		_(RawOrigin::Root, delegator, task);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn notify_moonbeam_parachain_staking() -> Result<(), BenchmarkError> {
+		let staking_protocol = StakingProtocol::MoonbeamParachainStaking;
+		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), staking_protocol, None));
+		do_set_protocol_configuration::<T>(staking_protocol);
+		let delegator = DelegatorByStakingProtocolAndDelegatorIndex::<T>::get(staking_protocol, 0)
+			.ok_or(BenchmarkError::Stop("Delegator not found"))?;
+
+		PendingStatusByQueryId::<T>::insert(
+			0,
+			PendingStatus::MoonbeamParachainStaking(MoonbeamParachainStakingPendingStatus::Bond(
+				delegator,
+				H160::zero(),
+				100,
+			)),
+		);
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0, xcm::v4::Response::DispatchResult(MaybeErrorCode::Success));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_staking_protocol_info() -> Result<(), BenchmarkError> {
+		let mut info = SlpV2::<T>::get_staking_protocol_info(&STAKING_PROTOCOL);
+		info.utility_pallet_index = info.utility_pallet_index.saturating_add(1);
+		#[extrinsic_call]
+		_(RawOrigin::Root, STAKING_PROTOCOL.protocol_id(), Some(info));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(SlpV2, crate::mock::new_test_ext(), crate::mock::Test);
 }
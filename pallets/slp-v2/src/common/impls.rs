@@ -18,12 +18,13 @@
 
 use crate::{
 	common::types::{
-		Delegator, DelegatorIndex, StakingProtocol, AS_DERIVATIVE_CALL_INDEX,
+		Delegator, DelegatorIndex, StakingProtocol, StakingProtocolInfo, AS_DERIVATIVE_CALL_INDEX,
 		LIMITED_RESERVE_TRANSFER_ASSETS_CALL_INDEX,
 	},
 	Config, ConfigurationByStakingProtocol, DelegatorByStakingProtocolAndDelegatorIndex,
 	DelegatorIndexByStakingProtocolAndDelegator, Error, Event, LedgerByStakingProtocolAndDelegator,
-	NextDelegatorIndexByStakingProtocol, Pallet, ValidatorsByStakingProtocolAndDelegator,
+	NextDelegatorIndexByStakingProtocol, Pallet, StakingProtocolInfoByProtocolId,
+	ValidatorsByStakingProtocolAndDelegator,
 };
 use bifrost_primitives::{Balance, CurrencyId, VtokenMintingOperator};
 use frame_support::{
@@ -34,7 +35,7 @@ use frame_support::{
 use frame_system::pallet_prelude::OriginFor;
 use orml_traits::{MultiCurrency, XcmTransfer};
 use parity_scale_codec::{Decode, Encode};
-use sp_core::blake2_256;
+use sp_core::{blake2_256, H160};
 use sp_runtime::{
 	helpers_128bit::multiply_by_rational_with_rounding, traits::TrailingZeroInput, DispatchError,
 	Rounding, Saturating,
@@ -42,7 +43,7 @@ use sp_runtime::{
 use sp_std::{vec, vec::Vec};
 use xcm::{
 	latest::{OriginKind, QueryId, QueryResponseInfo, WeightLimit, WildAsset},
-	prelude::{AccountId32, Fungible, Here, ReportTransactStatus},
+	prelude::{AccountId32, Fungible, Here, Parachain, ReportTransactStatus},
 	v4::{opaque::Xcm, Asset, AssetFilter, AssetId, Assets, Location, SendXcm},
 	DoubleEncoded, VersionedAssets, VersionedLocation,
 };
@@ -125,7 +126,7 @@ impl<T: Config> Pallet<T> {
 		delegator: Delegator<T::AccountId>,
 	) -> DispatchResultWithPostInfo {
 		Self::ensure_delegator_exist(&staking_protocol, &delegator)?;
-		let currency_id = staking_protocol.info().currency_id;
+		let currency_id = Self::get_staking_protocol_info(&staking_protocol).currency_id;
 		let dest_beneficiary_location = staking_protocol
 			.get_dest_beneficiary_location::<T>(delegator.clone())
 			.ok_or(Error::<T>::UnsupportedStakingProtocol)?;
@@ -190,13 +191,25 @@ impl<T: Config> Pallet<T> {
 		Ok(account_id)
 	}
 
+	/// Implemented by Utility pallet to get derived ethereum address
+	pub fn derivative_account_id_20(
+		account_id: [u8; 20],
+		delegator_index: DelegatorIndex,
+	) -> Result<H160, Error<T>> {
+		let entropy = (b"modlpy/utilisuba", account_id, delegator_index).using_encoded(blake2_256);
+		let account_id: [u8; 20] = Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.map_err(|_| Error::<T>::DerivativeAccountIdFailed)?;
+		Ok(H160::from(account_id))
+	}
+
 	/// Wrapping any runtime call with as_derivative.
 	pub fn wrap_utility_as_derivative_call_data(
 		staking_protocol: &StakingProtocol,
 		delegator_index: DelegatorIndex,
 		call: Vec<u8>,
 	) -> Vec<u8> {
-		let utility_pallet_index = staking_protocol.info().utility_pallet_index;
+		let utility_pallet_index =
+			Self::get_staking_protocol_info(staking_protocol).utility_pallet_index;
 		let mut call_data = utility_pallet_index.encode();
 		call_data.extend(AS_DERIVATIVE_CALL_INDEX.encode());
 		// derivative index
@@ -212,8 +225,8 @@ impl<T: Config> Pallet<T> {
 		amount: Balance,
 		to: T::AccountId,
 	) -> Result<Vec<u8>, Error<T>> {
-		let xcm_pallet_index = staking_protocol.info().xcm_pallet_index;
-		let bifrost_dest_location = staking_protocol.info().bifrost_dest_location;
+		let StakingProtocolInfo { xcm_pallet_index, bifrost_dest_location, .. } =
+			Self::get_staking_protocol_info(staking_protocol);
 		let account_id =
 			to.encode().try_into().map_err(|_| Error::<T>::DerivativeAccountIdFailed)?;
 		let beneficiary = Location::new(0, AccountId32 { network: None, id: account_id });
@@ -249,8 +262,11 @@ impl<T: Config> Pallet<T> {
 	) -> Result<Xcm, Error<T>> {
 		let configuration = ConfigurationByStakingProtocol::<T>::get(staking_protocol)
 			.ok_or(Error::<T>::ConfigurationNotFound)?;
-		let fee_location = staking_protocol.info().remote_fee_location;
-		let refund_beneficiary = staking_protocol.info().remote_refund_beneficiary;
+		let StakingProtocolInfo {
+			remote_fee_location: fee_location,
+			remote_refund_beneficiary: refund_beneficiary,
+			..
+		} = Self::get_staking_protocol_info(staking_protocol);
 		let asset =
 			Asset { id: AssetId(fee_location), fun: Fungible(configuration.xcm_task_fee.fee) };
 		let assets: Assets = Assets::from(asset.clone());
@@ -278,11 +294,14 @@ impl<T: Config> Pallet<T> {
 		let notify_call_weight = notify_call.get_dispatch_info().weight;
 		let now = frame_system::Pallet::<T>::block_number();
 		let timeout = now.saturating_add(T::QueryTimeout::get());
-		let responder = staking_protocol.info().remote_dest_location;
+		let StakingProtocolInfo {
+			remote_dest_location: responder,
+			bifrost_dest_location: destination,
+			..
+		} = Self::get_staking_protocol_info(staking_protocol);
 		let query_id =
 			pallet_xcm::Pallet::<T>::new_notify_query(responder, notify_call, timeout, Here);
 		*mut_query_id = Some(query_id);
-		let destination = staking_protocol.info().bifrost_dest_location;
 		let report_transact_status = ReportTransactStatus(QueryResponseInfo {
 			destination,
 			query_id,
@@ -293,11 +312,33 @@ impl<T: Config> Pallet<T> {
 		Ok(xcm_message)
 	}
 
+	/// Wrapping the call with as_derivative, and with notify if there is a notify call.
+	pub fn get_query_id_and_xcm_message(
+		staking_protocol: &StakingProtocol,
+		call: Vec<u8>,
+		delegator_index: DelegatorIndex,
+		notify_call: Option<<T as Config>::RuntimeCall>,
+	) -> Result<(Option<QueryId>, Xcm), Error<T>> {
+		let call =
+			Self::wrap_utility_as_derivative_call_data(staking_protocol, delegator_index, call);
+		let mut query_id = None;
+		let xcm_message = match notify_call {
+			Some(notify_call) => Self::wrap_xcm_message_with_notify(
+				staking_protocol,
+				call,
+				notify_call,
+				&mut query_id,
+			)?,
+			None => Self::wrap_xcm_message(staking_protocol, call)?,
+		};
+		Ok((query_id, xcm_message))
+	}
+
 	pub fn send_xcm_message(
 		staking_protocol: StakingProtocol,
 		xcm_message: Xcm,
 	) -> Result<(), Error<T>> {
-		let dest_location = staking_protocol.info().remote_dest_location;
+		let dest_location = Self::get_staking_protocol_info(&staking_protocol).remote_dest_location;
 		let (ticket, _price) =
 			T::XcmSender::validate(&mut Some(dest_location), &mut Some(xcm_message))
 				.map_err(|_| Error::<T>::ValidatingFailed)?;
@@ -324,6 +365,53 @@ impl<T: Config> Pallet<T> {
 		Ok(vtoken_amount)
 	}
 
+	/// The information of the staking protocol registered by governance, or the built-in one.
+	pub fn get_staking_protocol_info(staking_protocol: &StakingProtocol) -> StakingProtocolInfo {
+		StakingProtocolInfoByProtocolId::<T>::get(staking_protocol.protocol_id())
+			.unwrap_or_else(|| staking_protocol.default_info())
+	}
+
+	/// Check the staking protocol information before governance registers it.
+	pub fn ensure_staking_protocol_info_valid(
+		staking_protocol: Option<&StakingProtocol>,
+		info: &StakingProtocolInfo,
+	) -> Result<(), Error<T>> {
+		// The remote utility and xcm pallets can't share an index.
+		ensure!(
+			info.utility_pallet_index != info.xcm_pallet_index,
+			Error::<T>::InvalidStakingProtocolInfo
+		);
+		// The staked token must have a vtoken.
+		ensure!(info.currency_id.to_vtoken().is_ok(), Error::<T>::InvalidStakingProtocolInfo);
+		ensure!(info.unlock_period.into_value() != 0, Error::<T>::InvalidStakingProtocolInfo);
+		// A built-in protocol keeps the time unit of its remote chain.
+		if let Some(staking_protocol) = staking_protocol {
+			ensure!(
+				info.unlock_period.add(staking_protocol.default_info().unlock_period).is_some(),
+				Error::<T>::InvalidStakingProtocolInfo
+			);
+		}
+		// The remote chain is the relay chain or a sibling parachain.
+		ensure!(
+			info.remote_dest_location.parent_count() == 1,
+			Error::<T>::InvalidStakingProtocolInfo
+		);
+		// The fee and refund locations are seen from the remote chain.
+		ensure!(
+			info.remote_fee_location.parent_count() == 0 &&
+				info.remote_refund_beneficiary.parent_count() == 0,
+			Error::<T>::InvalidStakingProtocolInfo
+		);
+		// Tokens sent back from the remote chain must arrive at Bifrost.
+		ensure!(
+			info.bifrost_dest_location.parent_count() <= 1 &&
+				info.bifrost_dest_location.last() ==
+					Some(&Parachain(T::ParachainId::get().into())),
+			Error::<T>::InvalidStakingProtocolInfo
+		);
+		Ok(())
+	}
+
 	pub fn ensure_governance_or_xcm_response(
 		origin: OriginFor<T>,
 	) -> Result<Location, DispatchError> {
@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	common::types::{Delegator, DelegatorIndex, StakingProtocolInfo},
+	common::types::{Delegator, DelegatorIndex, StakingProtocolId, StakingProtocolInfo},
 	Config, Error,
 };
 use bifrost_primitives::{
@@ -44,7 +44,8 @@ pub enum StakingProtocol {
 }
 
 impl StakingProtocol {
-	pub(crate) fn info(&self) -> StakingProtocolInfo {
+	/// The built-in information of the staking protocol, used until governance registers one.
+	pub(crate) fn default_info(&self) -> StakingProtocolInfo {
 		match self {
 			StakingProtocol::MoonriverParachainStaking => StakingProtocolInfo {
 				utility_pallet_index: 30,
@@ -78,6 +79,23 @@ impl StakingProtocol {
 		}
 	}
 
+	/// The identifier of the staking protocol in the registry.
+	pub fn protocol_id(&self) -> StakingProtocolId {
+		match self {
+			StakingProtocol::MoonriverParachainStaking => 0,
+			StakingProtocol::KusamaStaking => 1,
+		}
+	}
+
+	/// The built-in staking protocol with the identifier, if any.
+	pub fn from_protocol_id(protocol_id: StakingProtocolId) -> Option<Self> {
+		match protocol_id {
+			0 => Some(StakingProtocol::MoonriverParachainStaking),
+			1 => Some(StakingProtocol::KusamaStaking),
+			_ => None,
+		}
+	}
+
 	pub fn get_dest_beneficiary_location<T: Config>(
 		&self,
 		delegator: Delegator<T::AccountId>,
@@ -27,6 +27,8 @@ use xcm::v4::{Location, Weight};
 pub type DelegatorIndex = u16;
 /// Pallet index in remote chain.
 pub type PalletIndex = u8;
+/// Identifier of a staking protocol in the registry.
+pub type StakingProtocolId = u8;
 /// As derivative call index
 pub const AS_DERIVATIVE_CALL_INDEX: u8 = 1;
 /// Reserve transfer assets call index
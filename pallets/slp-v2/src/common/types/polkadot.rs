@@ -20,7 +20,13 @@ use crate::{
 	astar_dapp_staking::types::{
 		AstarDappStakingLedger, AstarDappStakingPendingStatus, AstarValidator, DappStaking,
 	},
-	common::types::{Delegator, DelegatorIndex, StakingProtocolInfo},
+	common::types::{Delegator, DelegatorIndex, StakingProtocolId, StakingProtocolInfo},
+	moonbeam_parachain_staking::types::{
+		MoonbeamParachainStakingLedger, MoonbeamParachainStakingPendingStatus, ParachainStaking,
+	},
+	polkadot_staking::types::{
+		PolkadotStaking, PolkadotStakingLedger, PolkadotStakingPendingStatus,
+	},
 	Config, Error,
 };
 use bifrost_primitives::{
@@ -49,7 +55,8 @@ pub enum StakingProtocol {
 }
 
 impl StakingProtocol {
-	pub(crate) fn info(&self) -> StakingProtocolInfo {
+	/// The built-in information of the staking protocol, used until governance registers one.
+	pub(crate) fn default_info(&self) -> StakingProtocolInfo {
 		match self {
 			StakingProtocol::AstarDappStaking => StakingProtocolInfo {
 				utility_pallet_index: 11,
@@ -99,6 +106,25 @@ impl StakingProtocol {
 		}
 	}
 
+	/// The identifier of the staking protocol in the registry.
+	pub fn protocol_id(&self) -> StakingProtocolId {
+		match self {
+			StakingProtocol::AstarDappStaking => 0,
+			StakingProtocol::MoonbeamParachainStaking => 1,
+			StakingProtocol::PolkadotStaking => 2,
+		}
+	}
+
+	/// The built-in staking protocol with the identifier, if any.
+	pub fn from_protocol_id(protocol_id: StakingProtocolId) -> Option<Self> {
+		match protocol_id {
+			0 => Some(StakingProtocol::AstarDappStaking),
+			1 => Some(StakingProtocol::MoonbeamParachainStaking),
+			2 => Some(StakingProtocol::PolkadotStaking),
+			_ => None,
+		}
+	}
+
 	pub fn get_dest_beneficiary_location<T: Config>(
 		&self,
 		delegator: Delegator<T::AccountId>,
@@ -142,7 +168,20 @@ impl StakingProtocol {
 				)?;
 				Ok(Delegator::Substrate(sub_sibling_account))
 			},
-			_ => Err(Error::<T>::UnsupportedStakingProtocol),
+			StakingProtocol::PolkadotStaking => {
+				let sub_child_account = crate::Pallet::<T>::derivative_account_id(
+					T::ParachainId::get().into_account_truncating(),
+					delegator_index,
+				)?;
+				Ok(Delegator::Substrate(sub_child_account))
+			},
+			StakingProtocol::MoonbeamParachainStaking => {
+				let sub_sibling_account = crate::Pallet::<T>::derivative_account_id_20(
+					Sibling::from(T::ParachainId::get()).into_account_truncating(),
+					delegator_index,
+				)?;
+				Ok(Delegator::Ethereum(sub_sibling_account))
+			},
 		}
 	}
 
@@ -150,7 +189,10 @@ impl StakingProtocol {
 		match self {
 			StakingProtocol::AstarDappStaking =>
 				Ledger::AstarDappStaking(AstarDappStakingLedger::default()),
-			_ => unreachable!(),
+			StakingProtocol::PolkadotStaking =>
+				Ledger::PolkadotStaking(PolkadotStakingLedger::default()),
+			StakingProtocol::MoonbeamParachainStaking =>
+				Ledger::MoonbeamParachainStaking(MoonbeamParachainStakingLedger::default()),
 		}
 	}
 }
@@ -167,15 +209,21 @@ pub enum Validator<AccountId> {
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
 pub enum Ledger {
 	AstarDappStaking(AstarDappStakingLedger),
+	PolkadotStaking(PolkadotStakingLedger),
+	MoonbeamParachainStaking(MoonbeamParachainStakingLedger),
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
 pub enum XcmTask<AccountId> {
 	AstarDappStaking(DappStaking<AccountId>),
+	PolkadotStaking(PolkadotStaking<AccountId>),
+	MoonbeamParachainStaking(ParachainStaking),
 }
 
 /// PendingStatus in slp protocol.
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
 pub enum PendingStatus<AccountId> {
 	AstarDappStaking(AstarDappStakingPendingStatus<AccountId>),
+	PolkadotStaking(PolkadotStakingPendingStatus<AccountId>),
+	MoonbeamParachainStaking(MoonbeamParachainStakingPendingStatus<AccountId>),
 }
@@ -30,8 +30,12 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::pallet_prelude::*;
+#[cfg(feature = "polkadot")]
+use moonbeam_parachain_staking::types::ParachainStaking;
 use orml_traits::{MultiCurrency, XcmTransfer};
 use polkadot_parachain_primitives::primitives::Id as ParaId;
+#[cfg(feature = "polkadot")]
+use polkadot_staking::types::PolkadotStaking;
 use sp_runtime::traits::AccountIdConversion;
 pub use weights::WeightInfo;
 use xcm::v4::{Location, SendXcm};
@@ -44,6 +48,10 @@ mod mock;
 #[cfg(feature = "polkadot")]
 mod astar_dapp_staking;
 mod common;
+#[cfg(feature = "polkadot")]
+mod moonbeam_parachain_staking;
+#[cfg(feature = "polkadot")]
+mod polkadot_staking;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -54,7 +62,10 @@ mod benchmarking;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use crate::common::types::{Ledger, PendingStatus, StakingProtocol, Validator, XcmTask};
+	use crate::common::types::{
+		Ledger, PendingStatus, StakingProtocol, StakingProtocolId, StakingProtocolInfo, Validator,
+		XcmTask,
+	};
 	use sp_runtime::{traits::BlockNumberProvider, Permill};
 	use xcm::latest::{MaybeErrorCode, QueryId, Response};
 
@@ -190,6 +201,12 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Information of the staking protocols registered by governance, keyed by protocol id. It
+	/// takes precedence over the built-in information of the protocol.
+	#[pallet::storage]
+	pub type StakingProtocolInfoByProtocolId<T: Config> =
+		StorageMap<_, Blake2_128Concat, StakingProtocolId, StakingProtocolInfo, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -307,6 +324,13 @@ pub mod pallet {
 			/// Amount
 			amount: Balance,
 		},
+		/// Set information for a specific staking protocol.
+		SetStakingProtocolInfo {
+			/// The identifier of the staking protocol in the registry.
+			protocol_id: StakingProtocolId,
+			/// The staking protocol information, None restores the built-in information.
+			info: Option<StakingProtocolInfo>,
+		},
 	}
 
 	#[pallet::error]
@@ -357,8 +381,26 @@ pub mod pallet {
 		UpdateTokenExchangeRateAmountTooLarge,
 		/// Invalid parameter.
 		InvalidParameter,
+		/// Invalid staking protocol information.
+		InvalidStakingProtocolInfo,
 		/// Not authorized.
 		NotAuthorized,
+		/// The amount exceeds the active amount of the delegator.
+		ExceedActiveAmount,
+		/// The amount exceeds the unlocking amount of the delegator.
+		ExceedUnlockingAmount,
+		/// The delegation was not found.
+		DelegationNotFound,
+		/// The delegation already exists.
+		DelegationAlreadyExists,
+		/// The delegation request was not found.
+		DelegationRequestNotFound,
+		/// The delegation request already exists.
+		DelegationRequestAlreadyExists,
+		/// The delegation request is not executable yet.
+		DelegationRequestNotDue,
+		/// The currency of a staking protocol cannot change while it has delegators.
+		CurrencyIdChangeNotAllowed,
 	}
 
 	#[pallet::hooks]
@@ -605,7 +647,7 @@ pub mod pallet {
 				Error::<T>::UpdateIntervalTooShort
 			);
 
-			let currency_id = staking_protocol.info().currency_id;
+			let currency_id = Self::get_staking_protocol_info(&staking_protocol).currency_id;
 
 			let time_unit = match time_uint_option {
 				Some(time_unit) => time_unit,
@@ -643,7 +685,7 @@ pub mod pallet {
 			amount: Balance,
 		) -> DispatchResultWithPostInfo {
 			Self::ensure_governance_or_operator(origin, staking_protocol)?;
-			let currency_id = staking_protocol.info().currency_id;
+			let currency_id = Self::get_staking_protocol_info(&staking_protocol).currency_id;
 
 			// Check the update token exchange rate limit.
 			let (update_interval, max_update_permill, protocol_fee_rate) =
@@ -701,6 +743,16 @@ pub mod pallet {
 						astar_dapp_staking_ledger.add_lock_amount(amount);
 						Ok(())
 					},
+					#[cfg(feature = "polkadot")]
+					Some(Ledger::PolkadotStaking(polkadot_staking_ledger)) => {
+						polkadot_staking_ledger.bond(amount);
+						Ok(())
+					},
+					#[cfg(feature = "polkadot")]
+					Some(Ledger::MoonbeamParachainStaking(moonbeam_parachain_staking_ledger)) =>
+						moonbeam_parachain_staking_ledger
+							.add_rewards(amount)
+							.map_err(|_| Error::<T>::DelegationNotFound),
 					_ => Err(Error::<T>::LedgerNotFound),
 				},
 			)?;
@@ -761,5 +813,136 @@ pub mod pallet {
 			}
 			Ok(().into())
 		}
+
+		/// Manipulate a delegator to perform relay chain staking related operations.
+		///
+		/// Can be called by governance or staking protocol operator.
+		///
+		/// Parameters
+		/// - `delegator`: Select the delegator which is existed.
+		/// - `task`: The relay chain staking task.
+		#[cfg(feature = "polkadot")]
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::polkadot_staking())]
+		pub fn polkadot_staking(
+			origin: OriginFor<T>,
+			delegator: Delegator<T::AccountId>,
+			task: PolkadotStaking<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_governance_or_operator(origin, StakingProtocol::PolkadotStaking)?;
+			Self::do_polkadot_staking(delegator, task)
+		}
+
+		/// Processing Xcm message execution results of relay chain staking.
+		///
+		/// Can be called by governance or xcm origin.
+		#[cfg(feature = "polkadot")]
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::notify_polkadot_staking())]
+		pub fn notify_polkadot_staking(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResultWithPostInfo {
+			let responder = Self::ensure_governance_or_xcm_response(origin)?;
+			let pending_status = PendingStatusByQueryId::<T>::get(query_id)
+				.ok_or(Error::<T>::PendingStatusNotFound)?;
+			// The ledger is synced once per query response.
+			if Response::DispatchResult(MaybeErrorCode::Success) == response {
+				Self::do_notify_polkadot_staking(responder, pending_status)?;
+			}
+			PendingStatusByQueryId::<T>::remove(query_id);
+			Ok(().into())
+		}
+
+		/// Manipulate a delegator to perform Moonbeam parachain staking related operations.
+		///
+		/// Can be called by governance or staking protocol operator.
+		///
+		/// Parameters
+		/// - `delegator`: Select the delegator which is existed.
+		/// - `task`: The parachain staking task.
+		#[cfg(feature = "polkadot")]
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::moonbeam_parachain_staking())]
+		pub fn moonbeam_parachain_staking(
+			origin: OriginFor<T>,
+			delegator: Delegator<T::AccountId>,
+			task: ParachainStaking,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_governance_or_operator(origin, StakingProtocol::MoonbeamParachainStaking)?;
+			Self::do_moonbeam_parachain_staking(delegator, task)
+		}
+
+		/// Processing Xcm message execution results of Moonbeam parachain staking.
+		///
+		/// Can be called by governance or xcm origin.
+		#[cfg(feature = "polkadot")]
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::notify_moonbeam_parachain_staking())]
+		pub fn notify_moonbeam_parachain_staking(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResultWithPostInfo {
+			let responder = Self::ensure_governance_or_xcm_response(origin)?;
+			let pending_status = PendingStatusByQueryId::<T>::get(query_id)
+				.ok_or(Error::<T>::PendingStatusNotFound)?;
+			// The ledger is synced once per query response.
+			if Response::DispatchResult(MaybeErrorCode::Success) == response {
+				Self::do_notify_moonbeam_parachain_staking(responder, pending_status)?;
+			}
+			PendingStatusByQueryId::<T>::remove(query_id);
+			Ok(().into())
+		}
+
+		/// Set the information of a staking protocol, such as the remote pallet indices and
+		/// locations, so that changes on the remote chain do not need a runtime upgrade.
+		///
+		/// Can only be called by governance
+		///
+		/// Parameters
+		/// - `protocol_id`: The identifier of the staking protocol in the registry.
+		/// - `info`: The staking protocol information. If info is None, the built-in information
+		///   will be used.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_staking_protocol_info())]
+		pub fn set_staking_protocol_info(
+			origin: OriginFor<T>,
+			protocol_id: StakingProtocolId,
+			info: Option<StakingProtocolInfo>,
+		) -> DispatchResultWithPostInfo {
+			T::ControlOrigin::ensure_origin(origin)?;
+			let staking_protocol = StakingProtocol::from_protocol_id(protocol_id);
+			if let Some(ref info) = info {
+				Self::ensure_staking_protocol_info_valid(staking_protocol.as_ref(), info)?;
+			}
+			// A built-in protocol with delegators keeps its currency.
+			if let Some(staking_protocol) = staking_protocol {
+				let new_currency_id = match info {
+					Some(ref info) => info.currency_id,
+					None => staking_protocol.default_info().currency_id,
+				};
+				ensure!(
+					new_currency_id ==
+						Self::get_staking_protocol_info(&staking_protocol).currency_id ||
+						DelegatorByStakingProtocolAndDelegatorIndex::<T>::iter_prefix(
+							staking_protocol
+						)
+						.next()
+						.is_none(),
+					Error::<T>::CurrencyIdChangeNotAllowed
+				);
+			}
+			StakingProtocolInfoByProtocolId::<T>::mutate_exists(
+				protocol_id,
+				|storage_info| -> DispatchResultWithPostInfo {
+					ensure!(info.ne(storage_info), Error::<T>::InvalidParameter);
+					*storage_info = info.clone();
+					Self::deposit_event(Event::SetStakingProtocolInfo { protocol_id, info });
+					Ok(().into())
+				},
+			)
+		}
 	}
 }
@@ -20,8 +20,8 @@ use crate as slp_v2;
 use bifrost_asset_registry::AssetIdMaps;
 use bifrost_primitives::{
 	currency::DOT, Amount, Balance, BifrostEntranceAccount, BifrostExitAccount, BifrostFeeAccount,
-	BlockNumber, CommissionPalletId, CurrencyId, IncentivePoolAccount, MockXcmRouter,
-	MockXcmTransfer, SlpOperator, SlpxOperator, BNC,
+	BlockNumber, CommissionPalletId, CurrencyId, IncentivePoolAccount, InstantRedeemBufferPalletId,
+	MockXcmRouter, MockXcmTransfer, SlpOperator, SlpxOperator, BNC,
 };
 use frame_support::{
 	derive_impl,
@@ -248,7 +248,11 @@ impl bifrost_vtoken_minting::Config for Test {
 	type MoonbeamChainId = ConstU32<2023>;
 	type ChannelCommission = ();
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<100>;
+	type OffchainSignature = sp_runtime::MultiSignature;
+	type SigningPublicKey = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = ();
 }
 
@@ -0,0 +1,284 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	common::types::{Delegator, Ledger, PendingStatus, StakingProtocol, Validator, XcmTask},
+	moonbeam_parachain_staking::types::{
+		MoonbeamCall, MoonbeamDelegationAction, MoonbeamParachainStakingCall,
+		MoonbeamParachainStakingLedger, MoonbeamParachainStakingPendingStatus,
+		MoonbeamScheduledRequest, ParachainStaking,
+	},
+	Call, Config, ConfigurationByStakingProtocol, Error, Event,
+	LedgerByStakingProtocolAndDelegator, Pallet, PendingStatusByQueryId,
+	ValidatorsByStakingProtocolAndDelegator,
+};
+use bifrost_primitives::{TimeUnit, VtokenMintingOperator};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure};
+use parity_scale_codec::Encode;
+use sp_core::H160;
+use xcm::v4::Location;
+
+pub const MOONBEAM_PARACHAIN_STAKING: StakingProtocol = StakingProtocol::MoonbeamParachainStaking;
+
+impl<T: Config> Pallet<T> {
+	pub fn ensure_candidate_exist(
+		delegator: &Delegator<T::AccountId>,
+		candidate: &H160,
+	) -> Result<(), Error<T>> {
+		ensure!(
+			ValidatorsByStakingProtocolAndDelegator::<T>::get(
+				MOONBEAM_PARACHAIN_STAKING,
+				delegator
+			)
+			.contains(&Validator::MoonbeamParachainStaking(*candidate)),
+			Error::<T>::ValidatorNotFound
+		);
+		Ok(())
+	}
+
+	pub fn get_moonbeam_parachain_staking_ledger(
+		delegator: &Delegator<T::AccountId>,
+	) -> Result<MoonbeamParachainStakingLedger, Error<T>> {
+		match LedgerByStakingProtocolAndDelegator::<T>::get(MOONBEAM_PARACHAIN_STAKING, delegator) {
+			Some(Ledger::MoonbeamParachainStaking(ledger)) => Ok(ledger),
+			_ => Err(Error::<T>::LedgerNotFound),
+		}
+	}
+
+	fn get_moonbeam_time_unit_after_unlock_period() -> Result<TimeUnit, Error<T>> {
+		let currency_id = Self::get_staking_protocol_info(&MOONBEAM_PARACHAIN_STAKING).currency_id;
+		let current_time_unit = T::VtokenMinting::get_ongoing_time_unit(currency_id)
+			.ok_or(Error::<T>::TimeUnitNotFound)?;
+		let configuration = ConfigurationByStakingProtocol::<T>::get(MOONBEAM_PARACHAIN_STAKING)
+			.ok_or(Error::<T>::ConfigurationNotFound)?;
+		current_time_unit
+			.add(configuration.unlock_period)
+			.ok_or(Error::<T>::TimeUnitNotFound)
+	}
+
+	pub fn do_moonbeam_parachain_staking(
+		delegator: Delegator<T::AccountId>,
+		task: ParachainStaking,
+	) -> DispatchResultWithPostInfo {
+		let delegator_index =
+			Self::ensure_delegator_exist(&MOONBEAM_PARACHAIN_STAKING, &delegator)?;
+		let ledger = Self::get_moonbeam_parachain_staking_ledger(&delegator)?;
+		let (call, pending_status) = match task {
+			ParachainStaking::Delegate(
+				candidate,
+				amount,
+				auto_compound,
+				candidate_delegation_count,
+				candidate_auto_compounding_delegation_count,
+				delegation_count,
+			) => {
+				Self::ensure_candidate_exist(&delegator, &candidate)?;
+				ensure!(
+					ledger.delegation_amount(&candidate).is_none(),
+					Error::<T>::DelegationAlreadyExists
+				);
+				(
+					MoonbeamParachainStakingCall::DelegateWithAutoCompound(
+						candidate,
+						amount,
+						auto_compound,
+						candidate_delegation_count,
+						candidate_auto_compounding_delegation_count,
+						delegation_count,
+					),
+					MoonbeamParachainStakingPendingStatus::Bond(
+						delegator.clone(),
+						candidate,
+						amount,
+					),
+				)
+			},
+			ParachainStaking::DelegatorBondMore(candidate, amount) => {
+				Self::ensure_candidate_exist(&delegator, &candidate)?;
+				ensure!(
+					ledger.delegation_amount(&candidate).is_some(),
+					Error::<T>::DelegationNotFound
+				);
+				(
+					MoonbeamParachainStakingCall::DelegatorBondMore(candidate, amount),
+					MoonbeamParachainStakingPendingStatus::Bond(
+						delegator.clone(),
+						candidate,
+						amount,
+					),
+				)
+			},
+			ParachainStaking::ScheduleDelegatorBondLess(candidate, amount) => {
+				let delegation_amount =
+					ledger.delegation_amount(&candidate).ok_or(Error::<T>::DelegationNotFound)?;
+				ensure!(delegation_amount > amount, Error::<T>::ExceedActiveAmount);
+				ensure!(
+					ledger.request(&candidate).is_none(),
+					Error::<T>::DelegationRequestAlreadyExists
+				);
+				(
+					MoonbeamParachainStakingCall::ScheduleDelegatorBondLess(candidate, amount),
+					MoonbeamParachainStakingPendingStatus::ScheduleDelegatorBondLess(
+						delegator.clone(),
+						candidate,
+						amount,
+					),
+				)
+			},
+			ParachainStaking::ScheduleRevokeDelegation(candidate) => {
+				ensure!(
+					ledger.delegation_amount(&candidate).is_some(),
+					Error::<T>::DelegationNotFound
+				);
+				ensure!(
+					ledger.request(&candidate).is_none(),
+					Error::<T>::DelegationRequestAlreadyExists
+				);
+				(
+					MoonbeamParachainStakingCall::ScheduleRevokeDelegation(candidate),
+					MoonbeamParachainStakingPendingStatus::ScheduleRevokeDelegation(
+						delegator.clone(),
+						candidate,
+					),
+				)
+			},
+			ParachainStaking::ExecuteDelegationRequest(candidate) => {
+				let Delegator::Ethereum(delegator_account) = &delegator else {
+					return Err(Error::<T>::DelegatorNotFound.into());
+				};
+				let request =
+					ledger.request(&candidate).ok_or(Error::<T>::DelegationRequestNotFound)?;
+				let currency_id =
+					Self::get_staking_protocol_info(&MOONBEAM_PARACHAIN_STAKING).currency_id;
+				let current_time_unit = T::VtokenMinting::get_ongoing_time_unit(currency_id)
+					.ok_or(Error::<T>::TimeUnitNotFound)?;
+				ensure!(
+					request.when_executable <= current_time_unit,
+					Error::<T>::DelegationRequestNotDue
+				);
+				(
+					MoonbeamParachainStakingCall::ExecuteDelegationRequest(
+						*delegator_account,
+						candidate,
+					),
+					MoonbeamParachainStakingPendingStatus::ExecuteDelegationRequest(
+						delegator.clone(),
+						candidate,
+					),
+				)
+			},
+			ParachainStaking::CancelDelegationRequest(candidate) => {
+				ensure!(
+					ledger.request(&candidate).is_some(),
+					Error::<T>::DelegationRequestNotFound
+				);
+				(
+					MoonbeamParachainStakingCall::CancelDelegationRequest(candidate),
+					MoonbeamParachainStakingPendingStatus::CancelDelegationRequest(
+						delegator.clone(),
+						candidate,
+					),
+				)
+			},
+		};
+		let call = MoonbeamCall::ParachainStaking(call).encode();
+		let pending_status = PendingStatus::MoonbeamParachainStaking(pending_status);
+		let notify_call =
+			<T as Config>::RuntimeCall::from(Call::<T>::notify_moonbeam_parachain_staking {
+				query_id: 0,
+				response: Default::default(),
+			});
+		let (query_id, xcm_message) = Self::get_query_id_and_xcm_message(
+			&MOONBEAM_PARACHAIN_STAKING,
+			call,
+			delegator_index,
+			Some(notify_call),
+		)?;
+		if let Some(query_id) = query_id {
+			PendingStatusByQueryId::<T>::insert(query_id, pending_status.clone());
+		}
+		Self::send_xcm_message(MOONBEAM_PARACHAIN_STAKING, xcm_message)?;
+		Self::deposit_event(Event::<T>::SendXcmTask {
+			query_id,
+			delegator,
+			task: XcmTask::MoonbeamParachainStaking(task),
+			pending_status: Some(pending_status),
+			dest_location: Self::get_staking_protocol_info(&MOONBEAM_PARACHAIN_STAKING)
+				.remote_dest_location,
+		});
+		Ok(().into())
+	}
+
+	pub fn do_notify_moonbeam_parachain_staking(
+		responder: Location,
+		pending_status: PendingStatus<T::AccountId>,
+	) -> Result<(), Error<T>> {
+		let PendingStatus::MoonbeamParachainStaking(status) = pending_status.clone() else {
+			return Err(Error::<T>::PendingStatusNotFound);
+		};
+		let delegator = match status.clone() {
+			MoonbeamParachainStakingPendingStatus::Bond(delegator, _, _) |
+			MoonbeamParachainStakingPendingStatus::ScheduleDelegatorBondLess(delegator, _, _) |
+			MoonbeamParachainStakingPendingStatus::ScheduleRevokeDelegation(delegator, _) |
+			MoonbeamParachainStakingPendingStatus::ExecuteDelegationRequest(delegator, _) |
+			MoonbeamParachainStakingPendingStatus::CancelDelegationRequest(delegator, _) => delegator,
+		};
+		let mut ledger = Self::get_moonbeam_parachain_staking_ledger(&delegator)?;
+		match status {
+			MoonbeamParachainStakingPendingStatus::Bond(_, candidate, amount) =>
+				ledger.bond(candidate, amount).map_err(|_| Error::<T>::ValidatorsOverflow)?,
+			MoonbeamParachainStakingPendingStatus::ScheduleDelegatorBondLess(
+				_,
+				candidate,
+				amount,
+			) => ledger
+				.schedule_request(MoonbeamScheduledRequest {
+					candidate,
+					when_executable: Self::get_moonbeam_time_unit_after_unlock_period()?,
+					action: MoonbeamDelegationAction::Decrease(amount),
+				})
+				.map_err(|_| Error::<T>::UnlockRecordOverflow)?,
+			MoonbeamParachainStakingPendingStatus::ScheduleRevokeDelegation(_, candidate) => {
+				let amount =
+					ledger.delegation_amount(&candidate).ok_or(Error::<T>::DelegationNotFound)?;
+				ledger
+					.schedule_request(MoonbeamScheduledRequest {
+						candidate,
+						when_executable: Self::get_moonbeam_time_unit_after_unlock_period()?,
+						action: MoonbeamDelegationAction::Revoke(amount),
+					})
+					.map_err(|_| Error::<T>::UnlockRecordOverflow)?
+			},
+			MoonbeamParachainStakingPendingStatus::ExecuteDelegationRequest(_, candidate) => {
+				ledger
+					.execute_request(&candidate)
+					.ok_or(Error::<T>::DelegationRequestNotFound)?;
+			},
+			MoonbeamParachainStakingPendingStatus::CancelDelegationRequest(_, candidate) => {
+				ledger.cancel_request(&candidate).ok_or(Error::<T>::DelegationRequestNotFound)?;
+			},
+		};
+		LedgerByStakingProtocolAndDelegator::<T>::insert(
+			MOONBEAM_PARACHAIN_STAKING,
+			delegator,
+			Ledger::MoonbeamParachainStaking(ledger),
+		);
+		Self::deposit_event(Event::<T>::NotifyResponseReceived { responder, pending_status });
+		Ok(())
+	}
+}
@@ -0,0 +1,226 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::common::types::Delegator;
+use bifrost_primitives::{Balance, TimeUnit};
+use frame_support::{
+	pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo},
+	BoundedVec,
+};
+use sp_core::{ConstU32, H160};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, traits::Zero, Percent, Rounding, Saturating,
+};
+
+/// Maximum delegations of a delegator on Moonbeam.
+pub const MAX_DELEGATIONS: u32 = 100;
+
+/// Moonbeam parachain staking task.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum ParachainStaking {
+	/// Delegate the amount to a candidate with the auto-compound percent, followed by the weight
+	/// hints: candidate delegation count, candidate auto-compounding delegation count and
+	/// delegation count.
+	Delegate(H160, Balance, Percent, u32, u32, u32),
+	/// Bond more for an existing delegation.
+	DelegatorBondMore(H160, Balance),
+	/// Schedule to bond less for an existing delegation.
+	ScheduleDelegatorBondLess(H160, Balance),
+	/// Schedule to revoke an existing delegation.
+	ScheduleRevokeDelegation(H160),
+	/// Execute the due request of a delegation.
+	ExecuteDelegationRequest(H160),
+	/// Cancel the request of a delegation.
+	CancelDelegationRequest(H160),
+}
+
+/// Moonbeam extrinsic call.
+#[derive(Encode, Decode, Debug, Clone)]
+pub enum MoonbeamCall {
+	#[codec(index = 20)]
+	ParachainStaking(MoonbeamParachainStakingCall),
+}
+
+/// Parachain staking extrinsic call.
+#[derive(Encode, Decode, Debug, Clone)]
+pub enum MoonbeamParachainStakingCall {
+	#[codec(index = 18)]
+	DelegateWithAutoCompound(H160, Balance, Percent, u32, u32, u32),
+	#[codec(index = 22)]
+	ScheduleRevokeDelegation(H160),
+	#[codec(index = 23)]
+	DelegatorBondMore(H160, Balance),
+	#[codec(index = 24)]
+	ScheduleDelegatorBondLess(H160, Balance),
+	#[codec(index = 25)]
+	ExecuteDelegationRequest(H160, H160),
+	#[codec(index = 26)]
+	CancelDelegationRequest(H160),
+}
+
+/// Moonbeam delegation.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct MoonbeamDelegation {
+	/// The candidate delegated to.
+	pub candidate: H160,
+	/// Amount of the delegation.
+	pub amount: Balance,
+}
+
+/// Moonbeam delegation request action.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum MoonbeamDelegationAction {
+	/// Revoke the whole delegation.
+	Revoke(Balance),
+	/// Decrease the delegation by the amount.
+	Decrease(Balance),
+}
+
+impl MoonbeamDelegationAction {
+	pub fn amount(&self) -> Balance {
+		match self {
+			MoonbeamDelegationAction::Revoke(amount) |
+			MoonbeamDelegationAction::Decrease(amount) => *amount,
+		}
+	}
+}
+
+/// Moonbeam scheduled delegation request.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct MoonbeamScheduledRequest {
+	/// The candidate of the delegation.
+	pub candidate: H160,
+	/// Time unit at which point the request can be executed.
+	pub when_executable: TimeUnit,
+	/// The request action.
+	pub action: MoonbeamDelegationAction,
+}
+
+/// Moonbeam parachain staking ledger.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, Default, PartialEq, Eq, TypeInfo)]
+pub struct MoonbeamParachainStakingLedger {
+	/// The total amount of all the delegations.
+	pub total: Balance,
+	/// The total amount of all the scheduled requests.
+	pub less_total: Balance,
+	/// The delegations of the delegator.
+	pub delegations: BoundedVec<MoonbeamDelegation, ConstU32<MAX_DELEGATIONS>>,
+	/// The scheduled requests, at most one per delegation.
+	pub requests: BoundedVec<MoonbeamScheduledRequest, ConstU32<MAX_DELEGATIONS>>,
+}
+
+impl MoonbeamParachainStakingLedger {
+	/// The amount delegated to the candidate.
+	pub fn delegation_amount(&self, candidate: &H160) -> Option<Balance> {
+		self.delegations
+			.iter()
+			.find(|delegation| delegation.candidate == *candidate)
+			.map(|delegation| delegation.amount)
+	}
+
+	/// The scheduled request of the delegation to the candidate.
+	pub fn request(&self, candidate: &H160) -> Option<&MoonbeamScheduledRequest> {
+		self.requests.iter().find(|request| request.candidate == *candidate)
+	}
+
+	/// Adds the amount to the delegation to the candidate, the delegation is returned if there
+	/// are too many delegations.
+	pub fn bond(&mut self, candidate: H160, amount: Balance) -> Result<(), MoonbeamDelegation> {
+		match self.delegations.iter_mut().find(|delegation| delegation.candidate == candidate) {
+			Some(delegation) => delegation.amount.saturating_accrue(amount),
+			None => self.delegations.try_push(MoonbeamDelegation { candidate, amount })?,
+		};
+		self.total.saturating_accrue(amount);
+		Ok(())
+	}
+
+	/// Adds the auto-compounded rewards to the delegations pro rata to their amounts, the
+	/// rounding remainder goes to the last delegation. The rewards are returned if there is no
+	/// delegation.
+	pub fn add_rewards(&mut self, rewards: Balance) -> Result<(), Balance> {
+		let last_index = self.delegations.len().checked_sub(1).ok_or(rewards)?;
+		let delegated = self
+			.delegations
+			.iter()
+			.fold(Balance::zero(), |acc, delegation| acc.saturating_add(delegation.amount));
+		let mut distributed = Balance::zero();
+		for (index, delegation) in self.delegations.iter_mut().enumerate() {
+			let reward = if index == last_index {
+				rewards.saturating_sub(distributed)
+			} else {
+				multiply_by_rational_with_rounding(
+					rewards,
+					delegation.amount,
+					delegated,
+					Rounding::Down,
+				)
+				.unwrap_or_default()
+			};
+			delegation.amount.saturating_accrue(reward);
+			distributed.saturating_accrue(reward);
+		}
+		self.total.saturating_accrue(rewards);
+		Ok(())
+	}
+
+	/// Records a scheduled request, the request is returned if there are too many requests.
+	pub fn schedule_request(
+		&mut self,
+		request: MoonbeamScheduledRequest,
+	) -> Result<(), MoonbeamScheduledRequest> {
+		let amount = request.action.amount();
+		self.requests.try_push(request)?;
+		self.less_total.saturating_accrue(amount);
+		Ok(())
+	}
+
+	/// Removes the scheduled request of the delegation to the candidate.
+	pub fn cancel_request(&mut self, candidate: &H160) -> Option<MoonbeamScheduledRequest> {
+		let index = self.requests.iter().position(|request| request.candidate == *candidate)?;
+		let request = self.requests.remove(index);
+		self.less_total.saturating_reduce(request.action.amount());
+		Some(request)
+	}
+
+	/// Removes the scheduled request of the delegation to the candidate and applies it to the
+	/// delegation.
+	pub fn execute_request(&mut self, candidate: &H160) -> Option<MoonbeamScheduledRequest> {
+		let request = self.cancel_request(candidate)?;
+		let amount = request.action.amount();
+		if let Some(delegation) = self
+			.delegations
+			.iter_mut()
+			.find(|delegation| delegation.candidate == *candidate)
+		{
+			delegation.amount.saturating_reduce(amount);
+		}
+		self.delegations.retain(|delegation| delegation.amount != 0);
+		self.total.saturating_reduce(amount);
+		Some(request)
+	}
+}
+
+/// PendingStatus in slp protocol.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum MoonbeamParachainStakingPendingStatus<AccountId> {
+	Bond(Delegator<AccountId>, H160, Balance),
+	ScheduleDelegatorBondLess(Delegator<AccountId>, H160, Balance),
+	ScheduleRevokeDelegation(Delegator<AccountId>, H160),
+	ExecuteDelegationRequest(Delegator<AccountId>, H160),
+	CancelDelegationRequest(Delegator<AccountId>, H160),
+}
@@ -0,0 +1,185 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	common::types::{Delegator, Ledger, PendingStatus, StakingProtocol, Validator, XcmTask},
+	polkadot_staking::types::{
+		PolkadotCall, PolkadotStaking, PolkadotStakingLedger, PolkadotStakingPendingStatus,
+		StakingCall, MAX_NOMINATIONS,
+	},
+	Call, Config, ConfigurationByStakingProtocol, Error, Event,
+	LedgerByStakingProtocolAndDelegator, Pallet, PendingStatusByQueryId,
+	ValidatorsByStakingProtocolAndDelegator,
+};
+use bifrost_primitives::VtokenMintingOperator;
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure};
+use parity_scale_codec::Encode;
+use sp_runtime::MultiAddress;
+use sp_std::vec::Vec;
+use xcm::v4::Location;
+
+pub const POLKADOT_STAKING: StakingProtocol = StakingProtocol::PolkadotStaking;
+
+impl<T: Config> Pallet<T> {
+	/// The validators of the delegator to nominate on the relay chain.
+	pub fn get_nominations(
+		delegator: &Delegator<T::AccountId>,
+	) -> Result<Vec<MultiAddress<T::AccountId, ()>>, Error<T>> {
+		let nominations: Vec<MultiAddress<T::AccountId, ()>> =
+			ValidatorsByStakingProtocolAndDelegator::<T>::get(POLKADOT_STAKING, delegator)
+				.into_iter()
+				.filter_map(|validator| match validator {
+					Validator::PolkadotStaking(account_id) => Some(MultiAddress::Id(account_id)),
+					_ => None,
+				})
+				.collect();
+		ensure!(!nominations.is_empty(), Error::<T>::ValidatorNotFound);
+		ensure!(nominations.len() <= MAX_NOMINATIONS as usize, Error::<T>::ValidatorsOverflow);
+		Ok(nominations)
+	}
+
+	pub fn get_polkadot_staking_ledger(
+		delegator: &Delegator<T::AccountId>,
+	) -> Result<PolkadotStakingLedger, Error<T>> {
+		match LedgerByStakingProtocolAndDelegator::<T>::get(POLKADOT_STAKING, delegator) {
+			Some(Ledger::PolkadotStaking(ledger)) => Ok(ledger),
+			_ => Err(Error::<T>::LedgerNotFound),
+		}
+	}
+
+	pub fn do_polkadot_staking(
+		delegator: Delegator<T::AccountId>,
+		task: PolkadotStaking<T::AccountId>,
+	) -> DispatchResultWithPostInfo {
+		let delegator_index = Self::ensure_delegator_exist(&POLKADOT_STAKING, &delegator)?;
+		let (call, pending_status) = match task.clone() {
+			PolkadotStaking::Bond(amount, reward_destination) => (
+				StakingCall::Bond(amount, reward_destination),
+				Some(PolkadotStakingPendingStatus::Bond(delegator.clone(), amount)),
+			),
+			PolkadotStaking::BondExtra(amount) => (
+				StakingCall::BondExtra(amount),
+				Some(PolkadotStakingPendingStatus::Bond(delegator.clone(), amount)),
+			),
+			PolkadotStaking::Unbond(amount) => {
+				let ledger = Self::get_polkadot_staking_ledger(&delegator)?;
+				ensure!(ledger.active >= amount, Error::<T>::ExceedActiveAmount);
+				(
+					StakingCall::Unbond(amount),
+					Some(PolkadotStakingPendingStatus::Unbond(delegator.clone(), amount)),
+				)
+			},
+			PolkadotStaking::Rebond(amount) => {
+				let ledger = Self::get_polkadot_staking_ledger(&delegator)?;
+				ensure!(ledger.unlocking_amount() >= amount, Error::<T>::ExceedUnlockingAmount);
+				(
+					StakingCall::Rebond(amount),
+					Some(PolkadotStakingPendingStatus::Rebond(delegator.clone(), amount)),
+				)
+			},
+			PolkadotStaking::Nominate =>
+				(StakingCall::Nominate(Self::get_nominations(&delegator)?), None),
+			PolkadotStaking::Chill => (StakingCall::Chill, None),
+			PolkadotStaking::PayoutStakers(validator, era) => {
+				ensure!(
+					ValidatorsByStakingProtocolAndDelegator::<T>::get(POLKADOT_STAKING, &delegator)
+						.contains(&Validator::PolkadotStaking(validator.clone())),
+					Error::<T>::ValidatorNotFound
+				);
+				(StakingCall::PayoutStakers(validator, era), None)
+			},
+			PolkadotStaking::WithdrawUnbonded(num_slashing_spans) => (
+				StakingCall::WithdrawUnbonded(num_slashing_spans),
+				Some(PolkadotStakingPendingStatus::WithdrawUnbonded(delegator.clone())),
+			),
+		};
+		let call = PolkadotCall::<T>::Staking(call).encode();
+		let pending_status = pending_status.map(PendingStatus::PolkadotStaking);
+		let notify_call = pending_status.as_ref().map(|_| {
+			<T as Config>::RuntimeCall::from(Call::<T>::notify_polkadot_staking {
+				query_id: 0,
+				response: Default::default(),
+			})
+		});
+		let (query_id, xcm_message) = Self::get_query_id_and_xcm_message(
+			&POLKADOT_STAKING,
+			call,
+			delegator_index,
+			notify_call,
+		)?;
+		if let Some(query_id) = query_id {
+			let pending_status = pending_status.clone().ok_or(Error::<T>::XcmFeeNotFound)?;
+			PendingStatusByQueryId::<T>::insert(query_id, pending_status);
+		}
+		Self::send_xcm_message(POLKADOT_STAKING, xcm_message)?;
+		Self::deposit_event(Event::<T>::SendXcmTask {
+			query_id,
+			delegator,
+			task: XcmTask::PolkadotStaking(task),
+			pending_status,
+			dest_location: Self::get_staking_protocol_info(&POLKADOT_STAKING).remote_dest_location,
+		});
+		Ok(().into())
+	}
+
+	pub fn do_notify_polkadot_staking(
+		responder: Location,
+		pending_status: PendingStatus<T::AccountId>,
+	) -> Result<(), Error<T>> {
+		let PendingStatus::PolkadotStaking(status) = pending_status.clone() else {
+			return Err(Error::<T>::PendingStatusNotFound);
+		};
+		let delegator = match status.clone() {
+			PolkadotStakingPendingStatus::Bond(delegator, _) |
+			PolkadotStakingPendingStatus::Unbond(delegator, _) |
+			PolkadotStakingPendingStatus::Rebond(delegator, _) |
+			PolkadotStakingPendingStatus::WithdrawUnbonded(delegator) => delegator,
+		};
+		let mut ledger = Self::get_polkadot_staking_ledger(&delegator)?;
+		match status {
+			PolkadotStakingPendingStatus::Bond(_, amount) => ledger.bond(amount),
+			PolkadotStakingPendingStatus::Unbond(_, amount) => {
+				let currency_id = Self::get_staking_protocol_info(&POLKADOT_STAKING).currency_id;
+				let current_time_unit = T::VtokenMinting::get_ongoing_time_unit(currency_id)
+					.ok_or(Error::<T>::TimeUnitNotFound)?;
+				let configuration = ConfigurationByStakingProtocol::<T>::get(POLKADOT_STAKING)
+					.ok_or(Error::<T>::ConfigurationNotFound)?;
+				let unlock_time = current_time_unit
+					.add(configuration.unlock_period)
+					.ok_or(Error::<T>::TimeUnitNotFound)?;
+				ledger
+					.unbond(amount, unlock_time)
+					.map_err(|_| Error::<T>::UnlockRecordOverflow)?;
+			},
+			PolkadotStakingPendingStatus::Rebond(_, amount) => ledger.rebond(amount),
+			PolkadotStakingPendingStatus::WithdrawUnbonded(_) => {
+				let currency_id = Self::get_staking_protocol_info(&POLKADOT_STAKING).currency_id;
+				let current_time_unit = T::VtokenMinting::get_ongoing_time_unit(currency_id)
+					.ok_or(Error::<T>::TimeUnitNotFound)?;
+				ledger.withdraw_unbonded(&current_time_unit);
+			},
+		};
+		LedgerByStakingProtocolAndDelegator::<T>::insert(
+			POLKADOT_STAKING,
+			delegator,
+			Ledger::PolkadotStaking(ledger),
+		);
+		Self::deposit_event(Event::<T>::NotifyResponseReceived { responder, pending_status });
+		Ok(())
+	}
+}
@@ -0,0 +1,185 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{common::types::Delegator, Config};
+use bifrost_primitives::{Balance, TimeUnit};
+use frame_support::{
+	pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo},
+	BoundedVec,
+};
+use sp_core::ConstU32;
+use sp_runtime::{MultiAddress, Saturating};
+use sp_std::vec::Vec;
+
+/// Maximum validators a delegator can nominate on the relay chain.
+pub const MAX_NOMINATIONS: u32 = 16;
+/// Maximum unlocking chunks of a delegator on the relay chain.
+pub const MAX_UNLOCKING_CHUNKS: u32 = 32;
+
+/// A destination account for staking rewards.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum RewardDestination<AccountId> {
+	/// Pay into the stash account, increasing the amount at stake accordingly.
+	Staked,
+	/// Pay into the stash account, not increasing the amount at stake.
+	Stash,
+	/// Pay into the controller account.
+	Controller,
+	/// Pay into a specified account.
+	Account(AccountId),
+	/// Receive no reward.
+	None,
+}
+
+/// Relay chain staking task.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum PolkadotStaking<AccountId> {
+	/// Bond the amount for the first time.
+	Bond(#[codec(compact)] Balance, RewardDestination<AccountId>),
+	/// Bond more of the free balance.
+	BondExtra(#[codec(compact)] Balance),
+	/// Schedule the amount to be unbonded.
+	Unbond(#[codec(compact)] Balance),
+	/// Rebond the amount that is unbonding.
+	Rebond(#[codec(compact)] Balance),
+	/// Nominate all the validators of the delegator.
+	Nominate,
+	/// Stop nominating.
+	Chill,
+	/// Pay out the rewards of a validator for an era.
+	PayoutStakers(AccountId, u32),
+	/// Withdraw the unbonded amount, with the number of slashing spans.
+	WithdrawUnbonded(u32),
+}
+
+/// Relay chain extrinsic call.
+#[derive(Encode, Decode, Debug, Clone)]
+pub enum PolkadotCall<T: Config> {
+	#[codec(index = 7)]
+	Staking(StakingCall<T::AccountId>),
+}
+
+/// Staking extrinsic call.
+#[derive(Encode, Decode, Debug, Clone)]
+pub enum StakingCall<AccountId> {
+	#[codec(index = 0)]
+	Bond(#[codec(compact)] Balance, RewardDestination<AccountId>),
+	#[codec(index = 1)]
+	BondExtra(#[codec(compact)] Balance),
+	#[codec(index = 2)]
+	Unbond(#[codec(compact)] Balance),
+	#[codec(index = 3)]
+	WithdrawUnbonded(u32),
+	#[codec(index = 5)]
+	Nominate(Vec<MultiAddress<AccountId, ()>>),
+	#[codec(index = 6)]
+	Chill,
+	#[codec(index = 18)]
+	PayoutStakers(AccountId, u32),
+	#[codec(index = 19)]
+	Rebond(#[codec(compact)] Balance),
+}
+
+/// Relay chain unlocking chunk.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct PolkadotUnlockChunk {
+	/// Amount of funds to be unlocked.
+	#[codec(compact)]
+	pub value: Balance,
+	/// Time unit at which point it'll be unlocked.
+	pub unlock_time: TimeUnit,
+}
+
+/// Relay chain staking ledger.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, Default, PartialEq, Eq, TypeInfo)]
+pub struct PolkadotStakingLedger {
+	/// The active amount plus all the unlocking chunks.
+	#[codec(compact)]
+	pub total: Balance,
+	/// The amount at stake in any forthcoming eras.
+	#[codec(compact)]
+	pub active: Balance,
+	/// The amounts becoming free, ordered by unlock time.
+	pub unlocking: BoundedVec<PolkadotUnlockChunk, ConstU32<MAX_UNLOCKING_CHUNKS>>,
+}
+
+impl PolkadotStakingLedger {
+	/// Adds the specified amount to the active amount.
+	pub fn bond(&mut self, amount: Balance) {
+		self.total.saturating_accrue(amount);
+		self.active.saturating_accrue(amount);
+	}
+
+	/// Moves the specified amount from the active amount to an unlocking chunk, the chunk is
+	/// returned if there are too many unlocking chunks.
+	pub fn unbond(
+		&mut self,
+		amount: Balance,
+		unlock_time: TimeUnit,
+	) -> Result<(), PolkadotUnlockChunk> {
+		match self.unlocking.iter_mut().find(|chunk| chunk.unlock_time == unlock_time) {
+			Some(chunk) => chunk.value.saturating_accrue(amount),
+			None => self.unlocking.try_push(PolkadotUnlockChunk { value: amount, unlock_time })?,
+		};
+		self.active.saturating_reduce(amount);
+		Ok(())
+	}
+
+	/// Moves the specified amount back to the active amount, starting from the latest unlocking
+	/// chunk.
+	pub fn rebond(&mut self, amount: Balance) {
+		let mut remaining = amount;
+		for chunk in self.unlocking.iter_mut().rev() {
+			let rebonded = chunk.value.min(remaining);
+			chunk.value.saturating_reduce(rebonded);
+			remaining.saturating_reduce(rebonded);
+		}
+		self.unlocking.retain(|chunk| chunk.value != 0);
+		self.active.saturating_accrue(amount.saturating_sub(remaining));
+	}
+
+	/// Removes the unlocking chunks that are unlocked at the current time unit.
+	pub fn withdraw_unbonded(&mut self, current_time_unit: &TimeUnit) {
+		let mut withdrawn: Balance = 0;
+		self.unlocking.retain(|chunk| {
+			if chunk.unlock_time <= *current_time_unit {
+				withdrawn.saturating_accrue(chunk.value);
+				false
+			} else {
+				true
+			}
+		});
+		self.total.saturating_reduce(withdrawn);
+	}
+
+	/// The total amount of the unlocking chunks.
+	pub fn unlocking_amount(&self) -> Balance {
+		self.unlocking
+			.iter()
+			.fold(0, |total: Balance, chunk| total.saturating_add(chunk.value))
+	}
+}
+
+/// PendingStatus in slp protocol.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum PolkadotStakingPendingStatus<AccountId> {
+	Bond(Delegator<AccountId>, Balance),
+	Unbond(Delegator<AccountId>, Balance),
+	Rebond(Delegator<AccountId>, Balance),
+	WithdrawUnbonded(Delegator<AccountId>),
+}
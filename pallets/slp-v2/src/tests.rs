@@ -26,12 +26,20 @@ use crate::{
 		XcmFee, XcmTask,
 	},
 	mock::*,
+	moonbeam_parachain_staking::types::{
+		MoonbeamDelegation, MoonbeamDelegationAction, MoonbeamParachainStakingLedger,
+		MoonbeamParachainStakingPendingStatus, MoonbeamScheduledRequest, ParachainStaking,
+	},
+	polkadot_staking::types::{
+		PolkadotStaking, PolkadotStakingLedger, PolkadotStakingPendingStatus, PolkadotUnlockChunk,
+		RewardDestination,
+	},
 	DelegatorByStakingProtocolAndDelegatorIndex, DelegatorIndexByStakingProtocolAndDelegator,
 	Error as SlpV2Error, Event as SlpV2Event, LastUpdateOngoingTimeUnitBlockNumber,
 	LedgerByStakingProtocolAndDelegator, NextDelegatorIndexByStakingProtocol,
-	ValidatorsByStakingProtocolAndDelegator,
+	StakingProtocolInfoByProtocolId, ValidatorsByStakingProtocolAndDelegator,
 };
-use bifrost_primitives::{CommissionPalletId, TimeUnit, VtokenMintingOperator, VASTR};
+use bifrost_primitives::{CommissionPalletId, TimeUnit, VtokenMintingOperator, DOT, VASTR};
 use cumulus_primitives_core::Weight;
 use frame_support::{assert_noop, assert_ok, traits::fungibles::Mutate};
 use orml_traits::MultiCurrency;
@@ -40,7 +48,7 @@ use polkadot_parachain_primitives::primitives::Sibling;
 use sp_core::{bytes::to_hex, crypto::Ss58Codec, H160};
 use sp_runtime::{
 	helpers_128bit::multiply_by_rational_with_rounding, traits::AccountIdConversion, BoundedVec,
-	Permill, Rounding,
+	Percent, Permill, Rounding,
 };
 use xcm::{
 	latest::{MaybeErrorCode, Parent, Response},
@@ -102,6 +110,138 @@ fn set_configuration_should_work() {
 	})
 }
 
+#[test]
+fn set_staking_protocol_info_should_work() {
+	new_test_ext().execute_with(|| {
+		let protocol_id = STAKING_PROTOCOL.protocol_id();
+		let default_info = SlpV2::get_staking_protocol_info(&STAKING_PROTOCOL);
+		let mut info = default_info.clone();
+		info.utility_pallet_index = 12;
+		assert_ok!(SlpV2::set_staking_protocol_info(
+			RuntimeOrigin::root(),
+			protocol_id,
+			Some(info.clone())
+		));
+		expect_event(SlpV2Event::SetStakingProtocolInfo { protocol_id, info: Some(info.clone()) });
+		assert_eq!(SlpV2::get_staking_protocol_info(&STAKING_PROTOCOL), info);
+		assert_eq!(
+			SlpV2::wrap_utility_as_derivative_call_data(&STAKING_PROTOCOL, 0, vec![]),
+			vec![12, 1, 0, 0]
+		);
+		assert_noop!(
+			SlpV2::set_staking_protocol_info(
+				RuntimeOrigin::root(),
+				protocol_id,
+				Some(info.clone())
+			),
+			SlpV2Error::<Test>::InvalidParameter
+		);
+
+		// Fall back to the built-in information.
+		assert_ok!(SlpV2::set_staking_protocol_info(RuntimeOrigin::root(), protocol_id, None));
+		assert_eq!(SlpV2::get_staking_protocol_info(&STAKING_PROTOCOL), default_info);
+	})
+}
+
+#[test]
+fn set_staking_protocol_info_should_work_for_unknown_protocol_id() {
+	new_test_ext().execute_with(|| {
+		let protocol_id = 100;
+		assert_eq!(StakingProtocol::from_protocol_id(protocol_id), None);
+		let info = SlpV2::get_staking_protocol_info(&STAKING_PROTOCOL);
+		assert_ok!(SlpV2::set_staking_protocol_info(
+			RuntimeOrigin::root(),
+			protocol_id,
+			Some(info.clone())
+		));
+		assert_eq!(StakingProtocolInfoByProtocolId::<Test>::get(protocol_id), Some(info));
+	})
+}
+
+#[test]
+fn set_staking_protocol_info_should_not_work_with_invalid_info() {
+	new_test_ext().execute_with(|| {
+		let protocol_id = STAKING_PROTOCOL.protocol_id();
+		let default_info = SlpV2::get_staking_protocol_info(&STAKING_PROTOCOL);
+
+		let mut info = default_info.clone();
+		info.xcm_pallet_index = info.utility_pallet_index;
+		assert_noop!(
+			SlpV2::set_staking_protocol_info(RuntimeOrigin::root(), protocol_id, Some(info)),
+			SlpV2Error::<Test>::InvalidStakingProtocolInfo
+		);
+
+		let mut info = default_info.clone();
+		info.currency_id = VASTR;
+		assert_noop!(
+			SlpV2::set_staking_protocol_info(RuntimeOrigin::root(), protocol_id, Some(info)),
+			SlpV2Error::<Test>::InvalidStakingProtocolInfo
+		);
+
+		let mut info = default_info.clone();
+		info.unlock_period = TimeUnit::Era(0);
+		assert_noop!(
+			SlpV2::set_staking_protocol_info(RuntimeOrigin::root(), protocol_id, Some(info)),
+			SlpV2Error::<Test>::InvalidStakingProtocolInfo
+		);
+
+		let mut info = default_info.clone();
+		info.unlock_period = TimeUnit::Round(9);
+		assert_noop!(
+			SlpV2::set_staking_protocol_info(RuntimeOrigin::root(), protocol_id, Some(info)),
+			SlpV2Error::<Test>::InvalidStakingProtocolInfo
+		);
+
+		let mut info = default_info.clone();
+		info.remote_dest_location = Location::new(2, [Parachain(2006)]);
+		assert_noop!(
+			SlpV2::set_staking_protocol_info(RuntimeOrigin::root(), protocol_id, Some(info)),
+			SlpV2Error::<Test>::InvalidStakingProtocolInfo
+		);
+
+		let mut info = default_info.clone();
+		info.remote_fee_location = Location::parent();
+		assert_noop!(
+			SlpV2::set_staking_protocol_info(RuntimeOrigin::root(), protocol_id, Some(info)),
+			SlpV2Error::<Test>::InvalidStakingProtocolInfo
+		);
+
+		let mut info = default_info;
+		info.bifrost_dest_location = Location::new(1, Parachain(2001));
+		assert_noop!(
+			SlpV2::set_staking_protocol_info(RuntimeOrigin::root(), protocol_id, Some(info)),
+			SlpV2Error::<Test>::InvalidStakingProtocolInfo
+		);
+	})
+}
+
+#[test]
+fn set_staking_protocol_info_currency_id_change_not_allowed() {
+	new_test_ext().execute_with(|| {
+		let protocol_id = STAKING_PROTOCOL.protocol_id();
+		let mut info = SlpV2::get_staking_protocol_info(&STAKING_PROTOCOL);
+		info.currency_id = DOT;
+		assert_ok!(SlpV2::add_delegator(RuntimeOrigin::root(), STAKING_PROTOCOL, None));
+		assert_noop!(
+			SlpV2::set_staking_protocol_info(
+				RuntimeOrigin::root(),
+				protocol_id,
+				Some(info.clone())
+			),
+			SlpV2Error::<Test>::CurrencyIdChangeNotAllowed
+		);
+
+		let delegator =
+			DelegatorByStakingProtocolAndDelegatorIndex::<Test>::get(STAKING_PROTOCOL, 0).unwrap();
+		assert_ok!(SlpV2::remove_delegator(RuntimeOrigin::root(), STAKING_PROTOCOL, delegator));
+		assert_ok!(SlpV2::set_staking_protocol_info(
+			RuntimeOrigin::root(),
+			protocol_id,
+			Some(info)
+		));
+	})
+}
+
 #[test]
 fn add_delegator_should_work() {
 	new_test_ext().execute_with(|| {
@@ -409,7 +549,8 @@ fn astar_dapp_staking_lock() {
 			delegator.clone(),
 			100,
 		));
-		let dest_location = STAKING_PROTOCOL.info().remote_dest_location;
+		let dest_location =
+			SlpV2::get_staking_protocol_info(&STAKING_PROTOCOL).remote_dest_location;
 
 		set_protocol_configuration();
 		assert_ok!(SlpV2::add_delegator(RuntimeOrigin::root(), STAKING_PROTOCOL, None));
@@ -458,7 +599,8 @@ fn repeat_astar_dapp_staking_lock() {
 			delegator.clone(),
 			200,
 		));
-		let dest_location = STAKING_PROTOCOL.info().remote_dest_location;
+		let dest_location =
+			SlpV2::get_staking_protocol_info(&STAKING_PROTOCOL).remote_dest_location;
 		set_protocol_configuration();
 
 		assert_ok!(SlpV2::add_delegator(RuntimeOrigin::root(), STAKING_PROTOCOL, None));
@@ -558,7 +700,8 @@ fn astar_dapp_staking_stake() {
 		let task = DappStaking::Stake(AstarValidator::Evm(H160::default()), 100);
 		let query_id = None;
 		let pending_status = None;
-		let dest_location = STAKING_PROTOCOL.info().remote_dest_location;
+		let dest_location =
+			SlpV2::get_staking_protocol_info(&STAKING_PROTOCOL).remote_dest_location;
 
 		assert_ok!(SlpV2::add_delegator(RuntimeOrigin::root(), STAKING_PROTOCOL, None));
 		assert_ok!(SlpV2::add_validator(
@@ -594,7 +737,8 @@ fn astar_dapp_staking_unstake() {
 		let task = DappStaking::Unstake(AstarValidator::Evm(H160::default()), 100);
 		let query_id = None;
 		let pending_status = None;
-		let dest_location = STAKING_PROTOCOL.info().remote_dest_location;
+		let dest_location =
+			SlpV2::get_staking_protocol_info(&STAKING_PROTOCOL).remote_dest_location;
 
 		assert_ok!(SlpV2::add_delegator(RuntimeOrigin::root(), STAKING_PROTOCOL, None));
 		assert_ok!(SlpV2::add_validator(
@@ -620,6 +764,540 @@ fn astar_dapp_staking_unstake() {
 	})
 }
 
+fn polkadot_staking_bond() -> Delegator<AccountId> {
+	let staking_protocol = StakingProtocol::PolkadotStaking;
+	assert_ok!(SlpV2::set_protocol_configuration(
+		RuntimeOrigin::root(),
+		staking_protocol,
+		CONFIGURATION
+	));
+	assert_ok!(SlpV2::add_delegator(RuntimeOrigin::root(), staking_protocol, None));
+	let delegator =
+		DelegatorByStakingProtocolAndDelegatorIndex::<Test>::get(staking_protocol, 0).unwrap();
+
+	assert_ok!(SlpV2::polkadot_staking(
+		RuntimeOrigin::root(),
+		delegator.clone(),
+		PolkadotStaking::Bond(100, RewardDestination::Staked)
+	));
+	assert_ok!(SlpV2::notify_polkadot_staking(
+		XcmOrigin::Response(Parent.into()).into(),
+		0,
+		Response::DispatchResult(MaybeErrorCode::Success)
+	));
+	delegator
+}
+
+#[test]
+fn polkadot_staking_bond_should_work() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::PolkadotStaking;
+		let task = PolkadotStaking::BondExtra(50);
+		let delegator = polkadot_staking_bond();
+		let pending_status = PendingStatus::PolkadotStaking(PolkadotStakingPendingStatus::Bond(
+			delegator.clone(),
+			50,
+		));
+
+		assert_ok!(SlpV2::polkadot_staking(RuntimeOrigin::root(), delegator.clone(), task));
+		expect_event(SlpV2Event::SendXcmTask {
+			query_id: Some(1),
+			delegator: delegator.clone(),
+			task: XcmTask::PolkadotStaking(task),
+			pending_status: Some(pending_status),
+			dest_location: SlpV2::get_staking_protocol_info(&staking_protocol).remote_dest_location,
+		});
+		assert_ok!(SlpV2::notify_polkadot_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			1,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+
+		assert_eq!(
+			LedgerByStakingProtocolAndDelegator::<Test>::get(staking_protocol, delegator.clone()),
+			Some(Ledger::PolkadotStaking(PolkadotStakingLedger {
+				total: 150,
+				active: 150,
+				unlocking: Default::default()
+			}))
+		);
+
+		// A replayed response does not bond again.
+		assert_noop!(
+			SlpV2::notify_polkadot_staking(
+				XcmOrigin::Response(Parent.into()).into(),
+				1,
+				Response::DispatchResult(MaybeErrorCode::Success)
+			),
+			SlpV2Error::<Test>::PendingStatusNotFound
+		);
+		assert_eq!(
+			LedgerByStakingProtocolAndDelegator::<Test>::get(staking_protocol, delegator),
+			Some(Ledger::PolkadotStaking(PolkadotStakingLedger {
+				total: 150,
+				active: 150,
+				unlocking: Default::default()
+			}))
+		);
+	})
+}
+
+#[test]
+fn polkadot_staking_unbond_and_rebond_should_work() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::PolkadotStaking;
+		let delegator = polkadot_staking_bond();
+		RelaychainBlockNumber::set(100);
+		assert_ok!(SlpV2::update_ongoing_time_unit(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			Some(TimeUnit::Era(1))
+		));
+
+		assert_noop!(
+			SlpV2::polkadot_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				PolkadotStaking::Unbond(101)
+			),
+			SlpV2Error::<Test>::ExceedActiveAmount
+		);
+		assert_ok!(SlpV2::polkadot_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			PolkadotStaking::Unbond(60)
+		));
+		assert_ok!(SlpV2::notify_polkadot_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			1,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		assert_eq!(
+			LedgerByStakingProtocolAndDelegator::<Test>::get(staking_protocol, delegator.clone()),
+			Some(Ledger::PolkadotStaking(PolkadotStakingLedger {
+				total: 100,
+				active: 40,
+				unlocking: BoundedVec::try_from(vec![PolkadotUnlockChunk {
+					value: 60,
+					unlock_time: TimeUnit::Era(10)
+				}])
+				.unwrap()
+			}))
+		);
+
+		assert_noop!(
+			SlpV2::polkadot_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				PolkadotStaking::Rebond(61)
+			),
+			SlpV2Error::<Test>::ExceedUnlockingAmount
+		);
+		assert_ok!(SlpV2::polkadot_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			PolkadotStaking::Rebond(20)
+		));
+		assert_ok!(SlpV2::notify_polkadot_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			2,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		assert_eq!(
+			LedgerByStakingProtocolAndDelegator::<Test>::get(staking_protocol, delegator),
+			Some(Ledger::PolkadotStaking(PolkadotStakingLedger {
+				total: 100,
+				active: 60,
+				unlocking: BoundedVec::try_from(vec![PolkadotUnlockChunk {
+					value: 40,
+					unlock_time: TimeUnit::Era(10)
+				}])
+				.unwrap()
+			}))
+		);
+	})
+}
+
+#[test]
+fn polkadot_staking_withdraw_unbonded_should_work() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::PolkadotStaking;
+		let delegator = polkadot_staking_bond();
+		RelaychainBlockNumber::set(100);
+		assert_ok!(SlpV2::update_ongoing_time_unit(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			Some(TimeUnit::Era(1))
+		));
+		assert_ok!(SlpV2::polkadot_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			PolkadotStaking::Unbond(60)
+		));
+		assert_ok!(SlpV2::notify_polkadot_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			1,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+
+		RelaychainBlockNumber::set(200);
+		assert_ok!(SlpV2::update_ongoing_time_unit(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			Some(TimeUnit::Era(10))
+		));
+		assert_ok!(SlpV2::polkadot_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			PolkadotStaking::WithdrawUnbonded(0)
+		));
+		assert_ok!(SlpV2::notify_polkadot_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			2,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		assert_eq!(
+			LedgerByStakingProtocolAndDelegator::<Test>::get(staking_protocol, delegator),
+			Some(Ledger::PolkadotStaking(PolkadotStakingLedger {
+				total: 40,
+				active: 40,
+				unlocking: Default::default()
+			}))
+		);
+	})
+}
+
+#[test]
+fn polkadot_staking_nominate() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::PolkadotStaking;
+		let delegator = polkadot_staking_bond();
+		assert_noop!(
+			SlpV2::polkadot_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				PolkadotStaking::Nominate
+			),
+			SlpV2Error::<Test>::ValidatorNotFound
+		);
+
+		assert_ok!(SlpV2::add_validator(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			delegator.clone(),
+			Validator::PolkadotStaking(AccountId::new([1u8; 32]))
+		));
+		assert_ok!(SlpV2::polkadot_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			PolkadotStaking::Nominate
+		));
+		expect_event(SlpV2Event::SendXcmTask {
+			query_id: None,
+			delegator,
+			task: XcmTask::PolkadotStaking(PolkadotStaking::Nominate),
+			pending_status: None,
+			dest_location: SlpV2::get_staking_protocol_info(&staking_protocol).remote_dest_location,
+		});
+	})
+}
+
+fn moonbeam_parachain_staking_delegate(candidate: H160) -> Delegator<AccountId> {
+	let staking_protocol = StakingProtocol::MoonbeamParachainStaking;
+	assert_ok!(SlpV2::set_protocol_configuration(
+		RuntimeOrigin::root(),
+		staking_protocol,
+		ProtocolConfiguration { unlock_period: TimeUnit::Round(2), ..CONFIGURATION }
+	));
+	assert_ok!(SlpV2::add_delegator(RuntimeOrigin::root(), staking_protocol, None));
+	let delegator =
+		DelegatorByStakingProtocolAndDelegatorIndex::<Test>::get(staking_protocol, 0).unwrap();
+	assert_ok!(SlpV2::add_validator(
+		RuntimeOrigin::root(),
+		staking_protocol,
+		delegator.clone(),
+		Validator::MoonbeamParachainStaking(candidate)
+	));
+
+	assert_ok!(SlpV2::moonbeam_parachain_staking(
+		RuntimeOrigin::root(),
+		delegator.clone(),
+		ParachainStaking::Delegate(candidate, 100, Percent::zero(), 0, 0, 0)
+	));
+	assert_ok!(SlpV2::notify_moonbeam_parachain_staking(
+		XcmOrigin::Response(Parent.into()).into(),
+		0,
+		Response::DispatchResult(MaybeErrorCode::Success)
+	));
+	delegator
+}
+
+#[test]
+fn moonbeam_parachain_staking_delegate_should_work() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::MoonbeamParachainStaking;
+		let candidate = H160::repeat_byte(1);
+		let delegator = moonbeam_parachain_staking_delegate(candidate);
+		assert!(matches!(delegator, Delegator::Ethereum(_)));
+
+		assert_noop!(
+			SlpV2::moonbeam_parachain_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				ParachainStaking::Delegate(candidate, 100, Percent::zero(), 0, 0, 0)
+			),
+			SlpV2Error::<Test>::DelegationAlreadyExists
+		);
+		assert_noop!(
+			SlpV2::moonbeam_parachain_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				ParachainStaking::Delegate(H160::repeat_byte(2), 100, Percent::zero(), 0, 0, 0)
+			),
+			SlpV2Error::<Test>::ValidatorNotFound
+		);
+
+		let task = ParachainStaking::DelegatorBondMore(candidate, 50);
+		assert_ok!(SlpV2::moonbeam_parachain_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			task
+		));
+		expect_event(SlpV2Event::SendXcmTask {
+			query_id: Some(1),
+			delegator: delegator.clone(),
+			task: XcmTask::MoonbeamParachainStaking(task),
+			pending_status: Some(PendingStatus::MoonbeamParachainStaking(
+				MoonbeamParachainStakingPendingStatus::Bond(delegator.clone(), candidate, 50),
+			)),
+			dest_location: SlpV2::get_staking_protocol_info(&staking_protocol).remote_dest_location,
+		});
+		assert_ok!(SlpV2::notify_moonbeam_parachain_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			1,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+
+		assert_eq!(
+			LedgerByStakingProtocolAndDelegator::<Test>::get(staking_protocol, delegator),
+			Some(Ledger::MoonbeamParachainStaking(MoonbeamParachainStakingLedger {
+				total: 150,
+				less_total: 0,
+				delegations: BoundedVec::try_from(vec![MoonbeamDelegation {
+					candidate,
+					amount: 150
+				}])
+				.unwrap(),
+				requests: Default::default(),
+			}))
+		);
+		assert_noop!(
+			SlpV2::notify_moonbeam_parachain_staking(
+				XcmOrigin::Response(Parent.into()).into(),
+				1,
+				Response::DispatchResult(MaybeErrorCode::Success)
+			),
+			SlpV2Error::<Test>::PendingStatusNotFound
+		);
+
+		assert_ok!(SlpV2::remove_validator(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			delegator.clone(),
+			Validator::MoonbeamParachainStaking(candidate)
+		));
+		assert_noop!(
+			SlpV2::moonbeam_parachain_staking(
+				RuntimeOrigin::root(),
+				delegator,
+				ParachainStaking::DelegatorBondMore(candidate, 50)
+			),
+			SlpV2Error::<Test>::ValidatorNotFound
+		);
+	})
+}
+
+#[test]
+fn moonbeam_parachain_staking_rewards_should_work() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::MoonbeamParachainStaking;
+		let candidate = H160::repeat_byte(1);
+		let other_candidate = H160::repeat_byte(2);
+		let delegator = moonbeam_parachain_staking_delegate(candidate);
+		assert_ok!(SlpV2::add_validator(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			delegator.clone(),
+			Validator::MoonbeamParachainStaking(other_candidate)
+		));
+		assert_ok!(SlpV2::moonbeam_parachain_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			ParachainStaking::Delegate(other_candidate, 200, Percent::zero(), 0, 0, 0)
+		));
+		assert_ok!(SlpV2::notify_moonbeam_parachain_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			1,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		assert_ok!(SlpV2::set_protocol_configuration(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			ProtocolConfiguration {
+				protocol_fee_rate: Permill::zero(),
+				max_update_token_exchange_rate: Permill::zero(),
+				..CONFIGURATION
+			}
+		));
+		RelaychainBlockNumber::set(100);
+
+		assert_ok!(SlpV2::update_token_exchange_rate(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			delegator.clone(),
+			31
+		));
+		let ledger = SlpV2::get_moonbeam_parachain_staking_ledger(&delegator).unwrap();
+		assert_eq!(ledger.total, 331);
+		assert_eq!(ledger.delegation_amount(&candidate), Some(110));
+		assert_eq!(ledger.delegation_amount(&other_candidate), Some(221));
+	})
+}
+
+#[test]
+fn moonbeam_parachain_staking_bond_less_should_work() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::MoonbeamParachainStaking;
+		let candidate = H160::repeat_byte(1);
+		let delegator = moonbeam_parachain_staking_delegate(candidate);
+		RelaychainBlockNumber::set(100);
+		assert_ok!(SlpV2::update_ongoing_time_unit(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			Some(TimeUnit::Round(1))
+		));
+
+		assert_noop!(
+			SlpV2::moonbeam_parachain_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				ParachainStaking::ScheduleDelegatorBondLess(candidate, 100)
+			),
+			SlpV2Error::<Test>::ExceedActiveAmount
+		);
+		assert_ok!(SlpV2::moonbeam_parachain_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			ParachainStaking::ScheduleDelegatorBondLess(candidate, 40)
+		));
+		assert_ok!(SlpV2::notify_moonbeam_parachain_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			1,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		let ledger = SlpV2::get_moonbeam_parachain_staking_ledger(&delegator).unwrap();
+		assert_eq!(ledger.less_total, 40);
+		assert_eq!(
+			ledger.request(&candidate),
+			Some(&MoonbeamScheduledRequest {
+				candidate,
+				when_executable: TimeUnit::Round(3),
+				action: MoonbeamDelegationAction::Decrease(40)
+			})
+		);
+
+		assert_noop!(
+			SlpV2::moonbeam_parachain_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				ParachainStaking::ExecuteDelegationRequest(candidate)
+			),
+			SlpV2Error::<Test>::DelegationRequestNotDue
+		);
+		RelaychainBlockNumber::set(200);
+		assert_ok!(SlpV2::update_ongoing_time_unit(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			Some(TimeUnit::Round(3))
+		));
+		assert_ok!(SlpV2::moonbeam_parachain_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			ParachainStaking::ExecuteDelegationRequest(candidate)
+		));
+		assert_ok!(SlpV2::notify_moonbeam_parachain_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			2,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+
+		let ledger = SlpV2::get_moonbeam_parachain_staking_ledger(&delegator).unwrap();
+		assert_eq!(ledger.total, 60);
+		assert_eq!(ledger.less_total, 0);
+		assert_eq!(ledger.delegation_amount(&candidate), Some(60));
+		assert_eq!(ledger.request(&candidate), None);
+	})
+}
+
+#[test]
+fn moonbeam_parachain_staking_revoke_and_cancel_should_work() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::MoonbeamParachainStaking;
+		let candidate = H160::repeat_byte(1);
+		let delegator = moonbeam_parachain_staking_delegate(candidate);
+		RelaychainBlockNumber::set(100);
+		assert_ok!(SlpV2::update_ongoing_time_unit(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			Some(TimeUnit::Round(1))
+		));
+
+		assert_noop!(
+			SlpV2::moonbeam_parachain_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				ParachainStaking::CancelDelegationRequest(candidate)
+			),
+			SlpV2Error::<Test>::DelegationRequestNotFound
+		);
+		assert_ok!(SlpV2::moonbeam_parachain_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			ParachainStaking::ScheduleRevokeDelegation(candidate)
+		));
+		assert_ok!(SlpV2::notify_moonbeam_parachain_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			1,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		let ledger = SlpV2::get_moonbeam_parachain_staking_ledger(&delegator).unwrap();
+		assert_eq!(ledger.less_total, 100);
+		assert_noop!(
+			SlpV2::moonbeam_parachain_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				ParachainStaking::ScheduleRevokeDelegation(candidate)
+			),
+			SlpV2Error::<Test>::DelegationRequestAlreadyExists
+		);
+
+		assert_ok!(SlpV2::moonbeam_parachain_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			ParachainStaking::CancelDelegationRequest(candidate)
+		));
+		assert_ok!(SlpV2::notify_moonbeam_parachain_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			2,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		let ledger = SlpV2::get_moonbeam_parachain_staking_ledger(&delegator).unwrap();
+		assert_eq!(ledger.total, 100);
+		assert_eq!(ledger.less_total, 0);
+		assert!(ledger.requests.is_empty());
+	})
+}
+
 #[test]
 fn staking_protocol_get_dest_beneficiary_location() {
 	new_test_ext().execute_with(|| {
@@ -736,7 +1414,7 @@ fn set_ledger_error() {
 fn update_ongoing_time_unit_should_work() {
 	new_test_ext().execute_with(|| {
 		let staking_protocol = StakingProtocol::AstarDappStaking;
-		let currency_id = staking_protocol.info().currency_id;
+		let currency_id = SlpV2::get_staking_protocol_info(&staking_protocol).currency_id;
 		set_protocol_configuration();
 		RelaychainDataProvider::set_block_number(100);
 		assert_ok!(SlpV2::update_ongoing_time_unit(
@@ -806,7 +1484,7 @@ fn update_ongoing_time_unit_update_interval_too_short() {
 fn update_token_exchange_rate_should_work() {
 	new_test_ext().execute_with(|| {
 		let staking_protocol = StakingProtocol::AstarDappStaking;
-		let currency_id = staking_protocol.info().currency_id;
+		let currency_id = SlpV2::get_staking_protocol_info(&staking_protocol).currency_id;
 		let delegator = Delegator::Substrate(
 			AccountId::from_ss58check("YLF9AnL6V1vQRfuiB832NXNGZYCPAWkKLLkh7cf3KwXhB9o").unwrap(),
 		);
@@ -896,7 +1574,7 @@ fn update_token_exchange_rate_should_work() {
 fn update_token_exchange_rate_limt_error() {
 	new_test_ext().execute_with(|| {
 		let staking_protocol = StakingProtocol::AstarDappStaking;
-		let currency_id = staking_protocol.info().currency_id;
+		let currency_id = SlpV2::get_staking_protocol_info(&staking_protocol).currency_id;
 		let delegator = Delegator::Substrate(
 			AccountId::from_ss58check("YLF9AnL6V1vQRfuiB832NXNGZYCPAWkKLLkh7cf3KwXhB9o").unwrap(),
 		);
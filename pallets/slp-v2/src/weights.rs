@@ -67,6 +67,11 @@ pub trait WeightInfo {
 	fn update_token_exchange_rate() -> Weight;
 	fn astar_dapp_staking() -> Weight;
 	fn notify_astar_dapp_staking() -> Weight;
+	fn polkadot_staking() -> Weight;
+	fn notify_polkadot_staking() -> Weight;
+	fn moonbeam_parachain_staking() -> Weight;
+	fn notify_moonbeam_parachain_staking() -> Weight;
+	fn set_staking_protocol_info() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -225,13 +230,15 @@ impl WeightInfo for () {
 	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
 	fn transfer_to() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `942`
-		//  Estimated: `3583`
+		//  Estimated: `8670`
 		// Minimum execution time: 56_532_000 picoseconds.
-		Weight::from_parts(57_959_000, 3583)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
+		Weight::from_parts(57_959_000, 8670)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (r:1 w:0)
@@ -248,13 +255,15 @@ impl WeightInfo for () {
 	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
 	fn transfer_back() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `596`
-		//  Estimated: `3567`
+		//  Estimated: `8654`
 		// Minimum execution time: 62_312_000 picoseconds.
-		Weight::from_parts(65_024_000, 3567)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
+		Weight::from_parts(65_024_000, 8654)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
@@ -275,13 +284,15 @@ impl WeightInfo for () {
 	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
 	fn update_ongoing_time_unit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `560`
-		//  Estimated: `3567`
+		//  Estimated: `8654`
 		// Minimum execution time: 52_270_000 picoseconds.
-		Weight::from_parts(53_152_000, 3567)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
+		Weight::from_parts(53_152_000, 8654)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `SlpV2::ConfigurationByStakingProtocol` (r:1 w:0)
@@ -304,13 +315,15 @@ impl WeightInfo for () {
 	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
 	fn update_token_exchange_rate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `755`
-		//  Estimated: `3717`
+		//  Estimated: `8804`
 		// Minimum execution time: 67_792_000 picoseconds.
-		Weight::from_parts(69_546_000, 3717)
-			.saturating_add(RocksDbWeight::get().reads(10_u64))
+		Weight::from_parts(69_546_000, 8804)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (r:1 w:0)
@@ -333,13 +346,15 @@ impl WeightInfo for () {
 	/// Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
 	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
 	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
 	fn astar_dapp_staking() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `836`
-		//  Estimated: `3567`
+		//  Estimated: `8654`
 		// Minimum execution time: 80_609_000 picoseconds.
-		Weight::from_parts(81_635_000, 3567)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+		Weight::from_parts(81_635_000, 8654)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `SlpV2::PendingStatusByQueryId` (r:1 w:0)
@@ -354,12 +369,151 @@ impl WeightInfo for () {
 	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Events` (r:1 w:1)
 	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
 	fn notify_astar_dapp_staking() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `567`
-		//  Estimated: `3717`
+		//  Estimated: `8804`
 		// Minimum execution time: 45_781_000 picoseconds.
-		Weight::from_parts(46_813_000, 3717)
+		Weight::from_parts(46_813_000, 8804)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (r:1 w:0)
+	/// Proof: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (r:1 w:0)
+	/// Proof: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:0)
+	/// Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(772), added: 3247, mode: `MaxEncodedLen`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::ConfigurationByStakingProtocol` (r:1 w:0)
+	/// Proof: `SlpV2::ConfigurationByStakingProtocol` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::PendingStatusByQueryId` (r:0 w:1)
+	/// Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
+	fn polkadot_staking() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `903`
+		//  Estimated: `9324`
+		// Minimum execution time: 84_127_000 picoseconds.
+		Weight::from_parts(85_410_000, 9324)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `SlpV2::PendingStatusByQueryId` (r:1 w:1)
+	/// Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:1)
+	/// Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(772), added: 3247, mode: `MaxEncodedLen`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
+	fn notify_polkadot_staking() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `9324`
+		// Minimum execution time: 47_236_000 picoseconds.
+		Weight::from_parts(48_391_000, 9324)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			// Removing the pending status is not covered by the benchmark run above.
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (r:1 w:0)
+	/// Proof: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (r:1 w:0)
+	/// Proof: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:0)
+	/// Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(6337), added: 8812, mode: `MaxEncodedLen`)
+	/// Storage: `SlpV2::ValidatorsByStakingProtocolAndDelegator` (r:1 w:0)
+	/// Proof: `SlpV2::ValidatorsByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(8772), added: 11247, mode: `MaxEncodedLen`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::ConfigurationByStakingProtocol` (r:1 w:0)
+	/// Proof: `SlpV2::ConfigurationByStakingProtocol` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::PendingStatusByQueryId` (r:0 w:1)
+	/// Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
+	fn moonbeam_parachain_staking() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `17324`
+		// Minimum execution time: 86_954_000 picoseconds.
+		Weight::from_parts(88_302_000, 17324)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `SlpV2::PendingStatusByQueryId` (r:1 w:1)
+	/// Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:1)
+	/// Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(6337), added: 8812, mode: `MaxEncodedLen`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
+	fn notify_moonbeam_parachain_staking() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `689`
+		//  Estimated: `14889`
+		// Minimum execution time: 49_107_000 picoseconds.
+		Weight::from_parts(50_264_000, 14889)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:1)
+	/// Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
+	/// Storage: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (r:1 w:0)
+	/// Proof: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::ExecutionPhase` (r:1 w:0)
+	/// Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventCount` (r:1 w:1)
+	/// Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_staking_protocol_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `238`
+		//  Estimated: `9128`
+		// Minimum execution time: 35_914_000 picoseconds.
+		Weight::from_parts(37_108_000, 9128)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
@@ -20,8 +20,8 @@ use crate::{
 	primitives::{FilecoinLedger, Ledger},
 	traits::StakingAgent,
 	AccountIdOf, BalanceOf, BoundedVec, Config, DelegatorLatestTuneRecord, DelegatorLedgers,
-	HostingFees, LedgerUpdateEntry, MinimumsAndMaximums, Pallet, TimeUnit, Validators,
-	ValidatorsByDelegator, ValidatorsByDelegatorUpdateEntry,
+	FilecoinTransfersIn, HostingFees, LedgerUpdateEntry, MinimumsAndMaximums, Pallet, TimeUnit,
+	Validators, ValidatorsByDelegator, ValidatorsByDelegatorUpdateEntry,
 };
 use bifrost_primitives::{CurrencyId, VtokenMintingOperator};
 use core::marker::PhantomData;
@@ -29,7 +29,7 @@ use frame_support::ensure;
 use orml_traits::MultiCurrency;
 use sp_core::Get;
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedSub, Zero},
+	traits::{CheckedAdd, CheckedSub, Saturating, Zero},
 	DispatchResult,
 };
 use sp_std::prelude::*;
@@ -103,6 +103,8 @@ impl<T: Config>
 		let filecoin_ledger = Ledger::<BalanceOf<T>>::Filecoin(ledger);
 
 		DelegatorLedgers::<T>::insert(currency_id, who, filecoin_ledger);
+		// The pledge is not a reward of the miner.
+		FilecoinTransfersIn::<T>::mutate(currency_id, who, |total| total.saturating_accrue(amount));
 		let query_id = Zero::zero();
 
 		Ok(query_id)
@@ -151,6 +153,10 @@ impl<T: Config>
 					}
 				},
 			)?;
+			// The pledge is not a reward of the miner.
+			FilecoinTransfersIn::<T>::mutate(currency_id, who, |total| {
+				total.saturating_accrue(amount)
+			});
 		} else {
 			Err(Error::<T>::Unexpected)?;
 		}
@@ -444,6 +450,9 @@ impl<T: Config>
 
 		ensure!(!token_amount.is_zero(), Error::<T>::AmountZero);
 
+		// The rewards must have been verified by the oracles, if there are any.
+		Pallet::<T>::use_filecoin_verified_rewards(currency_id, who, token_amount)?;
+
 		// issue the increased interest amount to the entrance account
 		// Get charged fee value
 		let (fee_permill, _beneficiary) =
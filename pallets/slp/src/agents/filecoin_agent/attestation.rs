@@ -0,0 +1,140 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	pallet::{Error, Event},
+	primitives::{FilecoinLedger, FilecoinMinerReport, Ledger},
+	AccountIdOf, BalanceOf, Config, DelegatorLedgers, DelegatorsMultilocation2Index,
+	FilecoinOracleSets, FilecoinTransfersIn, FilecoinVerifiedRewards, Pallet,
+	VerifiedFilecoinReports,
+};
+use bifrost_primitives::{CurrencyId, VtokenMintingOperator};
+use frame_support::ensure;
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::Encode;
+use sp_runtime::{
+	traits::{Saturating, Verify, Zero},
+	DispatchResult,
+};
+use sp_std::prelude::*;
+use xcm::v3::MultiLocation;
+
+pub const FILECOIN_REPORT_DOMAIN: &[u8] = b"bifrost/slp/filecoin-report";
+
+impl<T: Config> Pallet<T> {
+	/// The message signed by the oracles for a report. It is bound to this chain and currency.
+	pub fn filecoin_report_payload(
+		currency_id: CurrencyId,
+		report: &FilecoinMinerReport<BalanceOf<T>>,
+	) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		(FILECOIN_REPORT_DOMAIN, genesis_hash, currency_id, report).encode()
+	}
+
+	/// Verify a miner report against the oracle set of the currency, and apply it to the ledger
+	/// of the miner. The attested rewards are recorded so that they can be used to tune the
+	/// exchange rate.
+	pub fn do_verify_filecoin_report(
+		currency_id: CurrencyId,
+		report: FilecoinMinerReport<BalanceOf<T>>,
+		signatures: Vec<(AccountIdOf<T>, T::OracleSignature)>,
+	) -> DispatchResult {
+		let oracle_set =
+			FilecoinOracleSets::<T>::get(currency_id).ok_or(Error::<T>::OracleSetNotExist)?;
+		ensure!(
+			DelegatorsMultilocation2Index::<T>::contains_key(currency_id, report.miner),
+			Error::<T>::DelegatorNotExist
+		);
+		let current_time_unit = T::VtokenMinting::get_ongoing_time_unit(currency_id)
+			.ok_or(Error::<T>::TimeUnitNotExist)?;
+		ensure!(report.time_unit == current_time_unit, Error::<T>::InvalidReportTimeUnit);
+		let last_report = VerifiedFilecoinReports::<T>::get(currency_id, report.miner);
+		ensure!(
+			last_report.as_ref().map(|last| &last.time_unit) != Some(&report.time_unit),
+			Error::<T>::ReportAlreadyVerified
+		);
+
+		let payload = Self::filecoin_report_payload(currency_id, &report);
+		let mut signers: Vec<AccountIdOf<T>> = Vec::new();
+		for (signer, signature) in signatures.iter() {
+			if oracle_set.oracles.contains(signer) &&
+				!signers.contains(signer) &&
+				signature.verify(&payload[..], signer)
+			{
+				signers.push(signer.clone());
+			}
+		}
+		ensure!(
+			signers.len() >= oracle_set.threshold as usize,
+			Error::<T>::InsufficientAttestations
+		);
+
+		// The rewards can't exceed the balance growth since the last report, apart from the
+		// pledges known to have been transferred to the miner. The first report sets the baseline.
+		let rewards = report.rewards;
+		let rewards_limit = last_report
+			.map(|last| {
+				report
+					.balance
+					.saturating_sub(last.balance)
+					.saturating_sub(FilecoinTransfersIn::<T>::get(currency_id, report.miner))
+			})
+			.unwrap_or_else(Zero::zero);
+		ensure!(rewards <= rewards_limit, Error::<T>::InvalidReportRewards);
+		if !rewards.is_zero() {
+			FilecoinVerifiedRewards::<T>::mutate(currency_id, report.miner, |total| {
+				total.saturating_accrue(rewards)
+			});
+		}
+		DelegatorLedgers::<T>::insert(
+			currency_id,
+			report.miner,
+			Ledger::Filecoin(FilecoinLedger {
+				account: report.miner,
+				initial_pledge: report.initial_pledge,
+			}),
+		);
+		VerifiedFilecoinReports::<T>::insert(currency_id, report.miner, report.clone());
+		FilecoinTransfersIn::<T>::remove(currency_id, report.miner);
+
+		Self::deposit_event(Event::FilecoinReportVerified {
+			currency_id,
+			report,
+			signers,
+			rewards,
+		});
+		Ok(())
+	}
+
+	/// Use the verified rewards of a miner to tune the exchange rate. Without an oracle set the
+	/// rewards are not verified.
+	pub(crate) fn use_filecoin_verified_rewards(
+		currency_id: CurrencyId,
+		miner: &MultiLocation,
+		amount: BalanceOf<T>,
+	) -> Result<(), Error<T>> {
+		if !FilecoinOracleSets::<T>::contains_key(currency_id) {
+			return Ok(());
+		}
+		FilecoinVerifiedRewards::<T>::try_mutate(currency_id, miner, |total| {
+			ensure!(*total >= amount, Error::<T>::ExceedVerifiedRewards);
+			total.saturating_reduce(amount);
+			Ok(())
+		})
+	}
+}
@@ -1082,6 +1082,7 @@ impl<T: Config> PolkadotAgent<T> {
 			unlock_time,
 		}) = query_entry
 		{
+			let ledger_before = DelegatorLedgers::<T>::get(currency_id, delegator_id);
 			DelegatorLedgers::<T>::mutate(
 				currency_id,
 				delegator_id,
@@ -1195,6 +1196,14 @@ impl<T: Config> PolkadotAgent<T> {
 					}
 				},
 			)?;
+
+			// Handle the slash if the ledger has shrunk.
+			Pallet::<T>::detect_slash(
+				currency_id,
+				&delegator_id,
+				&ledger_before,
+				&DelegatorLedgers::<T>::get(currency_id, delegator_id),
+			)?;
 		} else {
 			Err(Error::<T>::Unexpected)?;
 		}
@@ -0,0 +1,171 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	pallet::{Call, Error, Event},
+	primitives::{OperatorApprovalConfig, OperatorCallClass, PendingOperation},
+	AccountIdOf, Config, CurrencyId, OperateOrigins, OperatorApprovalConfigs, Pallet,
+	PendingOperations,
+};
+use frame_support::{ensure, traits::EnsureOrigin};
+use frame_system::{
+	pallet_prelude::{BlockNumberFor, OriginFor},
+	RawOrigin,
+};
+use sp_runtime::traits::{Hash, Saturating};
+use sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// The hash the approvers approve for a call.
+	pub fn operation_call_hash(call: Call<T>) -> T::Hash {
+		T::Hashing::hash_of(&<T as Config>::RuntimeCall::from(call))
+	}
+
+	/// Ensure privileged origin for a call of the class. If the class requires approval, the
+	/// operator can only dispatch the call once it has been approved, and the approval is
+	/// consumed. The governance origin never needs approval.
+	pub(crate) fn ensure_approved_or_authorized(
+		origin: OriginFor<T>,
+		currency_id: CurrencyId,
+		class: OperatorCallClass,
+		call: Call<T>,
+	) -> Result<(), Error<T>> {
+		match origin.clone().into() {
+			Ok(RawOrigin::Signed(ref signer))
+				if Some(signer) == OperateOrigins::<T>::get(currency_id).as_ref() =>
+			{
+				let Some(config) = OperatorApprovalConfigs::<T>::get(currency_id, class) else {
+					return Ok(());
+				};
+				let call_hash = Self::operation_call_hash(call);
+				let operation = PendingOperations::<T>::get(currency_id, call_hash)
+					.ok_or(Error::<T>::OperationNotApproved)?;
+				ensure!(operation.class == class, Error::<T>::OperationClassMismatch);
+				ensure!(
+					frame_system::Pallet::<T>::block_number() <= operation.expire_at,
+					Error::<T>::OperationExpired
+				);
+				ensure!(
+					operation.approvals.len() >= config.threshold as usize,
+					Error::<T>::OperationNotApproved
+				);
+
+				PendingOperations::<T>::remove(currency_id, call_hash);
+				Self::deposit_event(Event::OperationExecuted { currency_id, class, call_hash });
+				Ok(())
+			},
+			_ => {
+				T::ControlOrigin::ensure_origin(origin).map_err(|_| Error::<T>::NotAuthorized)?;
+				Ok(())
+			},
+		}
+	}
+
+	/// Check an approval config before it is set.
+	pub(crate) fn ensure_valid_approval_config(
+		config: &OperatorApprovalConfig<AccountIdOf<T>, BlockNumberFor<T>>,
+	) -> Result<(), Error<T>> {
+		ensure!(
+			config.approvers.len() <= T::MaxLengthLimit::get() as usize,
+			Error::<T>::ExceedMaxLengthLimit
+		);
+		let mut approvers = config.approvers.clone();
+		approvers.sort();
+		approvers.dedup();
+		ensure!(
+			approvers.len() == config.approvers.len() &&
+				config.threshold > 0 &&
+				config.threshold as usize <= approvers.len() &&
+				config.expiry > BlockNumberFor::<T>::default(),
+			Error::<T>::InvalidOperatorApprovalConfig
+		);
+		Ok(())
+	}
+
+	/// Approve an operator call. The first approval proposes the operation, an expired
+	/// operation is dropped and proposed again.
+	pub fn do_approve_operation(
+		approver: AccountIdOf<T>,
+		currency_id: CurrencyId,
+		class: OperatorCallClass,
+		call_hash: T::Hash,
+	) -> Result<(), Error<T>> {
+		let config = OperatorApprovalConfigs::<T>::get(currency_id, class)
+			.ok_or(Error::<T>::OperatorApprovalConfigNotExist)?;
+		ensure!(config.approvers.contains(&approver), Error::<T>::NotApprover);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut operation = match PendingOperations::<T>::get(currency_id, call_hash) {
+			Some(operation) if now > operation.expire_at => {
+				Self::deposit_event(Event::OperationExpired { currency_id, call_hash });
+				None
+			},
+			operation => operation,
+		}
+		.unwrap_or_else(|| PendingOperation {
+			class,
+			approvals: Vec::new(),
+			expire_at: now.saturating_add(config.expiry),
+		});
+		ensure!(operation.class == class, Error::<T>::OperationClassMismatch);
+		ensure!(!operation.approvals.contains(&approver), Error::<T>::OperationAlreadyApproved);
+		operation.approvals.push(approver.clone());
+
+		let approvals = operation.approvals.len() as u32;
+		PendingOperations::<T>::insert(currency_id, call_hash, operation);
+		Self::deposit_event(Event::OperationApproved {
+			currency_id,
+			class,
+			call_hash,
+			approver,
+			approvals,
+		});
+		Ok(())
+	}
+
+	/// Remove a pending operation. Anyone can remove an expired operation, otherwise only the
+	/// approvers of its class or the governance can cancel it.
+	pub fn do_cancel_operation(
+		origin: OriginFor<T>,
+		currency_id: CurrencyId,
+		call_hash: T::Hash,
+	) -> Result<(), Error<T>> {
+		let operation = PendingOperations::<T>::get(currency_id, call_hash)
+			.ok_or(Error::<T>::OperationNotExist)?;
+
+		if frame_system::Pallet::<T>::block_number() > operation.expire_at {
+			PendingOperations::<T>::remove(currency_id, call_hash);
+			Self::deposit_event(Event::OperationExpired { currency_id, call_hash });
+			return Ok(());
+		}
+
+		let is_approver = match origin.clone().into() {
+			Ok(RawOrigin::Signed(ref signer)) =>
+				OperatorApprovalConfigs::<T>::get(currency_id, operation.class)
+					.is_some_and(|config| config.approvers.contains(signer)),
+			_ => false,
+		};
+		if !is_approver {
+			T::ControlOrigin::ensure_origin(origin).map_err(|_| Error::<T>::NotAuthorized)?;
+		}
+
+		PendingOperations::<T>::remove(currency_id, call_hash);
+		Self::deposit_event(Event::OperationCancelled { currency_id, call_hash });
+		Ok(())
+	}
+}
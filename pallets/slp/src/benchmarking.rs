@@ -19,12 +19,18 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::*;
+use crate::{
+	primitives::{SubstrateLedgerUpdateEntry, SubstrateLedgerUpdateOperation},
+	*,
+};
 use bifrost_primitives::{DOT, VDOT};
 use frame_benchmarking::v2::*;
 use frame_support::{assert_ok, PalletId};
 use frame_system::RawOrigin as SystemOrigin;
-use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedFrom};
+use sp_runtime::{
+	traits::{AccountIdConversion, StaticLookup, UniqueSaturatedFrom},
+	MultiSignature,
+};
 
 const DELEGATOR1: MultiLocation =
 	MultiLocation { parents: 1, interior: X1(AccountId32 { network: None, id: [1u8; 32] }) };
@@ -194,7 +200,74 @@ pub fn init_ongoing_time<T: Config>(origin: <T as frame_system::Config>::Runtime
 	assert_ok!(Pallet::<T>::set_currency_delays(origin.clone(), KSM, Some(delay)));
 }
 
-#[benchmarks(where T: Config + orml_tokens::Config<CurrencyId = CurrencyId> + bifrost_vtoken_minting::Config+ bifrost_stable_pool::Config+ pallet_balances::Config<Balance=u128> + bifrost_asset_registry::Config)]
+fn delegator_location(i: u8) -> MultiLocation {
+	MultiLocation { parents: 1, interior: X1(AccountId32 { network: None, id: [i; 32] }) }
+}
+
+fn validator_location(i: u8) -> MultiLocation {
+	MultiLocation { parents: 1, interior: X1(AccountId32 { network: None, id: [i + 100; 32] }) }
+}
+
+fn scoring_config<T: Config>() -> ValidatorScoringConfig<BalanceOf<T>> {
+	ValidatorScoringConfig {
+		commission_weight: Permill::from_percent(25),
+		self_stake_weight: Permill::from_percent(25),
+		era_points_weight: Permill::from_percent(25),
+		slash_weight: Permill::from_percent(25),
+		self_stake_cap: 1_000_000u32.into(),
+		min_score: Permill::zero(),
+		validators_per_delegator: 10,
+		max_churn: 20,
+	}
+}
+
+fn score_input<T: Config>() -> ValidatorScoreInput<BalanceOf<T>> {
+	ValidatorScoreInput {
+		commission: Permill::from_percent(5),
+		self_stake: 1_000_000u32.into(),
+		era_points: 100,
+		slashes: 0,
+	}
+}
+
+fn init_pending_operation<T: Config>() -> Result<T::Hash, BenchmarkError> {
+	let origin = <T as Config>::ControlOrigin::try_successful_origin()
+		.map_err(|_| BenchmarkError::Weightless)?;
+	let approvers: Vec<AccountIdOf<T>> =
+		(0..T::MaxLengthLimit::get()).map(|i| account("approver", i, 0)).collect();
+	let config = OperatorApprovalConfig {
+		threshold: approvers.len() as u32,
+		approvers,
+		expiry: 100u32.into(),
+	};
+	assert_ok!(Pallet::<T>::set_operator_approval_config(
+		origin,
+		KSM,
+		OperatorCallClass::Bond,
+		Some(config)
+	));
+
+	// Every approver but the last one has approved.
+	let call_hash = Pallet::<T>::operation_call_hash(Call::<T>::bond {
+		currency_id: KSM,
+		who: Box::new(DELEGATOR1),
+		amount: 10u32.into(),
+		validator: None,
+		weight_and_fee: None,
+	});
+	for i in 0..T::MaxLengthLimit::get() - 1 {
+		let approver: AccountIdOf<T> = account("approver", i, 0);
+		assert_ok!(Pallet::<T>::approve_operation(
+			SystemOrigin::Signed(approver).into(),
+			KSM,
+			OperatorCallClass::Bond,
+			call_hash
+		));
+	}
+	Ok(call_hash)
+}
+
+#[benchmarks(where T: Config + orml_tokens::Config<CurrencyId = CurrencyId> + bifrost_vtoken_minting::Config+ bifrost_stable_pool::Config+ pallet_balances::Config<Balance=u128> + bifrost_asset_registry::Config, T::OracleSignature: From<MultiSignature>, T::AccountId: From<sp_runtime::AccountId32>)]
 // #[benchmarks(where T: Config + bifrost_stable_pool::Config +
 // pallet_balances::Config<Balance=u128>)]
 mod benchmarks {
@@ -1207,6 +1280,252 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_validator_scoring_config() -> Result<(), BenchmarkError> {
+		let origin = <T as Config>::ControlOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as <T as frame_system::Config>::RuntimeOrigin, KSM, Some(scoring_config::<T>()));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_validator_score_inputs(n: Linear<1, 50>) -> Result<(), BenchmarkError> {
+		let origin = <T as Config>::ControlOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let validators: Vec<MultiLocation> = (0..n as u8).map(validator_location).collect();
+		Validators::<T>::insert(KSM, BoundedVec::try_from(validators.clone()).unwrap());
+		let inputs = validators.into_iter().map(|v| (v, score_input::<T>())).collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		_(origin as <T as frame_system::Config>::RuntimeOrigin, KSM, inputs);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn rebalance_validators(v: Linear<1, 100>, d: Linear<1, 100>) -> Result<(), BenchmarkError> {
+		let origin = <T as Config>::ControlOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		set_mins_and_maxs::<T>(origin.clone());
+		init_ongoing_time::<T>(origin.clone());
+		ValidatorScoringConfigs::<T>::insert(KSM, scoring_config::<T>());
+
+		let validators: Vec<MultiLocation> = (0..v as u8).map(validator_location).collect();
+		Validators::<T>::insert(KSM, BoundedVec::try_from(validators.clone()).unwrap());
+		for validator in validators.iter() {
+			ValidatorScoreInputs::<T>::insert(KSM, validator, score_input::<T>());
+		}
+		for index in 0..d as u16 {
+			let delegator = delegator_location(index as u8);
+			DelegatorsIndex2Multilocation::<T>::insert(KSM, index, delegator);
+			DelegatorLedgers::<T>::insert(
+				KSM,
+				delegator,
+				Ledger::Substrate(SubstrateLedger {
+					account: delegator,
+					total: 1000u32.into(),
+					active: 1000u32.into(),
+					unlocking: vec![],
+				}),
+			);
+			// Every delegator already backs a validator, so that it is redelegated.
+			ValidatorsByDelegator::<T>::insert(
+				KSM,
+				delegator,
+				BoundedVec::try_from(vec![validators[0]]).unwrap(),
+			);
+		}
+		DelegatorNextIndex::<T>::insert(KSM, d as u16);
+
+		#[extrinsic_call]
+		_(origin as <T as frame_system::Config>::RuntimeOrigin, KSM);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reconcile_delegator_ledger(q: Linear<1, 100>) -> Result<(), BenchmarkError> {
+		let origin = <T as Config>::ControlOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		init_bond::<T>(origin.clone());
+		init_ongoing_time::<T>(origin.clone());
+
+		// The bond above is pending as query 0, the rest of the queue bonds more.
+		for query_id in 1..q as u64 {
+			let entry = LedgerUpdateEntry::Substrate(SubstrateLedgerUpdateEntry {
+				currency_id: KSM,
+				delegator_id: DELEGATOR1,
+				update_operation: SubstrateLedgerUpdateOperation::Bond,
+				amount: 10u32.into(),
+				unlock_time: None,
+			});
+			DelegatorLedgerXcmUpdateQueue::<T>::insert(
+				query_id,
+				(entry, BlockNumberFor::<T>::from(1000u32)),
+			);
+		}
+
+		#[extrinsic_call]
+		_(origin as <T as frame_system::Config>::RuntimeOrigin, KSM, Box::new(DELEGATOR1));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_filecoin_oracle_set() -> Result<(), BenchmarkError> {
+		let origin = <T as Config>::ControlOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let oracles: Vec<AccountIdOf<T>> =
+			(0..T::MaxLengthLimit::get()).map(|i| account("oracle", i, 0)).collect();
+		let oracle_set = FilecoinOracleSet { threshold: oracles.len() as u32, oracles };
+
+		#[extrinsic_call]
+		_(origin as <T as frame_system::Config>::RuntimeOrigin, FIL, Some(oracle_set));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_filecoin_report(n: Linear<1, 50>) -> Result<(), BenchmarkError> {
+		let origin = <T as Config>::ControlOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let caller: AccountIdOf<T> = whitelisted_caller();
+		let miner = DELEGATOR1;
+		DelegatorsMultilocation2Index::<T>::insert(FIL, miner, 0);
+		assert_ok!(Pallet::<T>::set_ongoing_time_unit_update_interval(
+			origin.clone(),
+			FIL,
+			Some(0u32.into())
+		));
+		assert_ok!(Pallet::<T>::update_ongoing_time_unit(origin.clone(), FIL, TimeUnit::Era(1)));
+		VerifiedFilecoinReports::<T>::insert(
+			FIL,
+			miner,
+			FilecoinMinerReport {
+				miner,
+				time_unit: TimeUnit::Era(0),
+				balance: 1000u32.into(),
+				rewards: 0u32.into(),
+				initial_pledge: 800u32.into(),
+			},
+		);
+		FilecoinTransfersIn::<T>::insert(FIL, miner, BalanceOf::<T>::from(50u32));
+
+		let report = FilecoinMinerReport {
+			miner,
+			time_unit: TimeUnit::Era(1),
+			balance: 1100u32.into(),
+			rewards: 50u32.into(),
+			initial_pledge: 800u32.into(),
+		};
+		let payload = Pallet::<T>::filecoin_report_payload(FIL, &report);
+		let mut oracles: Vec<AccountIdOf<T>> = vec![];
+		let mut signatures: Vec<(AccountIdOf<T>, T::OracleSignature)> = vec![];
+		for _ in 0..n {
+			let public = sp_io::crypto::sr25519_generate(0.into(), None);
+			let signature = MultiSignature::Sr25519(
+				sp_io::crypto::sr25519_sign(0.into(), &public, &payload)
+					.ok_or(BenchmarkError::Weightless)?,
+			);
+			let oracle: AccountIdOf<T> = sp_runtime::AccountId32::from(public).into();
+			oracles.push(oracle.clone());
+			signatures.push((oracle, signature.into()));
+		}
+		assert_ok!(Pallet::<T>::set_filecoin_oracle_set(
+			origin,
+			FIL,
+			Some(FilecoinOracleSet { oracles, threshold: n })
+		));
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller), FIL, report, signatures);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_operator_approval_config() -> Result<(), BenchmarkError> {
+		let origin = <T as Config>::ControlOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let approvers: Vec<AccountIdOf<T>> =
+			(0..T::MaxLengthLimit::get()).map(|i| account("approver", i, 0)).collect();
+		let config = OperatorApprovalConfig {
+			threshold: approvers.len() as u32,
+			approvers,
+			expiry: 100u32.into(),
+		};
+
+		#[extrinsic_call]
+		_(
+			origin as <T as frame_system::Config>::RuntimeOrigin,
+			KSM,
+			OperatorCallClass::Bond,
+			Some(config),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_operation() -> Result<(), BenchmarkError> {
+		let call_hash = init_pending_operation::<T>()?;
+		let approver: AccountIdOf<T> = account("approver", T::MaxLengthLimit::get() - 1, 0);
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(approver), KSM, OperatorCallClass::Bond, call_hash);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_operation() -> Result<(), BenchmarkError> {
+		let call_hash = init_pending_operation::<T>()?;
+		let approver: AccountIdOf<T> = account("approver", T::MaxLengthLimit::get() - 1, 0);
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(approver), KSM, call_hash);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn report_slash() -> Result<(), BenchmarkError> {
+		let origin = <T as Config>::ControlOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		init_bond::<T>(origin.clone());
+		init_ongoing_time::<T>(origin.clone());
+
+		// The slash is partly covered by the insurance fund.
+		DelegatorLedgers::<T>::insert(
+			KSM,
+			DELEGATOR1,
+			Ledger::Substrate(SubstrateLedger {
+				account: DELEGATOR1,
+				total: 2000u32.into(),
+				active: 2000u32.into(),
+				unlocking: vec![],
+			}),
+		);
+		let fund = Pallet::<T>::slash_insurance_fund_account();
+		assert_ok!(<T as Config>::MultiCurrency::deposit(KSM, &fund, 500u32.into()));
+		assert_ok!(T::VtokenMinting::increase_token_pool(KSM, 10_000u32.into()));
+
+		let ledger = Box::new(Ledger::Substrate(SubstrateLedger {
+			account: DELEGATOR1,
+			total: 1000u32.into(),
+			active: 1000u32.into(),
+			unlocking: vec![],
+		}));
+
+		#[extrinsic_call]
+		_(origin as <T as frame_system::Config>::RuntimeOrigin, KSM, Box::new(DELEGATOR1), ledger);
+
+		Ok(())
+	}
+
 	//   `cargo test -p bifrost-slp --all-features`
 	impl_benchmark_test_suite!(
 		Pallet,
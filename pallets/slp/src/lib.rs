@@ -24,8 +24,10 @@ extern crate core;
 use crate::{agents::PolkadotAgent, Junction::GeneralIndex, Junctions::X2};
 pub use crate::{
 	primitives::{
-		Delays, LedgerUpdateEntry, MinimumsMaximums, QueryId, SubstrateLedger,
-		ValidatorsByDelegatorUpdateEntry,
+		Delays, FilecoinMinerReport, FilecoinOracleSet, LedgerDivergence, LedgerUpdateEntry,
+		MinimumsMaximums, OperatorApprovalConfig, OperatorCallClass, PendingOperation, QueryId,
+		RebalanceOperation, SlashRecord, SubstrateLedger, ValidatorScoreInput,
+		ValidatorScoringConfig, ValidatorsByDelegatorUpdateEntry,
 	},
 	traits::{OnRefund, QueryResponseManager, StakingAgent},
 	Junction::AccountId32,
@@ -42,7 +44,7 @@ use bifrost_primitives::{
 };
 use bifrost_stable_pool::traits::StablePoolHandler;
 use cumulus_primitives_core::{relay_chain::HashT, ParaId};
-use frame_support::{pallet_prelude::*, traits::Contains, weights::Weight};
+use frame_support::{pallet_prelude::*, traits::Contains, weights::Weight, PalletId};
 use frame_system::{
 	ensure_signed,
 	pallet_prelude::{BlockNumberFor, OriginFor},
@@ -53,15 +55,22 @@ pub use primitives::Ledger;
 use sp_arithmetic::{per_things::Permill, traits::Zero};
 use sp_core::{bounded::BoundedVec, H160};
 use sp_io::hashing::blake2_256;
-use sp_runtime::traits::{CheckedAdd, CheckedSub, Convert, TrailingZeroInput, UniqueSaturatedFrom};
+use sp_runtime::traits::{
+	CheckedAdd, CheckedSub, Convert, IdentifyAccount, TrailingZeroInput, UniqueSaturatedFrom,
+	Verify,
+};
 use sp_std::{boxed::Box, vec, vec::Vec};
 pub use weights::WeightInfo;
 use xcm::v3::{Junction, Junctions, MultiLocation};
 
 mod agents;
+mod approval;
 pub mod migrations;
 mod mocks;
 pub mod primitives;
+mod rebalance;
+mod reconcile;
+mod slash;
 mod tests;
 pub mod traits;
 pub mod weights;
@@ -175,6 +184,15 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The signature of the oracle reports.
+		type OracleSignature: Verify<Signer = Self::OracleSigner> + Parameter;
+		/// The public key of the oracles.
+		type OracleSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// The pallet id of the account that covers the slashes before the token pool is reduced.
+		#[pallet::constant]
+		type SlashInsurancePalletId: Get<PalletId>;
 	}
 
 	#[pallet::error]
@@ -262,6 +280,24 @@ pub mod pallet {
 		ExceedLimit,
 		InvalidPageNumber,
 		NoMoreValidatorBoostListForCurrency,
+		ValidatorScoringConfigNotExist,
+		InvalidValidatorScoringConfig,
+		AlreadyRebalanced,
+		InvalidOracleSet,
+		OracleSetNotExist,
+		InvalidReportTimeUnit,
+		ReportAlreadyVerified,
+		InsufficientAttestations,
+		ExceedVerifiedRewards,
+		InvalidReportRewards,
+		InvalidOperatorApprovalConfig,
+		OperatorApprovalConfigNotExist,
+		NotApprover,
+		OperationNotExist,
+		OperationAlreadyApproved,
+		OperationClassMismatch,
+		OperationNotApproved,
+		OperationExpired,
 	}
 
 	#[pallet::event]
@@ -528,6 +564,69 @@ pub mod pallet {
 			currency_id: CurrencyId,
 			amount: BalanceOf<T>,
 		},
+		ValidatorScoringConfigSet {
+			currency_id: CurrencyId,
+			config: Option<ValidatorScoringConfig<BalanceOf<T>>>,
+		},
+		ValidatorScoreInputsUpdated {
+			currency_id: CurrencyId,
+			inputs: Vec<(MultiLocation, ValidatorScoreInput<BalanceOf<T>>)>,
+		},
+		ValidatorsRebalanced {
+			currency_id: CurrencyId,
+			time_unit: TimeUnit,
+			operations: Vec<RebalanceOperation>,
+		},
+		DelegatorSlashed {
+			currency_id: CurrencyId,
+			delegator: MultiLocation,
+			time_unit: TimeUnit,
+			#[codec(compact)]
+			amount: BalanceOf<T>,
+			#[codec(compact)]
+			insurance_covered: BalanceOf<T>,
+		},
+		DelegatorLedgerReconciled {
+			currency_id: CurrencyId,
+			delegator: MultiLocation,
+			divergence: Option<LedgerDivergence<BalanceOf<T>>>,
+		},
+		FilecoinOracleSetSet {
+			currency_id: CurrencyId,
+			oracle_set: Option<FilecoinOracleSet<AccountIdOf<T>>>,
+		},
+		FilecoinReportVerified {
+			currency_id: CurrencyId,
+			report: FilecoinMinerReport<BalanceOf<T>>,
+			signers: Vec<AccountIdOf<T>>,
+			#[codec(compact)]
+			rewards: BalanceOf<T>,
+		},
+		OperatorApprovalConfigSet {
+			currency_id: CurrencyId,
+			class: OperatorCallClass,
+			config: Option<OperatorApprovalConfig<AccountIdOf<T>, BlockNumberFor<T>>>,
+		},
+		OperationApproved {
+			currency_id: CurrencyId,
+			class: OperatorCallClass,
+			call_hash: T::Hash,
+			approver: AccountIdOf<T>,
+			approvals: u32,
+		},
+		OperationExecuted {
+			currency_id: CurrencyId,
+			class: OperatorCallClass,
+			call_hash: T::Hash,
+		},
+		OperationCancelled {
+			currency_id: CurrencyId,
+			call_hash: T::Hash,
+		},
+		OperationExpired {
+			currency_id: CurrencyId,
+			call_hash: T::Hash,
+		},
 	}
 
 	/// The current storage version, we set to 3 our new version(after migrate stroage from vec t
@@ -684,6 +783,120 @@ pub mod pallet {
 	pub type SupplementFeeAccountWhitelist<T> =
 		StorageMap<_, Blake2_128Concat, CurrencyId, Vec<(MultiLocation, Hash<T>)>>;
 
+	/// Validator scoring and rebalancing params for different chains.
+	#[pallet::storage]
+	pub type ValidatorScoringConfigs<T> =
+		StorageMap<_, Blake2_128Concat, CurrencyId, ValidatorScoringConfig<BalanceOf<T>>>;
+
+	/// Validator scoring inputs fed by the oracle. CurrencyId + Validator => Inputs
+	#[pallet::storage]
+	pub type ValidatorScoreInputs<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		MultiLocation,
+		ValidatorScoreInput<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The latest TimeUnit in which the validators of a currency have been rebalanced.
+	#[pallet::storage]
+	pub type LastRebalanceTimeUnit<T> = StorageMap<_, Blake2_128Concat, CurrencyId, TimeUnit>;
+
+	/// Slashes detected in a time unit. CurrencyId + TimeUnit => Slashes
+	#[pallet::storage]
+	pub type SlashRecords<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		TimeUnit,
+		BoundedVec<SlashRecord<BalanceOf<T>>, T::MaxLengthLimit>,
+		ValueQuery,
+	>;
+
+	/// The divergences between the ledgers of the pallet and the remote ledgers.
+	/// CurrencyId + Delegator => Divergence
+	#[pallet::storage]
+	pub type DelegatorLedgerDivergences<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		MultiLocation,
+		LedgerDivergence<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The oracles attesting the Filecoin miner reports of a currency.
+	#[pallet::storage]
+	pub type FilecoinOracleSets<T: Config> =
+		StorageMap<_, Blake2_128Concat, CurrencyId, FilecoinOracleSet<AccountIdOf<T>>>;
+
+	/// The latest verified report of a Filecoin miner. CurrencyId + Miner => Report
+	#[pallet::storage]
+	pub type VerifiedFilecoinReports<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		MultiLocation,
+		FilecoinMinerReport<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The verified rewards of a Filecoin miner that have not been used to tune the exchange
+	/// rate yet. CurrencyId + Miner => Rewards
+	#[pallet::storage]
+	pub type FilecoinVerifiedRewards<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		MultiLocation,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The amount known to have been pledged to a Filecoin miner since its last verified report.
+	/// CurrencyId + Miner => Amount
+	#[pallet::storage]
+	pub type FilecoinTransfersIn<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		MultiLocation,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The approvers of a class of operator calls. A class without approvers can be dispatched
+	/// by the operator alone. CurrencyId + Class => Config
+	#[pallet::storage]
+	pub type OperatorApprovalConfigs<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Twox64Concat,
+		OperatorCallClass,
+		OperatorApprovalConfig<AccountIdOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The operator calls being approved. CurrencyId + CallHash => Operation
+	#[pallet::storage]
+	pub type PendingOperations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Identity,
+		T::Hash,
+		PendingOperation<AccountIdOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -730,7 +943,18 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Bond,
+				Call::<T>::bond {
+					currency_id,
+					who: who.clone(),
+					amount,
+					validator,
+					weight_and_fee,
+				},
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			let query_id =
@@ -761,7 +985,18 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Bond,
+				Call::<T>::bond_extra {
+					currency_id,
+					who: who.clone(),
+					validator,
+					amount,
+					weight_and_fee,
+				},
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			let query_id =
@@ -793,7 +1028,18 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Unbond,
+				Call::<T>::unbond {
+					currency_id,
+					who: who.clone(),
+					validator,
+					amount,
+					weight_and_fee,
+				},
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			let query_id =
@@ -822,7 +1068,12 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Unbond,
+				Call::<T>::unbond_all { currency_id, who: who.clone(), weight_and_fee },
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			let query_id = staking_agent.unbond_all(&who, currency_id, weight_and_fee)?;
@@ -850,7 +1101,18 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Bond,
+				Call::<T>::rebond {
+					currency_id,
+					who: who.clone(),
+					validator,
+					amount,
+					weight_and_fee,
+				},
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			let query_id =
@@ -880,7 +1142,17 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Delegate,
+				Call::<T>::delegate {
+					currency_id,
+					who: who.clone(),
+					targets: targets.clone(),
+					weight_and_fee,
+				},
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			let query_id = staking_agent.delegate(&who, &targets, currency_id, weight_and_fee)?;
@@ -908,7 +1180,17 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Delegate,
+				Call::<T>::undelegate {
+					currency_id,
+					who: who.clone(),
+					targets: targets.clone(),
+					weight_and_fee,
+				},
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			let query_id = staking_agent.undelegate(&who, &targets, currency_id, weight_and_fee)?;
@@ -936,7 +1218,17 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Delegate,
+				Call::<T>::redelegate {
+					currency_id,
+					who: who.clone(),
+					targets: targets.clone(),
+					weight_and_fee,
+				},
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			let query_id = staking_agent.redelegate(&who, &targets, currency_id, weight_and_fee)?;
@@ -1027,7 +1319,12 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Delegate,
+				Call::<T>::chill { currency_id, who: who.clone(), weight_and_fee },
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			let query_id = staking_agent.chill(&who, currency_id, weight_and_fee)?;
@@ -1054,7 +1351,18 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Transfer,
+				Call::<T>::transfer_back {
+					currency_id,
+					from: from.clone(),
+					to: to.clone(),
+					amount,
+					weight_and_fee,
+				},
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			staking_agent.transfer_back(&from, &to, amount, currency_id, weight_and_fee)?;
@@ -1080,7 +1388,12 @@ pub mod pallet {
 			#[pallet::compact] amount: BalanceOf<T>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::Transfer,
+				Call::<T>::transfer_to { currency_id, from: from.clone(), to: to.clone(), amount },
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			staking_agent.transfer_to(&from, &to, amount, currency_id)?;
@@ -1111,7 +1424,18 @@ pub mod pallet {
 			weight_and_fee: Option<(Weight, BalanceOf<T>)>,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_approved_or_authorized(
+				origin,
+				currency_id,
+				OperatorCallClass::ConvertAsset,
+				Call::<T>::convert_asset {
+					currency_id,
+					who: who.clone(),
+					amount,
+					if_from_currency,
+					weight_and_fee,
+				},
+			)?;
 
 			let staking_agent = Self::get_currency_staking_agent(currency_id)?;
 			staking_agent.convert_asset(
@@ -1523,6 +1847,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			// Check the validity of origin
 			Self::ensure_authorized(origin, currency_id)?;
+			// The Filecoin ledgers are only updated by the verified miner reports.
+			ensure!(currency_id != FIL, Error::<T>::NotSupportedCurrencyId);
 
 			// Update the ledger.
 			DelegatorLedgers::<T>::mutate_exists(currency_id, &*who, |old_ledger| {
@@ -2195,6 +2521,300 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set the validator scoring and rebalancing params of a currency.
+		#[pallet::call_index(49)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_validator_scoring_config())]
+		pub fn set_validator_scoring_config(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			config: Option<ValidatorScoringConfig<BalanceOf<T>>>,
+		) -> DispatchResult {
+			// Check the validity of origin
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			if let Some(ref config) = config {
+				let total_weight = [
+					config.commission_weight,
+					config.self_stake_weight,
+					config.era_points_weight,
+					config.slash_weight,
+				]
+				.iter()
+				.try_fold(0u32, |total, weight| total.checked_add(weight.deconstruct()))
+				.ok_or(Error::<T>::OverFlow)?;
+				ensure!(
+					total_weight <= Permill::one().deconstruct() &&
+						config.validators_per_delegator > 0,
+					Error::<T>::InvalidValidatorScoringConfig
+				);
+				ValidatorScoringConfigs::<T>::insert(currency_id, config);
+			} else {
+				ValidatorScoringConfigs::<T>::remove(currency_id);
+			}
+
+			// Deposit event.
+			Pallet::<T>::deposit_event(Event::ValidatorScoringConfigSet { currency_id, config });
+			Ok(())
+		}
+
+		/// Update the scoring inputs of validators, fed by the oracle.
+		#[pallet::call_index(50)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_validator_score_inputs(inputs.len() as u32))]
+		pub fn update_validator_score_inputs(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			inputs: Vec<(MultiLocation, ValidatorScoreInput<BalanceOf<T>>)>,
+		) -> DispatchResult {
+			// Ensure origin
+			Self::ensure_authorized(origin, currency_id)?;
+
+			ensure!(
+				inputs.len() <= T::MaxLengthLimit::get() as usize,
+				Error::<T>::ExceedMaxLengthLimit
+			);
+			let validators = Validators::<T>::get(currency_id).unwrap_or_default();
+			for (validator, input) in inputs.iter() {
+				ensure!(validators.contains(validator), Error::<T>::ValidatorNotExist);
+				ValidatorScoreInputs::<T>::insert(currency_id, validator, input);
+			}
+
+			// Deposit event.
+			Pallet::<T>::deposit_event(Event::ValidatorScoreInputsUpdated { currency_id, inputs });
+			Ok(())
+		}
+
+		/// Score the validators and compute the redelegate/delegate operations that move the
+		/// delegators towards the target allocation. Can be done once per time unit.
+		#[pallet::call_index(51)]
+		#[pallet::weight(<T as Config>::WeightInfo::rebalance_validators(
+			T::MaxLengthLimit::get(),
+			T::MaxLengthLimit::get(),
+		))]
+		pub fn rebalance_validators(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+		) -> DispatchResultWithPostInfo {
+			// Ensure origin
+			Self::ensure_authorized(origin, currency_id)?;
+
+			let time_unit = T::VtokenMinting::get_ongoing_time_unit(currency_id)
+				.ok_or(Error::<T>::TimeUnitNotExist)?;
+			ensure!(
+				LastRebalanceTimeUnit::<T>::get(currency_id).as_ref() != Some(&time_unit),
+				Error::<T>::AlreadyRebalanced
+			);
+
+			let operations = Self::calculate_rebalance_operations(currency_id)?;
+			LastRebalanceTimeUnit::<T>::insert(currency_id, time_unit.clone());
+
+			// Deposit event.
+			Pallet::<T>::deposit_event(Event::ValidatorsRebalanced {
+				currency_id,
+				time_unit,
+				operations,
+			});
+
+			let validators_count = Validators::<T>::decode_len(currency_id).unwrap_or_default();
+			Ok(Some(<T as Config>::WeightInfo::rebalance_validators(
+				validators_count as u32,
+				Self::get_rebalance_delegators_count(currency_id),
+			))
+			.into())
+		}
+
+		/// Reconcile the ledger of a delegator with the remote ledger reported by the XCM query
+		/// responses. The ledger expected once all the pending ledger updates have landed is
+		/// compared with the ledger holding only the updates whose responses have been reported.
+		/// The divergence is recorded for the operators, the ledger of the pallet is untouched.
+		#[pallet::call_index(53)]
+		#[pallet::weight(<T as Config>::WeightInfo::reconcile_delegator_ledger(
+			T::MaxLengthLimit::get(),
+		))]
+		pub fn reconcile_delegator_ledger(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			who: Box<MultiLocation>,
+		) -> DispatchResultWithPostInfo {
+			// Ensure origin
+			Self::ensure_authorized(origin, currency_id)?;
+
+			ensure!(
+				DelegatorsMultilocation2Index::<T>::contains_key(currency_id, &*who),
+				Error::<T>::DelegatorNotExist
+			);
+			let time_unit = T::VtokenMinting::get_ongoing_time_unit(currency_id)
+				.ok_or(Error::<T>::TimeUnitNotExist)?;
+
+			let (queue_len, pending_updates) = Self::get_pending_ledger_updates(currency_id, &who);
+			let expected_ledger =
+				Self::get_ledger_after_pending_updates(currency_id, &who, &pending_updates, true)?;
+			let actual_ledger =
+				Self::get_ledger_after_pending_updates(currency_id, &who, &pending_updates, false)?;
+
+			let divergence =
+				Self::calculate_ledger_divergence(&expected_ledger, &actual_ledger, time_unit);
+			DelegatorLedgerDivergences::<T>::mutate_exists(currency_id, &*who, |old_divergence| {
+				*old_divergence = divergence.clone();
+			});
+
+			// Deposit event.
+			Pallet::<T>::deposit_event(Event::DelegatorLedgerReconciled {
+				currency_id,
+				delegator: *who,
+				divergence,
+			});
+
+			Ok(Some(<T as Config>::WeightInfo::reconcile_delegator_ledger(queue_len)).into())
+		}
+
+		/// Set the oracles attesting the Filecoin miner reports, and the number of signatures a
+		/// report needs. Once set, the exchange rate can only be tuned with verified rewards.
+		#[pallet::call_index(54)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_filecoin_oracle_set())]
+		pub fn set_filecoin_oracle_set(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			oracle_set: Option<FilecoinOracleSet<AccountIdOf<T>>>,
+		) -> DispatchResult {
+			// Check the validity of origin
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			if let Some(ref oracle_set) = oracle_set {
+				ensure!(currency_id == FIL, Error::<T>::NotSupportedCurrencyId);
+				ensure!(
+					oracle_set.oracles.len() <= T::MaxLengthLimit::get() as usize,
+					Error::<T>::ExceedMaxLengthLimit
+				);
+				let mut oracles = oracle_set.oracles.clone();
+				oracles.sort();
+				oracles.dedup();
+				ensure!(
+					oracles.len() == oracle_set.oracles.len() &&
+						oracle_set.threshold > 0 &&
+						oracle_set.threshold as usize <= oracles.len(),
+					Error::<T>::InvalidOracleSet
+				);
+			}
+
+			FilecoinOracleSets::<T>::mutate_exists(currency_id, |old_oracle_set| {
+				*old_oracle_set = oracle_set.clone();
+			});
+
+			// Deposit event.
+			Pallet::<T>::deposit_event(Event::FilecoinOracleSetSet { currency_id, oracle_set });
+			Ok(())
+		}
+
+		/// Submit a Filecoin miner report signed by the oracles. Anyone can relay it, the
+		/// signatures are verified against the oracle set.
+		#[pallet::call_index(55)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_filecoin_report(signatures.len() as u32))]
+		pub fn submit_filecoin_report(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			report: FilecoinMinerReport<BalanceOf<T>>,
+			signatures: Vec<(AccountIdOf<T>, T::OracleSignature)>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(
+				signatures.len() <= T::MaxLengthLimit::get() as usize,
+				Error::<T>::ExceedMaxLengthLimit
+			);
+
+			Self::do_verify_filecoin_report(currency_id, report, signatures)
+		}
+
+		/// Set the approvers of a class of operator calls. Once set, the operator can only
+		/// dispatch a call of the class after enough approvers have approved its hash.
+		#[pallet::call_index(56)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_operator_approval_config())]
+		pub fn set_operator_approval_config(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			class: OperatorCallClass,
+			config: Option<OperatorApprovalConfig<AccountIdOf<T>, BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			// Check the validity of origin
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			if let Some(ref config) = config {
+				Self::ensure_valid_approval_config(config)?;
+			}
+
+			OperatorApprovalConfigs::<T>::mutate_exists(currency_id, class, |old_config| {
+				*old_config = config.clone();
+			});
+
+			// Deposit event.
+			Pallet::<T>::deposit_event(Event::OperatorApprovalConfigSet {
+				currency_id,
+				class,
+				config,
+			});
+			Ok(())
+		}
+
+		/// Approve the hash of an operator call of the class.
+		#[pallet::call_index(57)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_operation())]
+		pub fn approve_operation(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			class: OperatorCallClass,
+			call_hash: T::Hash,
+		) -> DispatchResult {
+			let approver = ensure_signed(origin)?;
+
+			Self::do_approve_operation(approver, currency_id, class, call_hash)?;
+			Ok(())
+		}
+
+		/// Cancel a pending operator call, or remove it once it has expired.
+		#[pallet::call_index(58)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_operation())]
+		pub fn cancel_operation(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			call_hash: T::Hash,
+		) -> DispatchResult {
+			Self::do_cancel_operation(origin, currency_id, call_hash)?;
+			Ok(())
+		}
+
+		/// Update the ledger of a slashed delegator. The shrinkage of the ledger that is not
+		/// explained by the withdrawn unlocking chunks is recorded as a slash and covered by the
+		/// insurance fund before the token pool is reduced.
+		#[pallet::call_index(59)]
+		#[pallet::weight(<T as Config>::WeightInfo::report_slash())]
+		pub fn report_slash(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			who: Box<MultiLocation>,
+			ledger: Box<Ledger<BalanceOf<T>>>,
+		) -> DispatchResult {
+			// Check the validity of origin
+			T::ControlOrigin::ensure_origin(origin)?;
+			// The Filecoin ledgers are only updated by the verified miner reports.
+			ensure!(currency_id != FIL, Error::<T>::NotSupportedCurrencyId);
+
+			let old_ledger = DelegatorLedgers::<T>::get(currency_id, &*who)
+				.ok_or(Error::<T>::DelegatorNotBonded)?;
+			Self::detect_slash(currency_id, &who, &Some(old_ledger), &Some(*ledger.clone()))?;
+
+			// Update the ledger.
+			DelegatorLedgers::<T>::insert(currency_id, &*who, *ledger.clone());
+
+			// Deposit event.
+			Pallet::<T>::deposit_event(Event::DelegatorLedgerSet {
+				currency_id,
+				delegator: *who,
+				ledger: Some(*ledger),
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
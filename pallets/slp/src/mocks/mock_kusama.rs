@@ -26,9 +26,9 @@ use bifrost_asset_registry::AssetIdMaps;
 use bifrost_primitives::{
 	currency::{BNC, KSM, MANTA},
 	Amount, Balance, BifrostEntranceAccount, BifrostExitAccount, BifrostFeeAccount, CurrencyId,
-	IncentivePoolAccount, MockXcmExecutor, MockXcmRouter, MoonbeamChainId,
-	ParachainStakingPalletId, SlpxOperator, StableAssetPalletId, TokenSymbol,
-	XcmDestWeightAndFeeHandler, XcmOperationType,
+	IncentivePoolAccount, InstantRedeemBufferPalletId, MockXcmExecutor, MockXcmRouter,
+	MoonbeamChainId, ParachainStakingPalletId, SlashInsurancePalletId, SlpxOperator,
+	StableAssetPalletId, TokenSymbol, XcmDestWeightAndFeeHandler, XcmOperationType,
 };
 pub use cumulus_primitives_core::ParaId;
 use frame_support::{
@@ -261,7 +261,11 @@ impl bifrost_vtoken_minting::Config for Runtime {
 	type MoonbeamChainId = MoonbeamChainId;
 	type ChannelCommission = ();
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<100>;
+	type OffchainSignature = sp_runtime::MultiSignature;
+	type SigningPublicKey = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = ();
 }
 
@@ -528,6 +532,9 @@ impl Config for Runtime {
 	type StablePoolHandler = StablePool;
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = BifrostTreasuryAccount;
+	type OracleSignature = sp_runtime::MultiSignature;
+	type OracleSigner = <sp_runtime::MultiSignature as sp_runtime::traits::Verify>::Signer;
+	type SlashInsurancePalletId = SlashInsurancePalletId;
 }
 
 pub struct XcmDestWeightAndFee;
@@ -17,9 +17,11 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::CurrencyId;
+use bifrost_primitives::TimeUnit;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
 use xcm::v3::MultiLocation;
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -38,3 +40,30 @@ pub struct FilecoinOwnerByMinerEntry {
 	pub miner_id: MultiLocation,
 	pub owner_id: MultiLocation,
 }
+
+/// The oracles attesting the Filecoin miner reports.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct FilecoinOracleSet<AccountId> {
+	/// The oracle accounts.
+	pub oracles: Vec<AccountId>,
+	/// The number of oracle signatures a report needs to be verified.
+	pub threshold: u32,
+}
+
+/// The state of a Filecoin miner in a time unit, signed by the oracles.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct FilecoinMinerReport<Balance> {
+	/// The miner multilocation
+	pub miner: MultiLocation,
+	/// The time unit the report is made for.
+	pub time_unit: TimeUnit,
+	/// The total balance of the miner actor, including the rewards.
+	#[codec(compact)]
+	pub balance: Balance,
+	/// The block rewards the miner has earned since its last report.
+	#[codec(compact)]
+	pub rewards: Balance,
+	/// The pledge collateral of the sectors of the miner.
+	#[codec(compact)]
+	pub initial_pledge: Balance,
+}
@@ -29,7 +29,9 @@ pub use polkadot_primitives::*;
 use bifrost_primitives::TimeUnit;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{traits::Saturating, Permill, RuntimeDebug};
+use sp_std::vec::Vec;
+use xcm::v3::MultiLocation;
 
 pub type QueryId = u64;
 pub const TIMEOUT_BLOCKS: u32 = 1000;
@@ -44,6 +46,20 @@ pub enum Ledger<Balance> {
 	Phala(PhalaLedger<Balance>),
 }
 
+impl<Balance: Copy + Saturating> Ledger<Balance> {
+	/// The total and the active amount of the ledger.
+	pub fn total_and_active(&self) -> (Balance, Balance) {
+		match self {
+			Ledger::Substrate(ledger) => (ledger.total, ledger.active),
+			Ledger::Moonbeam(ledger) | Ledger::ParachainStaking(ledger) =>
+				(ledger.total, ledger.total.saturating_sub(ledger.less_total)),
+			Ledger::Filecoin(ledger) => (ledger.initial_pledge, ledger.initial_pledge),
+			Ledger::Phala(ledger) =>
+				(ledger.active_shares.saturating_add(ledger.unlocking_shares), ledger.active_shares),
+		}
+	}
+}
+
 /// A type for accommodating delegator update entries for different kinds of currencies.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum LedgerUpdateEntry<Balance> {
@@ -107,3 +123,124 @@ pub struct Delays {
 	/// Leave from delegator set delay.
 	pub leave_delegators_delay: TimeUnit,
 }
+
+/// Validator scoring and rebalancing params for different chain
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ValidatorScoringConfig<Balance> {
+	/// The score weight of the commission. Lower commission scores higher.
+	pub commission_weight: Permill,
+	/// The score weight of the self stake, relative to `self_stake_cap`.
+	pub self_stake_weight: Permill,
+	/// The score weight of the era points, relative to the highest era points.
+	pub era_points_weight: Permill,
+	/// The score weight of the slash history. Every slash lowers it.
+	pub slash_weight: Permill,
+	/// The self stake that earns the whole self stake weight.
+	#[codec(compact)]
+	pub self_stake_cap: Balance,
+	/// The minimum score for a validator to be selected.
+	pub min_score: Permill,
+	/// The number of validators each delegator targets.
+	#[codec(compact)]
+	pub validators_per_delegator: u32,
+	/// The maximum number of validator changes in a time unit. Replacing a validator counts as
+	/// one change.
+	#[codec(compact)]
+	pub max_churn: u32,
+}
+
+/// Validator inputs of the scoring, fed by the oracle.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ValidatorScoreInput<Balance> {
+	/// The commission of the validator.
+	pub commission: Permill,
+	/// The self stake of the validator.
+	#[codec(compact)]
+	pub self_stake: Balance,
+	/// The era points of the validator in the last time unit.
+	#[codec(compact)]
+	pub era_points: u32,
+	/// The number of times the validator has been slashed.
+	#[codec(compact)]
+	pub slashes: u32,
+}
+
+/// An operation of the validator rebalancing.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum RebalanceOperation {
+	/// Delegate a delegator without validators to the targets.
+	Delegate { delegator: MultiLocation, targets: Vec<MultiLocation> },
+	/// Redelegate a delegator to the targets.
+	Redelegate { delegator: MultiLocation, targets: Vec<MultiLocation> },
+}
+
+/// A slash of a delegator detected from its ledger.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct SlashRecord<Balance> {
+	/// The slashed delegator.
+	pub delegator: MultiLocation,
+	/// The slashed amount.
+	#[codec(compact)]
+	pub amount: Balance,
+	/// The part of the slash covered by the insurance fund. The rest is taken from the token
+	/// pool and borne by the vtoken holders.
+	#[codec(compact)]
+	pub insurance_covered: Balance,
+}
+
+/// The divergence between the ledger of a delegator kept by the pallet and the ledger reported
+/// from the remote chain.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LedgerDivergence<Balance> {
+	/// The time unit in which the remote ledger was reported.
+	pub time_unit: TimeUnit,
+	/// The total amount in the ledger of the pallet.
+	#[codec(compact)]
+	pub expected_total: Balance,
+	/// The total amount in the remote ledger.
+	#[codec(compact)]
+	pub actual_total: Balance,
+	/// The active amount in the ledger of the pallet.
+	#[codec(compact)]
+	pub expected_active: Balance,
+	/// The active amount in the remote ledger.
+	#[codec(compact)]
+	pub actual_active: Balance,
+}
+
+/// The classes of operator calls that can require the approval of several approvers.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum OperatorCallClass {
+	/// `bond`, `bond_extra` and `rebond`.
+	Bond,
+	/// `unbond` and `unbond_all`.
+	Unbond,
+	/// `delegate`, `undelegate`, `redelegate` and `chill`.
+	Delegate,
+	/// `transfer_to` and `transfer_back`.
+	Transfer,
+	/// `convert_asset`.
+	ConvertAsset,
+}
+
+/// The approvers of a class of operator calls.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct OperatorApprovalConfig<AccountId, BlockNumber> {
+	/// The accounts that can approve the calls.
+	pub approvers: Vec<AccountId>,
+	/// The number of approvals a call needs before the operator can dispatch it.
+	pub threshold: u32,
+	/// The number of blocks an operation stays valid after it is proposed.
+	pub expiry: BlockNumber,
+}
+
+/// An operator call being approved, identified by the hash of the call.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PendingOperation<AccountId, BlockNumber> {
+	/// The class of the call.
+	pub class: OperatorCallClass,
+	/// The approvers that have approved the call.
+	pub approvals: Vec<AccountId>,
+	/// The block after which the operation can no longer be dispatched.
+	pub expire_at: BlockNumber,
+}
@@ -0,0 +1,200 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	pallet::Error,
+	primitives::{RebalanceOperation, ValidatorScoreInput, ValidatorScoringConfig},
+	BalanceOf, Config, CurrencyId, DelegatorLedgers, DelegatorNextIndex,
+	DelegatorsIndex2Multilocation, Pallet, ValidatorScoreInputs, ValidatorScoringConfigs,
+	Validators, ValidatorsByDelegator,
+};
+use frame_support::traits::Get;
+use sp_arithmetic::per_things::Permill;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{Saturating, UniqueSaturatedInto},
+	Rounding,
+};
+use sp_std::{cmp::Reverse, prelude::*};
+use xcm::v3::MultiLocation;
+
+impl<T: Config> Pallet<T> {
+	/// Score a validator with the weights of the config. `max_era_points` is the highest era
+	/// points among the candidates.
+	pub fn score_validator(
+		config: &ValidatorScoringConfig<BalanceOf<T>>,
+		input: &ValidatorScoreInput<BalanceOf<T>>,
+		max_era_points: u32,
+	) -> Permill {
+		let self_stake: u128 = input.self_stake.unique_saturated_into();
+		let self_stake_cap: u128 = config.self_stake_cap.unique_saturated_into();
+		let self_stake_rate = if self_stake_cap == 0 {
+			Permill::one()
+		} else {
+			Permill::from_rational(self_stake.min(self_stake_cap), self_stake_cap)
+		};
+		let era_points_rate = if max_era_points == 0 {
+			Permill::zero()
+		} else {
+			Permill::from_rational(input.era_points, max_era_points)
+		};
+		let slash_rate = Permill::from_rational(1u32, input.slashes.saturating_add(1));
+
+		let score = [
+			(config.commission_weight, Permill::one().saturating_sub(input.commission)),
+			(config.self_stake_weight, self_stake_rate),
+			(config.era_points_weight, era_points_rate),
+			(config.slash_weight, slash_rate),
+		]
+		.iter()
+		.fold(0u32, |score, (weight, rate)| {
+			score.saturating_add(weight.mul_floor(rate.deconstruct()))
+		});
+		Permill::from_parts(score)
+	}
+
+	/// Validators of the currency that have inputs and reach the minimum score, the highest
+	/// score first.
+	pub fn get_scored_validators(
+		currency_id: CurrencyId,
+		config: &ValidatorScoringConfig<BalanceOf<T>>,
+	) -> Vec<(MultiLocation, Permill)> {
+		let inputs: Vec<(MultiLocation, ValidatorScoreInput<BalanceOf<T>>)> =
+			Validators::<T>::get(currency_id)
+				.unwrap_or_default()
+				.into_iter()
+				.filter_map(|validator| {
+					ValidatorScoreInputs::<T>::get(currency_id, validator)
+						.map(|input| (validator, input))
+				})
+				.collect();
+		let max_era_points =
+			inputs.iter().map(|(_, input)| input.era_points).max().unwrap_or_default();
+
+		let mut scored: Vec<(MultiLocation, Permill)> = inputs
+			.iter()
+			.map(|(validator, input)| {
+				(*validator, Self::score_validator(config, input, max_era_points))
+			})
+			.filter(|(_, score)| *score >= config.min_score)
+			.collect();
+		// Stable sort keeps the order of Validators<T> for equal scores.
+		scored.sort_by_key(|(_, score)| Reverse(*score));
+		scored
+	}
+
+	/// Compute the target validators of every delegator and the operations to reach them.
+	///
+	/// The delegators of the first `MaxLengthLimit` indices that have an active stake are
+	/// considered, the largest stake first. Each delegator targets a number of scored validators
+	/// proportional to its active stake, `validators_per_delegator` on average. The windows of
+	/// the delegators follow each other so that the delegators back different validators when
+	/// there are enough of them. The operations only replace the validators that are out of the
+	/// target, and stop once `max_churn` changes have been made.
+	pub fn calculate_rebalance_operations(
+		currency_id: CurrencyId,
+	) -> Result<Vec<RebalanceOperation>, Error<T>> {
+		let config = ValidatorScoringConfigs::<T>::get(currency_id)
+			.ok_or(Error::<T>::ValidatorScoringConfigNotExist)?;
+		let scored = Self::get_scored_validators(currency_id, &config);
+		let score_of = |validator: &MultiLocation| {
+			scored.iter().find(|(v, _)| v == validator).map(|(_, score)| *score)
+		};
+
+		let mut delegators: Vec<(MultiLocation, u128)> =
+			(0..Self::get_rebalance_delegators_count(currency_id))
+				.filter_map(|index| {
+					let delegator =
+						DelegatorsIndex2Multilocation::<T>::get(currency_id, index as u16)?;
+					let ledger = DelegatorLedgers::<T>::get(currency_id, delegator)?;
+					let (_, active) = ledger.total_and_active();
+					Some((delegator, active.unique_saturated_into()))
+				})
+				.filter(|(_, active)| *active > 0)
+				.collect();
+		// Stable sort keeps the order of the indices for equal stakes.
+		delegators.sort_by_key(|(_, active)| Reverse(*active));
+		let total_active = delegators
+			.iter()
+			.fold(0u128, |total, (_, active)| total.saturating_add(*active));
+		let total_targets =
+			(config.validators_per_delegator as u128).saturating_mul(delegators.len() as u128);
+
+		let mut churn_left = config.max_churn as usize;
+		let mut start = 0usize;
+		let mut operations = vec![];
+		for (delegator, active) in delegators.iter() {
+			if churn_left == 0 || scored.is_empty() {
+				break;
+			}
+			let per_delegator = multiply_by_rational_with_rounding(
+				total_targets,
+				*active,
+				total_active,
+				Rounding::NearestPrefDown,
+			)
+			.unwrap_or_default()
+			.max(1)
+			.min(scored.len() as u128) as usize;
+			let targets: Vec<MultiLocation> = scored
+				.iter()
+				.cycle()
+				.skip(start)
+				.take(per_delegator)
+				.map(|(validator, _)| *validator)
+				.collect();
+			start = start.saturating_add(per_delegator) % scored.len();
+
+			let current = ValidatorsByDelegator::<T>::get(currency_id, delegator)
+				.map(|validators| validators.to_vec())
+				.unwrap_or_default();
+			if current.is_empty() {
+				let targets: Vec<MultiLocation> = targets.into_iter().take(churn_left).collect();
+				churn_left.saturating_reduce(targets.len());
+				operations.push(RebalanceOperation::Delegate { delegator: *delegator, targets });
+				continue;
+			}
+
+			let to_add: Vec<MultiLocation> =
+				targets.iter().filter(|v| !current.contains(v)).copied().collect();
+			let mut to_remove: Vec<MultiLocation> =
+				current.iter().filter(|v| !targets.contains(v)).copied().collect();
+			// Unscored validators go first, then the lowest score.
+			to_remove.sort_by_key(|validator| score_of(validator));
+
+			let changes = to_add.len().max(to_remove.len()).min(churn_left);
+			if changes == 0 {
+				continue;
+			}
+			let mut new_targets = current.clone();
+			new_targets.retain(|v| !to_remove.iter().take(changes).any(|r| r == v));
+			new_targets.extend(to_add.iter().take(changes));
+			churn_left.saturating_reduce(changes);
+			operations.push(RebalanceOperation::Redelegate {
+				delegator: *delegator,
+				targets: new_targets,
+			});
+		}
+		Ok(operations)
+	}
+
+	/// The number of delegator indices a rebalance goes through, bounded by `MaxLengthLimit`.
+	pub fn get_rebalance_delegators_count(currency_id: CurrencyId) -> u32 {
+		u32::from(DelegatorNextIndex::<T>::get(currency_id)).min(T::MaxLengthLimit::get())
+	}
+}
@@ -0,0 +1,127 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	primitives::{Ledger, LedgerDivergence, LedgerUpdateEntry},
+	BalanceOf, Config, CurrencyId, DelegatorLedgerDivergences, DelegatorLedgerXcmUpdateQueue,
+	DelegatorLedgers, Pallet, QueryId,
+};
+use bifrost_primitives::TimeUnit;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::Get,
+};
+use sp_arithmetic::traits::Zero;
+use sp_runtime::DispatchError;
+use sp_std::prelude::*;
+use xcm::v3::MultiLocation;
+
+impl<T: Config> Pallet<T> {
+	/// The pending ledger updates of the delegator in the XCM update queue, in the order they were
+	/// sent, and the number of queue entries gone through. At most `MaxLengthLimit` queue entries
+	/// are gone through.
+	pub(crate) fn get_pending_ledger_updates(
+		currency_id: CurrencyId,
+		delegator: &MultiLocation,
+	) -> (u32, Vec<(QueryId, LedgerUpdateEntry<BalanceOf<T>>)>) {
+		let queue: Vec<(QueryId, LedgerUpdateEntry<BalanceOf<T>>)> =
+			DelegatorLedgerXcmUpdateQueue::<T>::iter()
+				.take(T::MaxLengthLimit::get() as usize)
+				.map(|(query_id, (entry, _))| (query_id, entry))
+				.collect();
+		let queue_len = queue.len() as u32;
+
+		let mut pending_updates: Vec<(QueryId, LedgerUpdateEntry<BalanceOf<T>>)> = queue
+			.into_iter()
+			.filter(|(_, entry)| {
+				let (entry_currency_id, entry_delegator) = match entry {
+					LedgerUpdateEntry::Substrate(entry) => (entry.currency_id, entry.delegator_id),
+					LedgerUpdateEntry::Moonbeam(entry) |
+					LedgerUpdateEntry::ParachainStaking(entry) => (entry.currency_id, entry.delegator_id),
+				};
+				entry_currency_id == currency_id && entry_delegator == *delegator
+			})
+			.collect();
+		pending_updates.sort_by_key(|(query_id, _)| *query_id);
+		(queue_len, pending_updates)
+	}
+
+	/// The ledger of the delegator once the pending ledger updates are applied. Unless
+	/// `all_updates` is set, only the updates whose query responses have been reported by the
+	/// remote chain are applied. The storage changes are discarded.
+	pub(crate) fn get_ledger_after_pending_updates(
+		currency_id: CurrencyId,
+		delegator: &MultiLocation,
+		pending_updates: &[(QueryId, LedgerUpdateEntry<BalanceOf<T>>)],
+		all_updates: bool,
+	) -> Result<Option<Ledger<BalanceOf<T>>>, DispatchError> {
+		let staking_agent = Self::get_currency_staking_agent(currency_id)?;
+		with_transaction(|| {
+			let ledger = pending_updates
+				.iter()
+				.try_for_each(|(query_id, entry)| {
+					staking_agent
+						.check_delegator_ledger_query_response(
+							*query_id,
+							entry.clone(),
+							all_updates,
+							currency_id,
+						)
+						.map(|_| ())
+				})
+				.map(|_| DelegatorLedgers::<T>::get(currency_id, delegator))
+				.map_err(Into::into);
+			TransactionOutcome::Rollback(ledger)
+		})
+	}
+
+	/// Compare the ledger the pallet expects once all of its XCM operations have landed with the
+	/// ledger reported by the query responses. Returns `None` if they agree.
+	pub fn calculate_ledger_divergence(
+		expected_ledger: &Option<Ledger<BalanceOf<T>>>,
+		actual_ledger: &Option<Ledger<BalanceOf<T>>>,
+		time_unit: TimeUnit,
+	) -> Option<LedgerDivergence<BalanceOf<T>>> {
+		let total_and_active = |ledger: &Option<Ledger<BalanceOf<T>>>| {
+			ledger
+				.as_ref()
+				.map(|ledger| ledger.total_and_active())
+				.unwrap_or((Zero::zero(), Zero::zero()))
+		};
+		let (expected_total, expected_active) = total_and_active(expected_ledger);
+		let (actual_total, actual_active) = total_and_active(actual_ledger);
+
+		if expected_total == actual_total && expected_active == actual_active {
+			return None;
+		}
+		Some(LedgerDivergence {
+			time_unit,
+			expected_total,
+			actual_total,
+			expected_active,
+			actual_active,
+		})
+	}
+
+	/// The divergent delegators of a currency and their last divergence.
+	pub fn get_delegator_ledger_divergences(
+		currency_id: CurrencyId,
+	) -> Vec<(MultiLocation, LedgerDivergence<BalanceOf<T>>)> {
+		DelegatorLedgerDivergences::<T>::iter_prefix(currency_id).collect()
+	}
+}
@@ -0,0 +1,120 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	pallet::{Error, Event},
+	primitives::{Ledger, SlashRecord, SubstrateLedger},
+	AccountIdOf, BalanceOf, Config, CurrencyId, Pallet, SlashRecords,
+};
+use bifrost_primitives::{TimeUnit, VtokenMintingOperator};
+use frame_support::traits::Get;
+use orml_traits::MultiCurrency;
+use sp_arithmetic::traits::Zero;
+use sp_runtime::traits::{AccountIdConversion, Saturating};
+use xcm::v3::MultiLocation;
+
+impl<T: Config> Pallet<T> {
+	/// The account that covers the slashes before the token pool is reduced.
+	pub fn slash_insurance_fund_account() -> AccountIdOf<T> {
+		T::SlashInsurancePalletId::get().into_account_truncating()
+	}
+
+	/// The amount by which the total of a substrate ledger has shrunk, apart from the unlocking
+	/// chunks that were due and have been withdrawn.
+	pub fn unexplained_ledger_shrinkage(
+		old_ledger: &SubstrateLedger<BalanceOf<T>>,
+		new_ledger: &SubstrateLedger<BalanceOf<T>>,
+		current_time_unit: &TimeUnit,
+	) -> BalanceOf<T> {
+		let due_unlocking = |ledger: &SubstrateLedger<BalanceOf<T>>| {
+			ledger
+				.unlocking
+				.iter()
+				.filter(|chunk| chunk.unlock_time <= *current_time_unit)
+				.fold(Zero::zero(), |total: BalanceOf<T>, chunk| total.saturating_add(chunk.value))
+		};
+		let withdrawn = due_unlocking(old_ledger).saturating_sub(due_unlocking(new_ledger));
+		old_ledger.total.saturating_sub(new_ledger.total).saturating_sub(withdrawn)
+	}
+
+	/// Compare the ledger reported for a delegator with the one in storage, and handle the
+	/// shrinkage as a slash. Only substrate ledgers can be slashed.
+	pub fn detect_slash(
+		currency_id: CurrencyId,
+		delegator: &MultiLocation,
+		old_ledger: &Option<Ledger<BalanceOf<T>>>,
+		new_ledger: &Option<Ledger<BalanceOf<T>>>,
+	) -> Result<(), Error<T>> {
+		let (Some(Ledger::Substrate(old_ledger)), Some(Ledger::Substrate(new_ledger))) =
+			(old_ledger, new_ledger)
+		else {
+			return Ok(());
+		};
+		if new_ledger.total >= old_ledger.total {
+			return Ok(());
+		}
+
+		let time_unit = T::VtokenMinting::get_ongoing_time_unit(currency_id)
+			.ok_or(Error::<T>::TimeUnitNotExist)?;
+		let amount = Self::unexplained_ledger_shrinkage(old_ledger, new_ledger, &time_unit);
+		if amount.is_zero() {
+			return Ok(());
+		}
+		Self::do_handle_slash(currency_id, *delegator, amount, time_unit)
+	}
+
+	/// Cover the slash with the insurance fund as much as possible, take the rest from the token
+	/// pool and record it in the slash records of the time unit.
+	pub(crate) fn do_handle_slash(
+		currency_id: CurrencyId,
+		delegator: MultiLocation,
+		amount: BalanceOf<T>,
+		time_unit: TimeUnit,
+	) -> Result<(), Error<T>> {
+		let fund = Self::slash_insurance_fund_account();
+		let insurance_covered = T::MultiCurrency::free_balance(currency_id, &fund).min(amount);
+		if !insurance_covered.is_zero() {
+			let (entrance_account, _) = T::VtokenMinting::get_entrance_and_exit_accounts();
+			T::MultiCurrency::transfer(currency_id, &fund, &entrance_account, insurance_covered)
+				.map_err(|_| Error::<T>::MultiCurrencyError)?;
+		}
+
+		let socialised = amount.saturating_sub(insurance_covered);
+		if !socialised.is_zero() {
+			T::VtokenMinting::decrease_token_pool(currency_id, socialised)
+				.map_err(|_| Error::<T>::UnderFlow)?;
+			Self::deposit_event(Event::PoolTokenDecreased { currency_id, amount: socialised });
+		}
+
+		SlashRecords::<T>::try_append(
+			currency_id,
+			time_unit.clone(),
+			SlashRecord { delegator, amount, insurance_covered },
+		)
+		.map_err(|_| Error::<T>::ExceedMaxLengthLimit)?;
+
+		Self::deposit_event(Event::DelegatorSlashed {
+			currency_id,
+			delegator,
+			time_unit,
+			amount,
+			insurance_covered,
+		});
+		Ok(())
+	}
+}
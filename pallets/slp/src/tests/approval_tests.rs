@@ -0,0 +1,363 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{mocks::mock_kusama::*, *};
+use bifrost_primitives::currency::KSM;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::BadOrigin;
+use xcm::v3::prelude::*;
+
+const DELEGATOR: MultiLocation =
+	MultiLocation { parents: 1, interior: X1(AccountId32 { network: None, id: [1u8; 32] }) };
+const RECEIVER: MultiLocation =
+	MultiLocation { parents: 0, interior: X1(AccountId32 { network: None, id: [2u8; 32] }) };
+
+fn approver(i: u8) -> AccountId {
+	AccountId::new([10 + i; 32])
+}
+
+fn approval_config(threshold: u32) -> OperatorApprovalConfig<AccountId, BlockNumberFor<Runtime>> {
+	OperatorApprovalConfig {
+		approvers: vec![approver(0), approver(1), approver(2)],
+		threshold,
+		expiry: 10,
+	}
+}
+
+fn transfer_to_call() -> Call<Runtime> {
+	Call::<Runtime>::transfer_to {
+		currency_id: KSM,
+		from: Box::new(DELEGATOR),
+		to: Box::new(RECEIVER),
+		amount: 1_000,
+	}
+}
+
+fn approval_setup() {
+	System::set_block_number(1);
+	assert_ok!(Slp::set_operate_origin(RuntimeOrigin::signed(ALICE), KSM, Some(BOB)));
+	assert_ok!(Slp::set_operator_approval_config(
+		RuntimeOrigin::signed(ALICE),
+		KSM,
+		OperatorCallClass::Transfer,
+		Some(approval_config(2))
+	));
+}
+
+#[test]
+fn set_operator_approval_config_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Slp::set_operator_approval_config(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				OperatorCallClass::Transfer,
+				Some(approval_config(2))
+			),
+			BadOrigin
+		);
+
+		let mut duplicated = approval_config(2);
+		duplicated.approvers.push(approver(0));
+		let mut no_expiry = approval_config(2);
+		no_expiry.expiry = 0;
+		for config in [approval_config(0), approval_config(4), duplicated, no_expiry] {
+			assert_noop!(
+				Slp::set_operator_approval_config(
+					RuntimeOrigin::signed(ALICE),
+					KSM,
+					OperatorCallClass::Transfer,
+					Some(config)
+				),
+				Error::<Runtime>::InvalidOperatorApprovalConfig
+			);
+		}
+
+		assert_ok!(Slp::set_operator_approval_config(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			OperatorCallClass::Transfer,
+			Some(approval_config(2))
+		));
+		assert_eq!(
+			OperatorApprovalConfigs::<Runtime>::get(KSM, OperatorCallClass::Transfer),
+			Some(approval_config(2))
+		);
+
+		assert_ok!(Slp::set_operator_approval_config(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			OperatorCallClass::Transfer,
+			None
+		));
+		assert_eq!(OperatorApprovalConfigs::<Runtime>::get(KSM, OperatorCallClass::Transfer), None);
+	});
+}
+
+#[test]
+fn operator_call_should_need_approval() {
+	ExtBuilder::default().build().execute_with(|| {
+		approval_setup();
+		let call_hash = Slp::operation_call_hash(transfer_to_call());
+
+		assert_noop!(
+			Slp::transfer_to(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				Box::new(DELEGATOR),
+				Box::new(RECEIVER),
+				1_000
+			),
+			Error::<Runtime>::OperationNotApproved
+		);
+
+		assert_noop!(
+			Slp::approve_operation(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				OperatorCallClass::Transfer,
+				call_hash
+			),
+			Error::<Runtime>::NotApprover
+		);
+		assert_ok!(Slp::approve_operation(
+			RuntimeOrigin::signed(approver(0)),
+			KSM,
+			OperatorCallClass::Transfer,
+			call_hash
+		));
+		assert_noop!(
+			Slp::approve_operation(
+				RuntimeOrigin::signed(approver(0)),
+				KSM,
+				OperatorCallClass::Transfer,
+				call_hash
+			),
+			Error::<Runtime>::OperationAlreadyApproved
+		);
+		assert_noop!(
+			Slp::approve_operation(
+				RuntimeOrigin::signed(approver(1)),
+				KSM,
+				OperatorCallClass::Bond,
+				call_hash
+			),
+			Error::<Runtime>::OperatorApprovalConfigNotExist
+		);
+
+		// One approval is not enough.
+		assert_noop!(
+			Slp::ensure_approved_or_authorized(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				OperatorCallClass::Transfer,
+				transfer_to_call()
+			),
+			Error::<Runtime>::OperationNotApproved
+		);
+
+		assert_ok!(Slp::approve_operation(
+			RuntimeOrigin::signed(approver(1)),
+			KSM,
+			OperatorCallClass::Transfer,
+			call_hash
+		));
+		System::assert_last_event(RuntimeEvent::Slp(Event::OperationApproved {
+			currency_id: KSM,
+			class: OperatorCallClass::Transfer,
+			call_hash,
+			approver: approver(1),
+			approvals: 2,
+		}));
+
+		// The approval only covers the approved call.
+		let other_call = Call::<Runtime>::transfer_to {
+			currency_id: KSM,
+			from: Box::new(DELEGATOR),
+			to: Box::new(RECEIVER),
+			amount: 2_000,
+		};
+		assert_noop!(
+			Slp::ensure_approved_or_authorized(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				OperatorCallClass::Transfer,
+				other_call
+			),
+			Error::<Runtime>::OperationNotApproved
+		);
+
+		assert_ok!(Slp::ensure_approved_or_authorized(
+			RuntimeOrigin::signed(BOB),
+			KSM,
+			OperatorCallClass::Transfer,
+			transfer_to_call()
+		));
+		System::assert_last_event(RuntimeEvent::Slp(Event::OperationExecuted {
+			currency_id: KSM,
+			class: OperatorCallClass::Transfer,
+			call_hash,
+		}));
+		// The approval is consumed.
+		assert_eq!(PendingOperations::<Runtime>::get(KSM, call_hash), None);
+		assert_noop!(
+			Slp::ensure_approved_or_authorized(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				OperatorCallClass::Transfer,
+				transfer_to_call()
+			),
+			Error::<Runtime>::OperationNotApproved
+		);
+	});
+}
+
+#[test]
+fn governance_and_unconfigured_classes_should_skip_approval() {
+	ExtBuilder::default().build().execute_with(|| {
+		approval_setup();
+
+		assert_ok!(Slp::ensure_approved_or_authorized(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			OperatorCallClass::Transfer,
+			transfer_to_call()
+		));
+		let bond_call = Call::<Runtime>::bond {
+			currency_id: KSM,
+			who: Box::new(DELEGATOR),
+			amount: 1_000,
+			validator: None,
+			weight_and_fee: None,
+		};
+		assert_ok!(Slp::ensure_approved_or_authorized(
+			RuntimeOrigin::signed(BOB),
+			KSM,
+			OperatorCallClass::Bond,
+			bond_call
+		));
+		assert_noop!(
+			Slp::ensure_approved_or_authorized(
+				RuntimeOrigin::signed(approver(0)),
+				KSM,
+				OperatorCallClass::Transfer,
+				transfer_to_call()
+			),
+			Error::<Runtime>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn operation_should_expire() {
+	ExtBuilder::default().build().execute_with(|| {
+		approval_setup();
+		let call_hash = Slp::operation_call_hash(transfer_to_call());
+
+		for i in 0..2 {
+			assert_ok!(Slp::approve_operation(
+				RuntimeOrigin::signed(approver(i)),
+				KSM,
+				OperatorCallClass::Transfer,
+				call_hash
+			));
+		}
+		assert_eq!(PendingOperations::<Runtime>::get(KSM, call_hash).unwrap().expire_at, 11);
+
+		System::set_block_number(12);
+		assert_noop!(
+			Slp::ensure_approved_or_authorized(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				OperatorCallClass::Transfer,
+				transfer_to_call()
+			),
+			Error::<Runtime>::OperationExpired
+		);
+
+		// Approving an expired operation proposes it again.
+		assert_ok!(Slp::approve_operation(
+			RuntimeOrigin::signed(approver(2)),
+			KSM,
+			OperatorCallClass::Transfer,
+			call_hash
+		));
+		System::assert_has_event(RuntimeEvent::Slp(Event::OperationExpired {
+			currency_id: KSM,
+			call_hash,
+		}));
+		assert_eq!(
+			PendingOperations::<Runtime>::get(KSM, call_hash),
+			Some(PendingOperation {
+				class: OperatorCallClass::Transfer,
+				approvals: vec![approver(2)],
+				expire_at: 22,
+			})
+		);
+
+		// Anyone can remove an expired operation.
+		System::set_block_number(23);
+		assert_ok!(Slp::cancel_operation(RuntimeOrigin::signed(BOB), KSM, call_hash));
+		assert_eq!(PendingOperations::<Runtime>::get(KSM, call_hash), None);
+		System::assert_last_event(RuntimeEvent::Slp(Event::OperationExpired {
+			currency_id: KSM,
+			call_hash,
+		}));
+	});
+}
+
+#[test]
+fn cancel_operation_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		approval_setup();
+		let call_hash = Slp::operation_call_hash(transfer_to_call());
+
+		assert_noop!(
+			Slp::cancel_operation(RuntimeOrigin::signed(approver(0)), KSM, call_hash),
+			Error::<Runtime>::OperationNotExist
+		);
+		assert_ok!(Slp::approve_operation(
+			RuntimeOrigin::signed(approver(0)),
+			KSM,
+			OperatorCallClass::Transfer,
+			call_hash
+		));
+
+		assert_noop!(
+			Slp::cancel_operation(RuntimeOrigin::signed(BOB), KSM, call_hash),
+			Error::<Runtime>::NotAuthorized
+		);
+		assert_ok!(Slp::cancel_operation(RuntimeOrigin::signed(approver(1)), KSM, call_hash));
+		assert_eq!(PendingOperations::<Runtime>::get(KSM, call_hash), None);
+		System::assert_last_event(RuntimeEvent::Slp(Event::OperationCancelled {
+			currency_id: KSM,
+			call_hash,
+		}));
+
+		assert_ok!(Slp::approve_operation(
+			RuntimeOrigin::signed(approver(0)),
+			KSM,
+			OperatorCallClass::Transfer,
+			call_hash
+		));
+		assert_ok!(Slp::cancel_operation(RuntimeOrigin::signed(ALICE), KSM, call_hash));
+		assert_eq!(PendingOperations::<Runtime>::get(KSM, call_hash), None);
+	});
+}
@@ -21,7 +21,8 @@
 use crate::{mocks::mock::*, primitives::FilecoinLedger, *};
 use bifrost_primitives::currency::{FIL, VFIL};
 use frame_support::{assert_noop, assert_ok, PalletId};
-use sp_runtime::traits::AccountIdConversion;
+use sp_core::{sr25519, Pair};
+use sp_runtime::{traits::AccountIdConversion, MultiSignature};
 
 fn mins_maxs_setup() {
 	let mins_and_maxs = MinimumsMaximums {
@@ -560,3 +561,278 @@ fn filecoin_transfer_to_works() {
 		));
 	});
 }
+
+fn oracle_pair(seed: u8) -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[seed; 32])
+}
+
+fn oracle_set_setup() -> Vec<sr25519::Pair> {
+	let pairs: Vec<sr25519::Pair> = (1..=3).map(oracle_pair).collect();
+	assert_ok!(Slp::set_filecoin_oracle_set(
+		RuntimeOrigin::signed(ALICE),
+		FIL,
+		Some(FilecoinOracleSet {
+			oracles: pairs.iter().map(|pair| pair.public().into()).collect(),
+			threshold: 2,
+		})
+	));
+	pairs
+}
+
+fn miner_report(time_unit: u32, balance: u128, rewards: u128) -> FilecoinMinerReport<u128> {
+	FilecoinMinerReport {
+		miner: MultiLocation { parents: 100, interior: X1(Junction::from(BoundedVec::default())) },
+		time_unit: TimeUnit::Era(time_unit),
+		balance,
+		rewards,
+		initial_pledge: 800,
+	}
+}
+
+fn sign_report(
+	pairs: &[&sr25519::Pair],
+	report: &FilecoinMinerReport<u128>,
+) -> Vec<(AccountId, MultiSignature)> {
+	let payload = Slp::filecoin_report_payload(FIL, report);
+	pairs
+		.iter()
+		.map(|pair| (pair.public().into(), MultiSignature::from(pair.sign(&payload))))
+		.collect()
+}
+
+#[test]
+fn set_filecoin_oracle_set_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let oracles: Vec<AccountId> =
+			(1..=3).map(|seed| oracle_pair(seed).public().into()).collect();
+
+		assert_noop!(
+			Slp::set_filecoin_oracle_set(
+				RuntimeOrigin::signed(ALICE),
+				VFIL,
+				Some(FilecoinOracleSet { oracles: oracles.clone(), threshold: 2 })
+			),
+			Error::<Runtime>::NotSupportedCurrencyId
+		);
+		for (oracles, threshold) in [
+			(oracles.clone(), 0),
+			(oracles.clone(), 4),
+			(vec![oracles[0].clone(), oracles[0].clone()], 1),
+		] {
+			assert_noop!(
+				Slp::set_filecoin_oracle_set(
+					RuntimeOrigin::signed(ALICE),
+					FIL,
+					Some(FilecoinOracleSet { oracles, threshold })
+				),
+				Error::<Runtime>::InvalidOracleSet
+			);
+		}
+
+		assert_ok!(Slp::set_filecoin_oracle_set(
+			RuntimeOrigin::signed(ALICE),
+			FIL,
+			Some(FilecoinOracleSet { oracles: oracles.clone(), threshold: 2 })
+		));
+		assert_eq!(
+			FilecoinOracleSets::<Runtime>::get(FIL),
+			Some(FilecoinOracleSet { oracles, threshold: 2 })
+		);
+	});
+}
+
+#[test]
+fn submit_filecoin_report_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let location =
+			MultiLocation { parents: 100, interior: X1(Junction::from(BoundedVec::default())) };
+		bifrost_vtoken_minting::OngoingTimeUnit::<Runtime>::insert(FIL, TimeUnit::Era(1));
+		initialize_delegator_setup();
+		let report = miner_report(1, 1_000, 0);
+
+		assert_noop!(
+			Slp::submit_filecoin_report(RuntimeOrigin::signed(BOB), FIL, report.clone(), vec![]),
+			Error::<Runtime>::OracleSetNotExist
+		);
+		let pairs = oracle_set_setup();
+		let stranger = oracle_pair(9);
+
+		// One signature, a duplicated signature and a signature of a stranger are not enough.
+		for signers in [vec![&pairs[0]], vec![&pairs[0], &pairs[0]], vec![&pairs[0], &stranger]] {
+			assert_noop!(
+				Slp::submit_filecoin_report(
+					RuntimeOrigin::signed(BOB),
+					FIL,
+					report.clone(),
+					sign_report(&signers, &report)
+				),
+				Error::<Runtime>::InsufficientAttestations
+			);
+		}
+		// A signature of another report is invalid.
+		let mut signatures = sign_report(&[&pairs[0]], &report);
+		signatures.extend(sign_report(&[&pairs[1]], &miner_report(1, 2_000, 0)));
+		assert_noop!(
+			Slp::submit_filecoin_report(
+				RuntimeOrigin::signed(BOB),
+				FIL,
+				report.clone(),
+				signatures
+			),
+			Error::<Runtime>::InsufficientAttestations
+		);
+		assert_noop!(
+			Slp::submit_filecoin_report(
+				RuntimeOrigin::signed(BOB),
+				FIL,
+				miner_report(0, 1_000, 0),
+				sign_report(&[&pairs[0], &pairs[1]], &miner_report(0, 1_000, 0))
+			),
+			Error::<Runtime>::InvalidReportTimeUnit
+		);
+		// The first report sets the baseline, it can't carry rewards.
+		assert_noop!(
+			Slp::submit_filecoin_report(
+				RuntimeOrigin::signed(BOB),
+				FIL,
+				miner_report(1, 1_000, 10),
+				sign_report(&[&pairs[0], &pairs[1]], &miner_report(1, 1_000, 10))
+			),
+			Error::<Runtime>::InvalidReportRewards
+		);
+
+		assert_ok!(Slp::submit_filecoin_report(
+			RuntimeOrigin::signed(BOB),
+			FIL,
+			report.clone(),
+			sign_report(&[&pairs[0], &pairs[2]], &report)
+		));
+		assert_eq!(VerifiedFilecoinReports::<Runtime>::get(FIL, location), Some(report.clone()));
+		assert_eq!(
+			DelegatorLedgers::<Runtime>::get(FIL, location),
+			Some(Ledger::Filecoin(FilecoinLedger { account: location, initial_pledge: 800 }))
+		);
+		assert_eq!(FilecoinVerifiedRewards::<Runtime>::get(FIL, location), 0);
+		assert_noop!(
+			Slp::submit_filecoin_report(
+				RuntimeOrigin::signed(BOB),
+				FIL,
+				report.clone(),
+				sign_report(&[&pairs[0], &pairs[1]], &report)
+			),
+			Error::<Runtime>::ReportAlreadyVerified
+		);
+
+		// The miner is pledged 30 more, which is not a reward.
+		assert_ok!(Slp::bond_extra(
+			RuntimeOrigin::signed(ALICE),
+			FIL,
+			Box::new(location),
+			None,
+			30,
+			None
+		));
+		assert_eq!(FilecoinTransfersIn::<Runtime>::get(FIL, location), 30);
+
+		// The rewards of the next time unit can't exceed the balance growth without the pledge.
+		bifrost_vtoken_minting::OngoingTimeUnit::<Runtime>::insert(FIL, TimeUnit::Era(2));
+		assert_noop!(
+			Slp::submit_filecoin_report(
+				RuntimeOrigin::signed(BOB),
+				FIL,
+				miner_report(2, 1_100, 100),
+				sign_report(&[&pairs[1], &pairs[2]], &miner_report(2, 1_100, 100))
+			),
+			Error::<Runtime>::InvalidReportRewards
+		);
+		let report = miner_report(2, 1_100, 60);
+		assert_ok!(Slp::submit_filecoin_report(
+			RuntimeOrigin::signed(BOB),
+			FIL,
+			report.clone(),
+			sign_report(&[&pairs[1], &pairs[2]], &report)
+		));
+		assert_eq!(FilecoinVerifiedRewards::<Runtime>::get(FIL, location), 60);
+		assert_eq!(FilecoinTransfersIn::<Runtime>::get(FIL, location), 0);
+	});
+}
+
+#[test]
+fn tune_vtoken_exchange_rate_with_verified_rewards_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let location =
+			MultiLocation { parents: 100, interior: X1(Junction::from(BoundedVec::default())) };
+		bifrost_vtoken_minting::OngoingTimeUnit::<Runtime>::insert(FIL, TimeUnit::Era(1));
+		initialize_delegator_setup();
+		let pairs = oracle_set_setup();
+
+		let treasury_id: AccountId = PalletId(*b"bf/trsry").into_account_truncating();
+		let treasury_location = MultiLocation {
+			parents: 0,
+			interior: X1(AccountId32 { network: None, id: treasury_id.into() }),
+		};
+		assert_ok!(Slp::set_hosting_fees(
+			RuntimeOrigin::signed(ALICE),
+			FIL,
+			Some((Permill::from_percent(20), treasury_location))
+		));
+		assert_ok!(Slp::set_currency_tune_exchange_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			FIL,
+			Some((1, Permill::from_percent(100)))
+		));
+		Validators::<Runtime>::insert(FIL, BoundedVec::try_from(vec![location]).unwrap());
+		assert_ok!(Currencies::deposit(VFIL, &ALICE, 1_000));
+		assert_ok!(Slp::increase_token_pool(RuntimeOrigin::signed(ALICE), FIL, 1_000));
+
+		for (time_unit, balance, rewards) in [(1, 1_000, 0), (2, 1_100, 100)] {
+			bifrost_vtoken_minting::OngoingTimeUnit::<Runtime>::insert(
+				FIL,
+				TimeUnit::Era(time_unit),
+			);
+			let report = miner_report(time_unit, balance, rewards);
+			assert_ok!(Slp::submit_filecoin_report(
+				RuntimeOrigin::signed(BOB),
+				FIL,
+				report.clone(),
+				sign_report(&[&pairs[0], &pairs[1]], &report)
+			));
+		}
+
+		assert_noop!(
+			Slp::charge_host_fee_and_tune_vtoken_exchange_rate(
+				RuntimeOrigin::signed(ALICE),
+				FIL,
+				150,
+				Some(location)
+			),
+			Error::<Runtime>::ExceedVerifiedRewards
+		);
+		assert_ok!(Slp::charge_host_fee_and_tune_vtoken_exchange_rate(
+			RuntimeOrigin::signed(ALICE),
+			FIL,
+			100,
+			Some(location)
+		));
+		assert_eq!(FilecoinVerifiedRewards::<Runtime>::get(FIL, location), 0);
+	});
+}
+
+#[test]
+fn set_delegator_ledger_should_not_work_for_filecoin() {
+	ExtBuilder::default().build().execute_with(|| {
+		let location =
+			MultiLocation { parents: 100, interior: X1(Junction::from(BoundedVec::default())) };
+		let ledger = Ledger::Filecoin(FilecoinLedger { account: location, initial_pledge: 800 });
+
+		assert_noop!(
+			Slp::set_delegator_ledger(
+				RuntimeOrigin::signed(ALICE),
+				FIL,
+				Box::new(location),
+				Box::new(Some(ledger))
+			),
+			Error::<Runtime>::NotSupportedCurrencyId
+		);
+	});
+}
@@ -16,6 +16,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(test)]
+mod approval_tests;
 #[cfg(test)]
 mod astar_tests;
 #[cfg(test)]
@@ -30,3 +32,9 @@ mod moonriver_tests;
 mod parachain_staking_tests;
 #[cfg(test)]
 mod phala_tests;
+#[cfg(test)]
+mod rebalance_tests;
+#[cfg(test)]
+mod reconcile_tests;
+#[cfg(test)]
+mod slash_tests;
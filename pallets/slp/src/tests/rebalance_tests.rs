@@ -0,0 +1,249 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{mocks::mock_kusama::*, *};
+use bifrost_primitives::currency::KSM;
+use frame_support::{assert_noop, assert_ok};
+use xcm::v3::prelude::*;
+
+const fn location(id: u8) -> MultiLocation {
+	MultiLocation { parents: 1, interior: X1(AccountId32 { network: None, id: [id; 32] }) }
+}
+
+const DELEGATOR_0: MultiLocation = location(1);
+const DELEGATOR_1: MultiLocation = location(2);
+const VALIDATOR_1: MultiLocation = location(11);
+const VALIDATOR_2: MultiLocation = location(12);
+const VALIDATOR_3: MultiLocation = location(13);
+const VALIDATOR_4: MultiLocation = location(14);
+
+fn scoring_config(max_churn: u32) -> ValidatorScoringConfig<u128> {
+	ValidatorScoringConfig {
+		commission_weight: Permill::from_percent(50),
+		self_stake_weight: Permill::zero(),
+		era_points_weight: Permill::zero(),
+		slash_weight: Permill::from_percent(50),
+		self_stake_cap: 0,
+		min_score: Permill::from_percent(80),
+		validators_per_delegator: 2,
+		max_churn,
+	}
+}
+
+fn score_input(commission: u32, slashes: u32) -> ValidatorScoreInput<u128> {
+	ValidatorScoreInput {
+		commission: Permill::from_percent(commission),
+		self_stake: 0,
+		era_points: 0,
+		slashes,
+	}
+}
+
+fn rebalance_setup(max_churn: u32) {
+	bifrost_vtoken_minting::OngoingTimeUnit::<Runtime>::insert(KSM, TimeUnit::Era(8));
+	Validators::<Runtime>::insert(
+		KSM,
+		BoundedVec::try_from(vec![VALIDATOR_1, VALIDATOR_2, VALIDATOR_3, VALIDATOR_4]).unwrap(),
+	);
+	assert_ok!(Slp::set_validator_scoring_config(
+		RuntimeOrigin::signed(ALICE),
+		KSM,
+		Some(scoring_config(max_churn))
+	));
+	// Scores: VALIDATOR_1 100%, VALIDATOR_2 95%, VALIDATOR_3 90%, VALIDATOR_4 75%.
+	assert_ok!(Slp::update_validator_score_inputs(
+		RuntimeOrigin::signed(ALICE),
+		KSM,
+		vec![
+			(VALIDATOR_1, score_input(0, 0)),
+			(VALIDATOR_2, score_input(10, 0)),
+			(VALIDATOR_3, score_input(20, 0)),
+			(VALIDATOR_4, score_input(0, 1)),
+		]
+	));
+
+	for (index, delegator) in [DELEGATOR_0, DELEGATOR_1].into_iter().enumerate() {
+		DelegatorsIndex2Multilocation::<Runtime>::insert(KSM, index as u16, delegator);
+		set_active(delegator, 1_000);
+	}
+	DelegatorNextIndex::<Runtime>::insert(KSM, 2);
+	ValidatorsByDelegator::<Runtime>::insert(
+		KSM,
+		DELEGATOR_0,
+		BoundedVec::try_from(vec![VALIDATOR_4, VALIDATOR_2]).unwrap(),
+	);
+}
+
+fn set_active(delegator: MultiLocation, active: u128) {
+	DelegatorLedgers::<Runtime>::insert(
+		KSM,
+		delegator,
+		Ledger::Substrate(SubstrateLedger {
+			account: delegator,
+			total: active,
+			active,
+			unlocking: vec![],
+		}),
+	);
+}
+
+#[test]
+fn set_validator_scoring_config_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mut config = scoring_config(10);
+		config.self_stake_weight = Permill::from_percent(1);
+		assert_noop!(
+			Slp::set_validator_scoring_config(RuntimeOrigin::signed(ALICE), KSM, Some(config)),
+			Error::<Runtime>::InvalidValidatorScoringConfig
+		);
+
+		assert_ok!(Slp::set_validator_scoring_config(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(scoring_config(10))
+		));
+		assert_eq!(ValidatorScoringConfigs::<Runtime>::get(KSM), Some(scoring_config(10)));
+
+		assert_ok!(Slp::set_validator_scoring_config(RuntimeOrigin::signed(ALICE), KSM, None));
+		assert_eq!(ValidatorScoringConfigs::<Runtime>::get(KSM), None);
+	});
+}
+
+#[test]
+fn update_validator_score_inputs_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		Validators::<Runtime>::insert(KSM, BoundedVec::try_from(vec![VALIDATOR_1]).unwrap());
+
+		assert_noop!(
+			Slp::update_validator_score_inputs(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				vec![(VALIDATOR_2, score_input(0, 0))]
+			),
+			Error::<Runtime>::ValidatorNotExist
+		);
+
+		assert_ok!(Slp::update_validator_score_inputs(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			vec![(VALIDATOR_1, score_input(5, 0))]
+		));
+		assert_eq!(ValidatorScoreInputs::<Runtime>::get(KSM, VALIDATOR_1), Some(score_input(5, 0)));
+	});
+}
+
+#[test]
+fn rebalance_validators_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Slp::rebalance_validators(RuntimeOrigin::signed(ALICE), KSM),
+			Error::<Runtime>::TimeUnitNotExist
+		);
+		rebalance_setup(10);
+
+		assert_eq!(
+			Slp::get_scored_validators(KSM, &scoring_config(10)),
+			vec![
+				(VALIDATOR_1, Permill::from_percent(100)),
+				(VALIDATOR_2, Permill::from_percent(95)),
+				(VALIDATOR_3, Permill::from_percent(90)),
+			]
+		);
+
+		let operations = vec![
+			RebalanceOperation::Redelegate {
+				delegator: DELEGATOR_0,
+				targets: vec![VALIDATOR_2, VALIDATOR_1],
+			},
+			RebalanceOperation::Delegate {
+				delegator: DELEGATOR_1,
+				targets: vec![VALIDATOR_3, VALIDATOR_1],
+			},
+		];
+		assert_ok!(Slp::rebalance_validators(RuntimeOrigin::signed(ALICE), KSM));
+		System::assert_last_event(RuntimeEvent::Slp(Event::ValidatorsRebalanced {
+			currency_id: KSM,
+			time_unit: TimeUnit::Era(8),
+			operations,
+		}));
+		assert_eq!(LastRebalanceTimeUnit::<Runtime>::get(KSM), Some(TimeUnit::Era(8)));
+
+		// Only once per time unit.
+		assert_noop!(
+			Slp::rebalance_validators(RuntimeOrigin::signed(ALICE), KSM),
+			Error::<Runtime>::AlreadyRebalanced
+		);
+		bifrost_vtoken_minting::OngoingTimeUnit::<Runtime>::insert(KSM, TimeUnit::Era(9));
+		assert_ok!(Slp::rebalance_validators(RuntimeOrigin::signed(ALICE), KSM));
+	});
+}
+
+#[test]
+fn rebalance_validators_should_respect_max_churn() {
+	ExtBuilder::default().build().execute_with(|| {
+		rebalance_setup(2);
+
+		assert_eq!(
+			Slp::calculate_rebalance_operations(KSM).unwrap(),
+			vec![
+				RebalanceOperation::Redelegate {
+					delegator: DELEGATOR_0,
+					targets: vec![VALIDATOR_2, VALIDATOR_1],
+				},
+				RebalanceOperation::Delegate { delegator: DELEGATOR_1, targets: vec![VALIDATOR_3] },
+			]
+		);
+	});
+}
+
+#[test]
+fn rebalance_validators_should_weight_by_stake() {
+	ExtBuilder::default().build().execute_with(|| {
+		rebalance_setup(10);
+		set_active(DELEGATOR_1, 3_000);
+
+		// DELEGATOR_1 has three quarters of the stake, it goes first with three of the four
+		// targets.
+		assert_eq!(
+			Slp::calculate_rebalance_operations(KSM).unwrap(),
+			vec![
+				RebalanceOperation::Delegate {
+					delegator: DELEGATOR_1,
+					targets: vec![VALIDATOR_1, VALIDATOR_2, VALIDATOR_3],
+				},
+				RebalanceOperation::Redelegate {
+					delegator: DELEGATOR_0,
+					targets: vec![VALIDATOR_1],
+				},
+			]
+		);
+
+		// Delegators without active stake are left out.
+		set_active(DELEGATOR_0, 0);
+		assert_eq!(
+			Slp::calculate_rebalance_operations(KSM).unwrap(),
+			vec![RebalanceOperation::Delegate {
+				delegator: DELEGATOR_1,
+				targets: vec![VALIDATOR_1, VALIDATOR_2],
+			}]
+		);
+	});
+}
@@ -0,0 +1,118 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{
+	mocks::mock_kusama::*,
+	primitives::{SubstrateLedgerUpdateEntry, SubstrateLedgerUpdateOperation},
+	*,
+};
+use bifrost_primitives::currency::KSM;
+use frame_support::{assert_noop, assert_ok};
+use xcm::v3::prelude::*;
+
+const DELEGATOR: MultiLocation =
+	MultiLocation { parents: 1, interior: X1(AccountId32 { network: None, id: [1u8; 32] }) };
+const OTHER_DELEGATOR: MultiLocation =
+	MultiLocation { parents: 1, interior: X1(AccountId32 { network: None, id: [2u8; 32] }) };
+
+fn substrate_ledger(total: u128, active: u128) -> Ledger<u128> {
+	Ledger::Substrate(SubstrateLedger { account: DELEGATOR, total, active, unlocking: vec![] })
+}
+
+fn queue_ledger_update(
+	query_id: QueryId,
+	delegator: MultiLocation,
+	update_operation: SubstrateLedgerUpdateOperation,
+	amount: u128,
+) {
+	let entry = LedgerUpdateEntry::Substrate(SubstrateLedgerUpdateEntry {
+		currency_id: KSM,
+		delegator_id: delegator,
+		update_operation,
+		amount,
+		unlock_time: Some(TimeUnit::Era(10)),
+	});
+	DelegatorLedgerXcmUpdateQueue::<Runtime>::insert(query_id, (entry, 1_000));
+}
+
+#[test]
+fn reconcile_delegator_ledger_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		bifrost_vtoken_minting::OngoingTimeUnit::<Runtime>::insert(KSM, TimeUnit::Era(8));
+
+		assert_noop!(
+			Slp::reconcile_delegator_ledger(RuntimeOrigin::signed(ALICE), KSM, Box::new(DELEGATOR)),
+			Error::<Runtime>::DelegatorNotExist
+		);
+
+		DelegatorsMultilocation2Index::<Runtime>::insert(KSM, DELEGATOR, 0);
+		DelegatorLedgers::<Runtime>::insert(KSM, DELEGATOR, substrate_ledger(1_000, 1_000));
+
+		// Nothing is pending, the ledgers agree.
+		assert_ok!(Slp::reconcile_delegator_ledger(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Box::new(DELEGATOR)
+		));
+		assert_eq!(Slp::get_delegator_ledger_divergences(KSM), vec![]);
+
+		// A bond and an unlock have been sent, their responses have not been reported.
+		queue_ledger_update(1, DELEGATOR, SubstrateLedgerUpdateOperation::Bond, 500);
+		queue_ledger_update(2, DELEGATOR, SubstrateLedgerUpdateOperation::Unlock, 300);
+		queue_ledger_update(3, OTHER_DELEGATOR, SubstrateLedgerUpdateOperation::Bond, 700);
+		assert_ok!(Slp::reconcile_delegator_ledger(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Box::new(DELEGATOR)
+		));
+		let divergence = LedgerDivergence {
+			time_unit: TimeUnit::Era(8),
+			expected_total: 1_500,
+			actual_total: 1_000,
+			expected_active: 1_200,
+			actual_active: 1_000,
+		};
+		assert_eq!(Slp::get_delegator_ledger_divergences(KSM), vec![(DELEGATOR, divergence)]);
+		// The ledger of the pallet and the queue are untouched.
+		assert_eq!(
+			DelegatorLedgers::<Runtime>::get(KSM, DELEGATOR),
+			Some(substrate_ledger(1_000, 1_000))
+		);
+		assert_eq!(DelegatorLedgerXcmUpdateQueue::<Runtime>::iter_keys().count(), 3);
+
+		// The responses are confirmed, the ledgers agree again.
+		assert_ok!(Slp::confirm_delegator_ledger_query_response(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			1
+		));
+		assert_ok!(Slp::confirm_delegator_ledger_query_response(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			2
+		));
+		assert_ok!(Slp::reconcile_delegator_ledger(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Box::new(DELEGATOR)
+		));
+		assert_eq!(Slp::get_delegator_ledger_divergences(KSM), vec![]);
+	});
+}
@@ -0,0 +1,178 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{mocks::mock_kusama::*, primitives::UnlockChunk, *};
+use bifrost_primitives::currency::KSM;
+use bifrost_vtoken_minting::TokenPool;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::BadOrigin;
+use xcm::v3::prelude::*;
+
+const DELEGATOR: MultiLocation =
+	MultiLocation { parents: 1, interior: X1(AccountId32 { network: None, id: [1u8; 32] }) };
+
+fn substrate_ledger(active: u128, unlocking: Vec<(u128, u32)>) -> Ledger<u128> {
+	let unlocking: Vec<UnlockChunk<u128>> = unlocking
+		.into_iter()
+		.map(|(value, era)| UnlockChunk { value, unlock_time: TimeUnit::Era(era) })
+		.collect();
+	let total = unlocking.iter().fold(active, |total, chunk| total + chunk.value);
+	Ledger::Substrate(SubstrateLedger { account: DELEGATOR, total, active, unlocking })
+}
+
+fn slash_setup(ledger: Ledger<u128>) {
+	System::set_block_number(1);
+	bifrost_vtoken_minting::OngoingTimeUnit::<Runtime>::insert(KSM, TimeUnit::Era(8));
+	TokenPool::<Runtime>::insert(KSM, 1_000);
+	DelegatorLedgers::<Runtime>::insert(KSM, DELEGATOR, ledger);
+}
+
+#[test]
+fn unbonding_and_withdrawal_should_not_be_slash() {
+	ExtBuilder::default().build().execute_with(|| {
+		slash_setup(substrate_ledger(1_000, vec![]));
+
+		// Unbonding keeps the total.
+		assert_ok!(Slp::report_slash(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Box::new(DELEGATOR),
+			Box::new(substrate_ledger(800, vec![(100, 5), (100, 10)]))
+		));
+		// Withdrawing the due chunk lowers the total.
+		assert_ok!(Slp::report_slash(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Box::new(DELEGATOR),
+			Box::new(substrate_ledger(800, vec![(100, 10)]))
+		));
+
+		assert_eq!(TokenPool::<Runtime>::get(KSM), 1_000);
+		assert_eq!(SlashRecords::<Runtime>::get(KSM, TimeUnit::Era(8)), vec![]);
+	});
+}
+
+#[test]
+fn slash_should_only_be_reported_by_governance() {
+	ExtBuilder::default().build().execute_with(|| {
+		slash_setup(substrate_ledger(1_000, vec![]));
+		assert_ok!(Slp::set_operate_origin(RuntimeOrigin::signed(ALICE), KSM, Some(BOB)));
+
+		assert_noop!(
+			Slp::report_slash(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				Box::new(DELEGATOR),
+				Box::new(substrate_ledger(900, vec![]))
+			),
+			BadOrigin
+		);
+
+		// A ledger correction by the operator is not a slash.
+		assert_ok!(Slp::set_delegator_ledger(
+			RuntimeOrigin::signed(BOB),
+			KSM,
+			Box::new(DELEGATOR),
+			Box::new(Some(substrate_ledger(900, vec![])))
+		));
+		assert_eq!(TokenPool::<Runtime>::get(KSM), 1_000);
+		assert_eq!(SlashRecords::<Runtime>::get(KSM, TimeUnit::Era(8)), vec![]);
+	});
+}
+
+#[test]
+fn slash_should_decrease_token_pool() {
+	ExtBuilder::default().build().execute_with(|| {
+		slash_setup(substrate_ledger(800, vec![(100, 5), (100, 10)]));
+
+		// The due chunk is withdrawn, and the rest is slashed by 10%.
+		assert_ok!(Slp::report_slash(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Box::new(DELEGATOR),
+			Box::new(substrate_ledger(720, vec![(90, 10)]))
+		));
+
+		assert_eq!(TokenPool::<Runtime>::get(KSM), 910);
+		assert_eq!(
+			SlashRecords::<Runtime>::get(KSM, TimeUnit::Era(8)),
+			vec![SlashRecord { delegator: DELEGATOR, amount: 90, insurance_covered: 0 }]
+		);
+		System::assert_last_event(RuntimeEvent::Slp(Event::DelegatorSlashed {
+			currency_id: KSM,
+			delegator: DELEGATOR,
+			time_unit: TimeUnit::Era(8),
+			amount: 90,
+			insurance_covered: 0,
+		}));
+	});
+}
+
+#[test]
+fn insurance_fund_should_cover_slash_first() {
+	ExtBuilder::default().build().execute_with(|| {
+		slash_setup(substrate_ledger(1_000, vec![]));
+		let fund = Slp::slash_insurance_fund_account();
+		assert_ok!(Tokens::deposit(KSM, &fund, 60));
+
+		assert_ok!(Slp::report_slash(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Box::new(DELEGATOR),
+			Box::new(substrate_ledger(900, vec![]))
+		));
+
+		let (entrance_account, _) =
+			<Runtime as Config>::VtokenMinting::get_entrance_and_exit_accounts();
+		assert_eq!(Tokens::free_balance(KSM, &fund), 0);
+		assert_eq!(Tokens::free_balance(KSM, &entrance_account), 60);
+		assert_eq!(TokenPool::<Runtime>::get(KSM), 960);
+		assert_eq!(
+			SlashRecords::<Runtime>::get(KSM, TimeUnit::Era(8)),
+			vec![SlashRecord { delegator: DELEGATOR, amount: 100, insurance_covered: 60 }]
+		);
+	});
+}
+
+#[test]
+fn slash_records_should_be_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		slash_setup(substrate_ledger(1_000, vec![]));
+		let records: Vec<SlashRecord<u128>> = (0..MaxLengthLimit::get())
+			.map(|_| SlashRecord { delegator: DELEGATOR, amount: 1, insurance_covered: 0 })
+			.collect();
+		SlashRecords::<Runtime>::insert(
+			KSM,
+			TimeUnit::Era(8),
+			BoundedVec::<SlashRecord<u128>, MaxLengthLimit>::try_from(records).unwrap(),
+		);
+
+		assert_noop!(
+			Slp::report_slash(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				Box::new(DELEGATOR),
+				Box::new(substrate_ledger(900, vec![]))
+			),
+			Error::<Runtime>::ExceedMaxLengthLimit
+		);
+	});
+}
@@ -23,8 +23,8 @@ pub use bifrost_primitives::{
 	GLMR, VBNC, VDOT,
 };
 use bifrost_primitives::{
-	BifrostEntranceAccount, BifrostExitAccount, IncentivePoolAccount, MoonbeamChainId,
-	StableAssetPalletId, KSM, KUSD,
+	BifrostEntranceAccount, BifrostExitAccount, IncentivePoolAccount, InstantRedeemBufferPalletId,
+	MoonbeamChainId, StableAssetPalletId, KSM, KUSD,
 };
 use bifrost_runtime_common::milli;
 use frame_support::{
@@ -33,7 +33,12 @@ use frame_support::{
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use orml_traits::{location::RelativeReserveProvider, parameter_type_with_key};
-use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{IdentifyAccount, IdentityLookup, Lazy, Verify},
+	BuildStorage,
+};
 use xcm::{prelude::*, v3::Weight};
 use xcm_builder::{FixedWeightBounds, FrameTransactionalProcessor};
 use xcm_executor::XcmExecutor;
@@ -287,10 +292,36 @@ impl bifrost_vtoken_minting::Config for Test {
 	type BifrostSlpx = SlpxInterface;
 	type ChannelCommission = ();
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<100>;
+	type OffchainSignature = MockSignature;
+	type SigningPublicKey = MockSigner;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = ();
 }
 
+// Permits are not used in these tests, so no signature is valid.
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct MockSignature;
+
+impl Verify for MockSignature {
+	type Signer = MockSigner;
+
+	fn verify<L: Lazy<[u8]>>(&self, _msg: L, _signer: &u128) -> bool {
+		false
+	}
+}
+
+pub struct MockSigner(u128);
+
+impl IdentifyAccount for MockSigner {
+	type AccountId = u128;
+
+	fn into_account(self) -> u128 {
+		self.0
+	}
+}
+
 pub struct Slp;
 // Functions to be called by other pallets.
 impl SlpOperator<CurrencyId> for Slp {
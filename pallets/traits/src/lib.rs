@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use num_bigint::{BigUint, ToBigUint};
+use sp_std::vec::Vec;
 
 pub mod evm;
 pub mod lend_market;
@@ -16,6 +17,21 @@ pub trait EmergencyOraclePriceProvider<CurrencyId, Price> {
 	fn reset_emergency_price(asset_id: CurrencyId);
 }
 
+/// A source of prices aggregated with the oracle by the prices pallet.
+pub trait PriceSource<CurrencyId, Price> {
+	/// Appends the prices the source quotes for `asset_id` to `prices`.
+	fn collect_prices(asset_id: &CurrencyId, prices: &mut Vec<Price>);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(3)]
+impl<CurrencyId, Price> PriceSource<CurrencyId, Price> for Tuple {
+	fn collect_prices(asset_id: &CurrencyId, prices: &mut Vec<Price>) {
+		for_tuples!( #(
+            Tuple::collect_prices(asset_id, prices);
+        )* );
+	}
+}
+
 pub trait ConvertToBigUint {
 	fn get_big_uint(&self) -> BigUint;
 }
@@ -18,13 +18,21 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use bifrost_primitives::{BlockNumber, ExchangeRateSnapshot};
 use parity_scale_codec::Codec;
 use sp_api::decl_runtime_apis;
+use sp_runtime::FixedI128;
+use sp_std::vec::Vec;
 
 decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait VtokenMintingRuntimeApi<CurrencyId, Balance> where CurrencyId: Codec, Balance: Codec
 	{
 		fn get_v_currency_amount_by_currency_amount(currency_id: CurrencyId, v_currency_id: CurrencyId, currency_amount: Balance) -> Balance;
 		fn get_currency_amount_by_v_currency_amount(currency_id: CurrencyId, v_currency_id: CurrencyId, v_currency_amount: Balance) -> Balance;
+		#[api_version(2)]
+		fn get_exchange_rate_history(v_currency_id: CurrencyId) -> Vec<ExchangeRateSnapshot<BlockNumber>>;
+		#[api_version(2)]
+		fn get_annualised_yield(v_currency_id: CurrencyId, window: BlockNumber) -> Option<FixedI128>;
 	}
 }
@@ -18,6 +18,7 @@
 
 use std::{marker::PhantomData, sync::Arc};
 
+use bifrost_primitives::{BlockNumber, ExchangeRateSnapshot};
 pub use bifrost_vtoken_minting_rpc_runtime_api::VtokenMintingRuntimeApi;
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
@@ -25,9 +26,9 @@ use jsonrpsee::{
 	types::error::{ErrorCode, ErrorObject},
 };
 use parity_scale_codec::Codec;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::{traits::Block as BlockT, FixedI128};
 
 #[rpc(client, server)]
 pub trait VtokenMintingRpcApi<CurrencyId, Balance, BlockHash> {
@@ -50,6 +51,23 @@ pub trait VtokenMintingRpcApi<CurrencyId, Balance, BlockHash> {
 		v_currency_amount: Balance,
 		at: Option<BlockHash>,
 	) -> RpcResult<Balance>;
+
+	/// rpc method for getting the exchange rate history of a vtoken
+	#[method(name = "vtoken_minting_get_exchange_rate_history")]
+	fn get_exchange_rate_history(
+		&self,
+		v_currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ExchangeRateSnapshot<BlockNumber>>>;
+
+	/// rpc method for getting the annualised yield of a vtoken over a window of blocks
+	#[method(name = "vtoken_minting_get_annualised_yield")]
+	fn get_annualised_yield(
+		&self,
+		v_currency_id: CurrencyId,
+		window: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<FixedI128>>;
 }
 
 #[derive(Clone, Debug)]
@@ -128,4 +146,73 @@ where
 			)),
 		}
 	}
+
+	fn get_exchange_rate_history(
+		&self,
+		v_currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ExchangeRateSnapshot<BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		// The exchange rate history is only available from version 2 of the runtime api.
+		let api_version = api
+			.api_version::<dyn VtokenMintingRuntimeApi<Block, CurrencyId, Balance>>(at)
+			.map_err(|e| {
+				ErrorObject::owned(
+					ErrorCode::InternalError.code(),
+					"Failed to get runtime api version.",
+					Some(format!("{:?}", e)),
+				)
+			})?;
+		if api_version.map_or(true, |version| version < 2) {
+			return Err(ErrorObject::owned(
+				ErrorCode::MethodNotFound.code(),
+				"VtokenMintingRuntimeApi version 2 required.",
+				None::<()>,
+			));
+		}
+
+		api.get_exchange_rate_history(at, v_currency_id).map_err(|e| {
+			ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get exchange rate history.",
+				Some(format!("{:?}", e)),
+			)
+		})
+	}
+
+	fn get_annualised_yield(
+		&self,
+		v_currency_id: CurrencyId,
+		window: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<FixedI128>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		// The annualised yield is only available from version 2 of the runtime api.
+		let api_version = api
+			.api_version::<dyn VtokenMintingRuntimeApi<Block, CurrencyId, Balance>>(at)
+			.map_err(|e| {
+				ErrorObject::owned(
+					ErrorCode::InternalError.code(),
+					"Failed to get runtime api version.",
+					Some(format!("{:?}", e)),
+				)
+			})?;
+		if api_version.map_or(true, |version| version < 2) {
+			return Err(ErrorObject::owned(
+				ErrorCode::MethodNotFound.code(),
+				"VtokenMintingRuntimeApi version 2 required.",
+				None::<()>,
+			));
+		}
+
+		api.get_annualised_yield(at, v_currency_id, window).map_err(|e| {
+			ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get annualised yield.",
+				Some(format!("{:?}", e)),
+			)
+		})
+	}
 }
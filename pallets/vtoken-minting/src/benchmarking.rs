@@ -21,11 +21,18 @@
 
 use crate::{Pallet as VtokenMinting, *};
 use bifrost_primitives::{CurrencyId, TokenSymbol, VtokenMintingOperator, VKSM};
-use frame_benchmarking::v1::{benchmarks, whitelisted_caller, BenchmarkError};
+use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller, BenchmarkError};
 use frame_support::{assert_ok, sp_runtime::traits::UniqueSaturatedFrom};
 use frame_system::RawOrigin;
+use sp_runtime::{AccountId32, MultiSignature};
 
 benchmarks! {
+	where_clause {
+		where
+			T::AccountId: From<AccountId32>,
+			T::OffchainSignature: From<MultiSignature>,
+	}
+
 	set_minimum_mint {
 		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		let token = CurrencyId::Token(TokenSymbol::KSM);
@@ -141,6 +148,105 @@ benchmarks! {
 		let unlock_id:UnlockId = 0;
 	}: _(RawOrigin::Signed(caller), KSM, unlock_id)
 
+	partial_rebond_by_unlock_id {
+		let caller: T::AccountId = whitelisted_caller();
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let rebond_amount = BalanceOf::<T>::unique_saturated_from(100000000000u128);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000000u128);
+		let mint_amount = BalanceOf::<T>::unique_saturated_from(2000000000000u128);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(5000000000000u128);
+		const FEE: Permill = Permill::from_percent(50);
+		assert_ok!(VtokenMinting::<T>::set_fees(RawOrigin::Root.into(), FEE, FEE));
+		assert_ok!(VtokenMinting::<T>::set_unlock_duration(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::<T>::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		T::MultiCurrency::deposit(KSM, &caller, token_amount)?;
+		T::MultiCurrency::deposit(VKSM, &caller, redeem_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(caller.clone()).into(), KSM, mint_amount,BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::<T>::redeem(RawOrigin::Signed(caller.clone()).into(), VKSM, redeem_amount));
+		assert_ok!(VtokenMinting::<T>::add_support_rebond_token(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM));
+		let unlock_id:UnlockId = 0;
+	}: _(RawOrigin::Signed(caller), KSM, unlock_id, rebond_amount)
+
+	transfer_unlock_receipt {
+		let caller: T::AccountId = whitelisted_caller();
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000000u128);
+		let mint_amount = BalanceOf::<T>::unique_saturated_from(2000000000000u128);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(5000000000000u128);
+		assert_ok!(VtokenMinting::<T>::set_unlock_duration(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::<T>::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		T::MultiCurrency::deposit(KSM, &caller, token_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(caller.clone()).into(), KSM, mint_amount,BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::<T>::redeem(RawOrigin::Signed(caller.clone()).into(), VKSM, redeem_amount));
+		let unlock_id:UnlockId = 0;
+	}: _(RawOrigin::Signed(caller), KSM, unlock_id, receiver)
+
+	schedule_rebond {
+		let caller: T::AccountId = whitelisted_caller();
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let rebond_amount = BalanceOf::<T>::unique_saturated_from(100000000000u128);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000000u128);
+		let mint_amount = BalanceOf::<T>::unique_saturated_from(2000000000000u128);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(5000000000000u128);
+		assert_ok!(VtokenMinting::<T>::set_unlock_duration(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::<T>::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		T::MultiCurrency::deposit(KSM, &caller, token_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(caller.clone()).into(), KSM, mint_amount,BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::<T>::redeem(RawOrigin::Signed(caller.clone()).into(), VKSM, redeem_amount));
+		assert_ok!(VtokenMinting::<T>::add_support_rebond_token(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM));
+		let unlock_id:UnlockId = 0;
+	}: _(RawOrigin::Signed(caller), KSM, unlock_id, rebond_amount, TimeUnit::Era(2))
+
+	cancel_scheduled_rebond {
+		let caller: T::AccountId = whitelisted_caller();
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let rebond_amount = BalanceOf::<T>::unique_saturated_from(100000000000u128);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000000u128);
+		let mint_amount = BalanceOf::<T>::unique_saturated_from(2000000000000u128);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(5000000000000u128);
+		assert_ok!(VtokenMinting::<T>::set_unlock_duration(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::<T>::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		T::MultiCurrency::deposit(KSM, &caller, token_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(caller.clone()).into(), KSM, mint_amount,BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::<T>::redeem(RawOrigin::Signed(caller.clone()).into(), VKSM, redeem_amount));
+		assert_ok!(VtokenMinting::<T>::add_support_rebond_token(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM));
+		let unlock_id:UnlockId = 0;
+		assert_ok!(VtokenMinting::<T>::schedule_rebond(RawOrigin::Signed(caller.clone()).into(), KSM, unlock_id, rebond_amount, TimeUnit::Era(2)));
+	}: _(RawOrigin::Signed(caller), KSM, unlock_id, TimeUnit::Era(2))
+
+	set_currency_fees {
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		const FEE: Permill = Permill::from_percent(5);
+	}: _(RawOrigin::Root, KSM, Some((FEE, FEE)))
+
+	set_redeem_fee_discount {
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let mut config = FeeDiscountConfig { holding_tiers: BoundedVec::default(), bb_bnc_tiers: BoundedVec::default() };
+		for i in 1..=MAX_FEE_DISCOUNT_TIERS {
+			assert_ok!(config.holding_tiers.try_push((BlockNumberFor::<T>::from(i), Permill::from_percent(i))));
+			assert_ok!(config.bb_bnc_tiers.try_push((BalanceOf::<T>::unique_saturated_from(i as u128), Permill::from_percent(i))));
+		}
+	}: _(RawOrigin::Root, KSM, Some(config))
+
+	set_redeem_queue_config {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let config = RedeemQueueConfig {
+			time_unit_cap: BalanceOf::<T>::unique_saturated_from(1000000000000u128),
+			max_rollover: MAX_TIME_UNIT_ROLLOVER,
+			pro_rata: true,
+		};
+		RedeemQueueConfigs::<T>::insert(KSM, RedeemQueueConfig { pro_rata: false, ..config });
+	}: _<T::RuntimeOrigin>(origin, KSM, Some(config))
+	verify {
+		assert_eq!(RedeemQueueConfigs::<T>::get(KSM), Some(config));
+	}
+
 	on_initialize {
 		let block_num =BlockNumberFor::<T>::from(10u32);
 	}:{VtokenMinting::<T>::on_initialize(block_num);}
@@ -222,9 +328,121 @@ benchmarks! {
 		let blocks = Some(BlockNumberFor::<T>::from(1000u32));
 	}: _<T::RuntimeOrigin>(origin, VKSM, blocks)
 
+	set_instant_redeem_config {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let token = CurrencyId::Token(TokenSymbol::KSM);
+		let config = InstantRedeemConfig { min_fee: Permill::from_percent(1), max_fee: Permill::from_percent(10) };
+	}: _<T::RuntimeOrigin>(origin, token, Some(config))
+
+	instant_redeem {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller: T::AccountId = whitelisted_caller();
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000u128);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(10000000000u128);
+		let config = InstantRedeemConfig { min_fee: Permill::from_percent(1), max_fee: Permill::from_percent(10) };
+		assert_ok!(VtokenMinting::<T>::set_instant_redeem_config(origin, KSM, Some(config)));
+		T::MultiCurrency::deposit(KSM, &VtokenMinting::<T>::instant_redeem_buffer_account(), token_amount)?;
+		T::MultiCurrency::deposit(KSM, &caller, token_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(caller.clone()).into(), KSM, token_amount,BoundedVec::default(), None));
+	}: _(RawOrigin::Signed(caller.clone()), VKSM, redeem_amount)
+
+	unlock_instant_redeem_debt {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller: T::AccountId = whitelisted_caller();
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000u128);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(10000000000u128);
+		let config = InstantRedeemConfig { min_fee: Permill::from_percent(1), max_fee: Permill::from_percent(10) };
+		assert_ok!(VtokenMinting::<T>::set_instant_redeem_config(origin.clone(), KSM, Some(config)));
+		assert_ok!(VtokenMinting::<T>::set_unlock_duration(origin.clone(), KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::<T>::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		T::MultiCurrency::deposit(KSM, &VtokenMinting::<T>::instant_redeem_buffer_account(), token_amount)?;
+		T::MultiCurrency::deposit(KSM, &caller, token_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(caller.clone()).into(), KSM, token_amount,BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::<T>::instant_redeem(RawOrigin::Signed(caller).into(), VKSM, redeem_amount));
+	}: _<T::RuntimeOrigin>(origin, KSM, redeem_amount)
+
+	mint_with_permit {
+		let relayer: T::AccountId = whitelisted_caller();
+		let public = sp_io::crypto::sr25519_generate(0.into(), None);
+		let owner: T::AccountId = AccountId32::from(public).into();
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(10000000000u128);
+		T::MultiCurrency::deposit(KSM, &owner, token_amount)?;
+		let permit = Permit {
+			owner,
+			action: PermitAction::Mint,
+			currency_id: KSM,
+			max_amount: token_amount,
+			deadline: BlockNumberFor::<T>::from(100u32),
+			nonce: 0,
+			remark: BoundedVec::default(),
+			channel_id: None,
+		};
+		let payload = VtokenMinting::<T>::permit_payload(&permit);
+		let signature: T::OffchainSignature = MultiSignature::Sr25519(
+			sp_io::crypto::sr25519_sign(0.into(), &public, &payload).ok_or(BenchmarkError::Weightless)?
+		).into();
+	}: _(RawOrigin::Signed(relayer), permit, signature, token_amount)
+
+	redeem_with_permit {
+		let relayer: T::AccountId = whitelisted_caller();
+		let public = sp_io::crypto::sr25519_generate(0.into(), None);
+		let owner: T::AccountId = AccountId32::from(public).into();
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000u128);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(10000000000u128);
+		assert_ok!(VtokenMinting::<T>::set_unlock_duration(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::<T>::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		T::MultiCurrency::deposit(KSM, &owner, token_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(owner.clone()).into(), KSM, token_amount, BoundedVec::default(), None));
+		let permit = Permit {
+			owner,
+			action: PermitAction::Redeem,
+			currency_id: VKSM,
+			max_amount: redeem_amount,
+			deadline: BlockNumberFor::<T>::from(100u32),
+			nonce: 0,
+			remark: BoundedVec::default(),
+			channel_id: None,
+		};
+		let payload = VtokenMinting::<T>::permit_payload(&permit);
+		let signature: T::OffchainSignature = MultiSignature::Sr25519(
+			sp_io::crypto::sr25519_sign(0.into(), &public, &payload).ok_or(BenchmarkError::Weightless)?
+		).into();
+	}: _(RawOrigin::Signed(relayer), permit, signature, redeem_amount)
+
+	accept_unlock_receipt {
+		let caller: T::AccountId = whitelisted_caller();
+		let holder: T::AccountId = account("holder", 0, 0);
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000000u128);
+		let mint_amount = BalanceOf::<T>::unique_saturated_from(2000000000000u128);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(5000000000000u128);
+		assert_ok!(VtokenMinting::<T>::set_unlock_duration(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::<T>::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		T::MultiCurrency::deposit(KSM, &holder, token_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(holder.clone()).into(), KSM, mint_amount,BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::<T>::redeem(RawOrigin::Signed(holder.clone()).into(), VKSM, redeem_amount));
+		let unlock_id:UnlockId = 0;
+		assert_ok!(VtokenMinting::<T>::transfer_unlock_receipt(RawOrigin::Signed(holder).into(), KSM, unlock_id, caller.clone()));
+	}: _(RawOrigin::Signed(caller), KSM, unlock_id)
+
 	impl_benchmark_test_suite!(
 	VtokenMinting,
-	crate::mock::ExtBuilder::default().one_hundred_for_alice_n_bob().build(),
+	{
+		// The permit benchmarks sign with a key generated in the keystore.
+		let mut ext = crate::mock::ExtBuilder::default().one_hundred_for_alice_n_bob().build();
+		ext.register_extension(sp_keystore::KeystoreExt::new(
+			sp_keystore::testing::MemoryKeystore::new(),
+		));
+		ext
+	},
 	crate::mock::Runtime,
 );
 }
@@ -20,25 +20,31 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use crate::{
-	AccountIdOf, BalanceOf, Config, CurrencyIdOf, Error, Event, Fees, HookIterationLimit,
-	MinTimeUnit, MinimumMint, MinimumRedeem, MintWithLockBlocks, OnRedeemSuccess, OngoingTimeUnit,
-	Pallet, RedeemTo, TimeUnitUnlockLedger, TokenPool, TokenUnlockLedger, TokenUnlockNextId,
-	UnlockDuration, UnlockId, UnlockingTotal, UserUnlockLedger, VtokenIncentiveCoef,
-	VtokenLockLedger, WeightInfo,
+	AccountIdOf, BalanceOf, Config, CurrencyFees, CurrencyIdOf, Error, Event, ExchangeRateHistory,
+	ExchangeRateHistoryCursor, Fees, HookIterationLimit, InstantRedeemConfig, InstantRedeemConfigs,
+	InstantRedeemDebt, MinTimeUnit, MinimumMint, MinimumRedeem, MintWithLockBlocks,
+	OnRedeemSuccess, OngoingTimeUnit, Pallet, Permit, PermitAction, PermitNonces,
+	RedeemFeeDiscounts, RedeemQueueConfigs, RedeemTo, ScheduledRebondTimeUnit, ScheduledRebonds,
+	TimeUnitUnlockLedger, TokenPool, TokenToRebond, TokenUnlockLedger, TokenUnlockNextId,
+	UnlockDuration, UnlockId, UnlockReceiptOffers, UnlockingTotal, UserUnlockLedger,
+	VtokenHoldingSince, VtokenIncentiveCoef, VtokenLockLedger, WeightInfo, PERMIT_DOMAIN,
 };
 use bb_bnc::traits::BbBNCInterface;
 use bifrost_primitives::{
-	currency::BNC, AstarChainId, CurrencyId, CurrencyIdExt, HydrationChainId, InterlayChainId,
-	MantaChainId, RedeemType, SlpxOperator, TimeUnit, VTokenMintRedeemProvider,
+	currency::BNC, AstarChainId, CurrencyId, CurrencyIdExt, ExchangeRateSnapshot, HydrationChainId,
+	InterlayChainId, MantaChainId, RedeemType, SlpxOperator, TimeUnit, VTokenMintRedeemProvider,
 	VTokenSupplyProvider, VtokenMintingInterface, VtokenMintingOperator, FIL,
 };
 use frame_support::{
 	pallet_prelude::{DispatchResultWithPostInfo, *},
 	sp_runtime::{
-		traits::{AccountIdConversion, CheckedAdd, CheckedSub, UniqueSaturatedInto, Zero},
-		DispatchError, FixedU128, Permill, SaturatedConversion,
+		traits::{
+			AccountIdConversion, CheckedAdd, CheckedMul, CheckedSub, One, Saturating,
+			UniqueSaturatedInto, Verify, Zero,
+		},
+		DispatchError, FixedI128, FixedPointNumber, FixedU128, Permill, SaturatedConversion,
 	},
-	traits::LockIdentifier,
+	traits::{tokens::nonfungibles, LockIdentifier},
 	transactional, BoundedVec,
 };
 use frame_system::pallet_prelude::*;
@@ -146,6 +152,7 @@ impl<T: Config> Pallet<T> {
 
 				if currency_amount >= total_locked_amount {
 					*value = None;
+					UnlockReceiptOffers::<T>::remove(currency_id, unlock_id);
 					Ok(true)
 				} else {
 					*total_locked_amount = total_locked_amount
@@ -329,7 +336,7 @@ impl<T: Config> Pallet<T> {
 		currency_id: CurrencyId,
 		currency_amount: BalanceOf<T>,
 	) -> Result<(BalanceOf<T>, BalanceOf<T>, BalanceOf<T>), DispatchError> {
-		let (mint_rate, _) = Fees::<T>::get();
+		let (mint_rate, _) = Self::fees_of(currency_id);
 		let mint_fee = mint_rate.mul_floor(currency_amount);
 		// Charging fees
 		T::MultiCurrency::transfer(currency_id, minter, &T::FeeAccount::get(), mint_fee)?;
@@ -343,6 +350,7 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		// Issue the corresponding v_currency to the user's account.
+		Self::update_vtoken_holding_since(minter, v_currency_id, v_currency_amount);
 		T::MultiCurrency::deposit(v_currency_id, minter, v_currency_amount)?;
 		// Increase the token pool amount.
 		Self::update_token_pool(&currency_id, &currency_amount, Operation::Add)?;
@@ -540,9 +548,21 @@ impl<T: Config> Pallet<T> {
 	#[transactional]
 	pub fn handle_ledger_by_currency(currency: CurrencyId) -> DispatchResult {
 		let time_unit = MinTimeUnit::<T>::get(currency);
-		if let Some((_total_locked, ledger_list, currency_id)) =
+		if let Some((total_locked, ledger_list, currency_id)) =
 			TimeUnitUnlockLedger::<T>::get(&time_unit, currency)
 		{
+			// When the entrance account can not pay all the unlocks of the time unit, each unlock
+			// is paid its share of the entrance account balance.
+			let entrance_account_balance = T::MultiCurrency::free_balance(
+				currency_id,
+				&T::EntranceAccount::get().into_account_truncating(),
+			);
+			let pro_rata_balance = RedeemQueueConfigs::<T>::get(currency)
+				.filter(|config| config.pro_rata && entrance_account_balance < total_locked)
+				.map(|_| entrance_account_balance);
+			let ed = T::MultiCurrency::minimum_balance(currency_id);
+
+			let mut processed_ids = Vec::new();
 			for unlock_id in ledger_list.iter().take(HookIterationLimit::<T>::get() as usize) {
 				if let Some((account, unlock_amount, time_unit, redeem_type)) =
 					TokenUnlockLedger::<T>::get(currency_id, unlock_id)
@@ -554,18 +574,48 @@ impl<T: Config> Pallet<T> {
 					if entrance_account_balance == BalanceOf::<T>::zero() {
 						break;
 					}
+					processed_ids.push(*unlock_id);
+
+					let redeem_amount = match pro_rata_balance {
+						Some(pro_rata_balance) => multiply_by_rational_with_rounding(
+							unlock_amount.saturated_into::<u128>(),
+							pro_rata_balance.saturated_into::<u128>(),
+							total_locked.saturated_into::<u128>(),
+							Rounding::Down,
+						)
+						.ok_or(Error::<T>::CalculationOverflow)?
+						.unique_saturated_into(),
+						None => unlock_amount,
+					};
+					if pro_rata_balance.is_some() && (redeem_amount.is_zero() || redeem_amount < ed)
+					{
+						continue;
+					}
 
 					Self::process_redeem(
 						currency_id,
 						account,
 						unlock_id,
-						unlock_amount,
+						redeem_amount,
 						entrance_account_balance,
 						time_unit,
 						redeem_type,
 					)?;
 				}
 			}
+
+			// Move the unlocks paid pro rata to the back of the time unit, so that the next
+			// block pays the others first.
+			if pro_rata_balance.is_some() {
+				TimeUnitUnlockLedger::<T>::mutate(&time_unit, currency, |ledger| {
+					if let Some((_, unlock_ids, _)) = ledger {
+						let (processed, rest): (Vec<UnlockId>, Vec<UnlockId>) =
+							unlock_ids.iter().copied().partition(|id| processed_ids.contains(id));
+						*unlock_ids =
+							BoundedVec::truncate_from(rest.into_iter().chain(processed).collect());
+					}
+				});
+			}
 		} else {
 			MinTimeUnit::<T>::mutate(currency, |time_unit| -> Result<(), Error<T>> {
 				let unlock_duration =
@@ -584,6 +634,112 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Rebond an unlock_id, or part of it.
+	/// Parameters:
+	/// - `rebonder`: The rebonder account id, the owner of the unlock_id.
+	/// - `currency_id`: The currency id.
+	/// - `unlock_id`: The unlock id.
+	/// - `currency_amount`: The currency amount to rebond, `None` to rebond the whole unlock_id.
+	#[transactional]
+	pub fn do_rebond_by_unlock_id(
+		rebonder: &AccountIdOf<T>,
+		currency_id: CurrencyId,
+		unlock_id: UnlockId,
+		currency_amount: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		let v_currency_id = currency_id.to_vtoken().map_err(|_| Error::<T>::NotSupportTokenType)?;
+
+		let (who, unlock_amount, time_unit, _) =
+			TokenUnlockLedger::<T>::get(currency_id, unlock_id)
+				.ok_or(Error::<T>::TokenUnlockLedgerNotFound)?;
+		ensure!(who == *rebonder, Error::<T>::CanNotRebond);
+		let rebond_amount = currency_amount.unwrap_or(unlock_amount);
+		ensure!(unlock_amount >= rebond_amount, Error::<T>::NotEnoughBalanceToUnlock);
+
+		Self::update_unlock_ledger(
+			rebonder,
+			&currency_id,
+			&rebond_amount,
+			&unlock_id,
+			&time_unit,
+			None,
+			Operation::Sub,
+		)?;
+
+		let (currency_amount, v_currency_amount, fee) =
+			Self::mint_without_transfer(rebonder, v_currency_id, currency_id, rebond_amount)?;
+
+		Self::add_token_to_rebond(currency_id, currency_amount)?;
+
+		Self::deposit_event(Event::RebondedByUnlockId {
+			rebonder: rebonder.clone(),
+			currency_id,
+			currency_amount: rebond_amount,
+			v_currency_amount,
+			fee,
+			unlock_id,
+		});
+		Ok(())
+	}
+
+	/// Execute the rebonds scheduled for the earliest time unit not after the ongoing time unit
+	/// of a currency, up to the hook iteration limit. Once all of its rebonds are executed, the
+	/// next time unit is handled from the next block on. A scheduled rebond is dropped if its
+	/// unlock_id has been paid out or transferred in the meantime.
+	pub fn handle_scheduled_rebonds(currency: CurrencyId) -> Weight {
+		let db_weight = T::DbWeight::get();
+		let mut weight = db_weight.reads(2);
+		let (Some(ongoing_time_unit), Some(time_unit)) =
+			(OngoingTimeUnit::<T>::get(currency), ScheduledRebondTimeUnit::<T>::get(currency))
+		else {
+			return weight;
+		};
+		if time_unit > ongoing_time_unit {
+			return weight;
+		}
+
+		weight.saturating_accrue(db_weight.reads_writes(1, 1));
+		ScheduledRebonds::<T>::mutate_exists(&time_unit, currency, |maybe_schedules| {
+			let Some(schedules) = maybe_schedules else {
+				return;
+			};
+			let count = schedules.len().min(HookIterationLimit::<T>::get() as usize);
+			for (rebonder, unlock_id, currency_amount) in schedules.drain(..count) {
+				weight.saturating_accrue(db_weight.reads(1));
+				let Some((who, unlock_amount, _, _)) =
+					TokenUnlockLedger::<T>::get(currency, unlock_id)
+				else {
+					continue;
+				};
+				if who != rebonder {
+					continue;
+				}
+				weight.saturating_accrue(T::WeightInfo::partial_rebond_by_unlock_id());
+				if let Err(err) = Self::do_rebond_by_unlock_id(
+					&rebonder,
+					currency,
+					unlock_id,
+					Some(currency_amount.min(unlock_amount)),
+				) {
+					Self::deposit_event(Event::ScheduledRebondFailed {
+						currency_id: currency,
+						unlock_id,
+						err,
+					});
+				}
+			}
+			if schedules.is_empty() {
+				*maybe_schedules = None;
+			}
+		});
+
+		if !ScheduledRebonds::<T>::contains_key(&time_unit, currency) {
+			weight.saturating_accrue(db_weight.writes(1));
+			ScheduledRebondTimeUnit::<T>::insert(currency, time_unit.add_one());
+		}
+		weight
+	}
+
 	pub fn do_mint(
 		minter: AccountIdOf<T>,
 		currency_id: CurrencyIdOf<T>,
@@ -600,12 +756,19 @@ impl<T: Config> Pallet<T> {
 		let (currency_amount_excluding_fee, v_currency_amount, mint_fee) =
 			Self::mint_without_transfer(&minter, v_currency_id, currency_id, currency_amount)?;
 
+		// Repay the instant redeem buffer first, the tokens it paid out are still staked.
+		let buffer_refill_amount = Self::refill_instant_redeem_buffer(
+			&minter,
+			currency_id,
+			currency_amount_excluding_fee,
+		)?;
+
 		// Transfer the user's token to EntranceAccount.
 		T::MultiCurrency::transfer(
 			currency_id,
 			&minter,
 			&T::EntranceAccount::get().into_account_truncating(),
-			currency_amount_excluding_fee,
+			currency_amount_excluding_fee.saturating_sub(buffer_refill_amount),
 		)?;
 
 		// record the minting information for ChannelCommission module
@@ -636,7 +799,8 @@ impl<T: Config> Pallet<T> {
 		);
 
 		// Charging fees
-		let (_, redeem_rate) = Fees::<T>::get();
+		let redeem_rate =
+			Self::redeem_fee_rate(&redeemer, currency_id, v_currency_id, v_currency_amount);
 		let redeem_fee = redeem_rate.mul_floor(v_currency_amount);
 		T::MultiCurrency::transfer(
 			v_currency_id,
@@ -655,9 +819,6 @@ impl<T: Config> Pallet<T> {
 			v_currency_amount,
 		)?;
 
-		// Withdraw the token from redeemer
-		T::MultiCurrency::withdraw(v_currency_id, &redeemer, v_currency_amount)?;
-
 		// Calculate the time to be locked
 		let ongoing_time_unit =
 			OngoingTimeUnit::<T>::get(currency_id).ok_or(Error::<T>::OngoingTimeUnitNotSet)?;
@@ -667,48 +828,683 @@ impl<T: Config> Pallet<T> {
 			.add(unlock_duration)
 			.ok_or(Error::<T>::UnlockDurationNotFound)?;
 
+		let unlocks =
+			Self::split_redeem_by_time_unit_cap(currency_id, currency_amount, lock_to_time_unit);
+
+		// Withdraw the token from redeemer
+		T::MultiCurrency::withdraw(v_currency_id, &redeemer, v_currency_amount)?;
+		Self::reduce_vtoken_holding(
+			&redeemer,
+			v_currency_id,
+			v_currency_amount.saturating_add(redeem_fee),
+		);
+
 		// Decrease the token pool amount
 		Self::update_token_pool(&currency_id, &currency_amount, Operation::Sub)?;
 
 		TokenUnlockNextId::<T>::mutate(&currency_id, |next_id| -> DispatchResultWithPostInfo {
+			for (index, (time_unit, unlock_amount)) in unlocks.into_iter().enumerate() {
+				Self::update_unlock_ledger(
+					&redeemer,
+					&currency_id,
+					&unlock_amount,
+					&next_id,
+					&time_unit,
+					Some(redeem_type.clone()),
+					Operation::Add,
+				)?;
+
+				if index == 0 {
+					Self::deposit_event(Event::Redeemed {
+						redeemer: redeemer.clone(),
+						currency_id,
+						v_currency_amount,
+						currency_amount,
+						redeem_fee,
+						unlock_id: *next_id,
+					});
+				} else {
+					Self::deposit_event(Event::RedeemRolledOver {
+						redeemer: redeemer.clone(),
+						currency_id,
+						currency_amount: unlock_amount,
+						unlock_id: *next_id,
+						time_unit,
+					});
+				}
+
+				// Increase the next unlock id
+				*next_id = next_id.checked_add(1).ok_or(Error::<T>::CalculationOverflow)?;
+			}
+
+			T::ChannelCommission::record_redeem_amount(v_currency_id, v_currency_amount)?;
+			let extra_weight = T::OnRedeemSuccess::on_redeemed(
+				redeemer,
+				currency_id,
+				currency_amount,
+				v_currency_amount,
+				redeem_fee,
+			);
+			Ok(Some(T::WeightInfo::redeem() + extra_weight).into())
+		})
+	}
+
+	/// Split a redeem over the time units from `time_unit` on, so that the currency amount
+	/// unlocking at each time unit stays within the time unit cap of the currency. What is left
+	/// after the last time unit the redeem can roll over to is queued at that time unit, so that
+	/// redeems are never refused because the following time units are full.
+	/// Parameters:
+	/// - `currency_id`: The currency id.
+	/// - `currency_amount`: The currency amount to redeem.
+	/// - `time_unit`: The time unit the redeem unlocks at without a cap.
+	/// Returns:
+	/// - `Vec<(TimeUnit, BalanceOf<T>)>`: The time units and the currency amounts unlocking at
+	///   them.
+	fn split_redeem_by_time_unit_cap(
+		currency_id: CurrencyId,
+		currency_amount: BalanceOf<T>,
+		mut time_unit: TimeUnit,
+	) -> Vec<(TimeUnit, BalanceOf<T>)> {
+		let Some(config) = RedeemQueueConfigs::<T>::get(currency_id) else {
+			return vec![(time_unit, currency_amount)];
+		};
+		if currency_amount.is_zero() {
+			return vec![(time_unit, currency_amount)];
+		}
+
+		let mut unlocks = Vec::new();
+		let mut remaining = currency_amount;
+		for rollover in 0..=config.max_rollover {
+			let unlock_amount = if rollover == config.max_rollover {
+				remaining
+			} else {
+				let unlocking = TimeUnitUnlockLedger::<T>::get(&time_unit, currency_id)
+					.map_or_else(Zero::zero, |(total_locked, _, _)| total_locked);
+				remaining.min(config.time_unit_cap.saturating_sub(unlocking))
+			};
+			if !unlock_amount.is_zero() {
+				unlocks.push((time_unit.clone(), unlock_amount));
+				remaining = remaining.saturating_sub(unlock_amount);
+			}
+			if remaining.is_zero() {
+				break;
+			}
+			time_unit = time_unit.add_one();
+		}
+		unlocks
+	}
+
+	pub fn do_instant_redeem(
+		redeemer: AccountIdOf<T>,
+		v_currency_id: CurrencyIdOf<T>,
+		v_currency_amount: BalanceOf<T>,
+	) -> DispatchResultWithPostInfo {
+		let currency_id = v_currency_id.to_token().map_err(|_| Error::<T>::NotSupportTokenType)?;
+		let config = InstantRedeemConfigs::<T>::get(currency_id)
+			.ok_or(Error::<T>::InstantRedeemNotSupported)?;
+		ensure!(
+			v_currency_amount >= MinimumRedeem::<T>::get(v_currency_id),
+			Error::<T>::BelowMinimumRedeem
+		);
+
+		// Charging fees
+		let redeem_rate =
+			Self::redeem_fee_rate(&redeemer, currency_id, v_currency_id, v_currency_amount);
+		let redeem_fee = redeem_rate.mul_floor(v_currency_amount);
+		T::MultiCurrency::transfer(
+			v_currency_id,
+			&redeemer,
+			&T::RedeemFeeAccount::get(),
+			redeem_fee,
+		)?;
+
+		// Calculate the currency amount by v_currency_amount
+		let v_currency_amount = v_currency_amount
+			.checked_sub(&redeem_fee)
+			.ok_or(Error::<T>::CalculationOverflow)?;
+		let currency_amount = Self::get_currency_amount_by_v_currency_amount(
+			currency_id,
+			v_currency_id,
+			v_currency_amount,
+		)?;
+
+		let buffer_account = Self::instant_redeem_buffer_account();
+		let buffer_balance = T::MultiCurrency::free_balance(currency_id, &buffer_account);
+		ensure!(buffer_balance >= currency_amount, Error::<T>::NotEnoughBufferLiquidity);
+		let instant_redeem_fee =
+			Self::instant_redeem_fee_rate(&config, currency_id, currency_amount, buffer_balance)
+				.mul_floor(currency_amount);
+
+		// Withdraw the token from redeemer
+		T::MultiCurrency::withdraw(v_currency_id, &redeemer, v_currency_amount)?;
+		Self::reduce_vtoken_holding(
+			&redeemer,
+			v_currency_id,
+			v_currency_amount.saturating_add(redeem_fee),
+		);
+
+		// Decrease the token pool amount, the buffer is owed the tokens which are still staked.
+		Self::update_token_pool(&currency_id, &currency_amount, Operation::Sub)?;
+		InstantRedeemDebt::<T>::try_mutate(currency_id, |debt| -> DispatchResult {
+			*debt = debt.checked_add(&currency_amount).ok_or(Error::<T>::CalculationOverflow)?;
+			Ok(())
+		})?;
+
+		// The instant redeem fee stays in the buffer.
+		let payout_amount = currency_amount
+			.checked_sub(&instant_redeem_fee)
+			.ok_or(Error::<T>::CalculationOverflow)?;
+		T::MultiCurrency::transfer(currency_id, &buffer_account, &redeemer, payout_amount)?;
+
+		T::ChannelCommission::record_redeem_amount(v_currency_id, v_currency_amount)?;
+		let extra_weight = T::OnRedeemSuccess::on_redeemed(
+			redeemer.clone(),
+			currency_id,
+			currency_amount,
+			v_currency_amount,
+			redeem_fee,
+		)
+		.saturating_add(T::OnRedeemSuccess::on_redeem_success(
+			currency_id,
+			redeemer.clone(),
+			payout_amount,
+		));
+
+		Self::deposit_event(Event::InstantRedeemed {
+			redeemer,
+			currency_id,
+			currency_amount,
+			v_currency_amount,
+			redeem_fee,
+			instant_redeem_fee,
+		});
+		Ok(Some(T::WeightInfo::instant_redeem() + extra_weight).into())
+	}
+
+	/// The instant redeem fee rate, interpolated by the share of the buffer that is lent out
+	/// after redeeming `currency_amount`.
+	/// Parameters:
+	/// - `config`: The instant redeem config.
+	/// - `currency_id`: The currency id.
+	/// - `currency_amount`: The currency amount to redeem.
+	/// - `buffer_balance`: The buffer balance before redeeming.
+	pub fn instant_redeem_fee_rate(
+		config: &InstantRedeemConfig,
+		currency_id: CurrencyId,
+		currency_amount: BalanceOf<T>,
+		buffer_balance: BalanceOf<T>,
+	) -> Permill {
+		let debt = InstantRedeemDebt::<T>::get(currency_id);
+		let utilization = Permill::from_rational(
+			debt.saturating_add(currency_amount),
+			debt.saturating_add(buffer_balance),
+		);
+		config
+			.min_fee
+			.saturating_add(config.max_fee.saturating_sub(config.min_fee) * utilization)
+	}
+
+	/// Transfer up to `currency_amount` from a minter to the buffer to repay the instant redeem
+	/// debt.
+	/// Returns:
+	/// - `BalanceOf<T>`: The currency amount repaid.
+	fn refill_instant_redeem_buffer(
+		minter: &AccountIdOf<T>,
+		currency_id: CurrencyId,
+		currency_amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let refill_amount = InstantRedeemDebt::<T>::mutate(currency_id, |debt| {
+			let refill_amount = currency_amount.min(*debt);
+			*debt = debt.saturating_sub(refill_amount);
+			refill_amount
+		});
+
+		if !refill_amount.is_zero() {
+			T::MultiCurrency::transfer(
+				currency_id,
+				minter,
+				&Self::instant_redeem_buffer_account(),
+				refill_amount,
+			)?;
+			Self::deposit_event(Event::InstantRedeemBufferRefilled {
+				currency_id,
+				currency_amount: refill_amount,
+			});
+		}
+		Ok(refill_amount)
+	}
+
+	/// Add the currency amount rebonded to `TokenToRebond`. While the instant redeem buffer is
+	/// owed tokens that are still staked, the rebonded tokens are unlocked to the buffer instead.
+	/// Parameters:
+	/// - `currency_id`: The currency id.
+	/// - `currency_amount`: The currency amount rebonded.
+	pub(crate) fn add_token_to_rebond(
+		currency_id: CurrencyId,
+		currency_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let refill_amount = currency_amount.min(InstantRedeemDebt::<T>::get(currency_id));
+		TokenToRebond::<T>::mutate(&currency_id, |maybe_value| -> Result<(), Error<T>> {
+			match maybe_value {
+				Some(rebonded_amount) => {
+					*rebonded_amount = rebonded_amount
+						.checked_add(&currency_amount.saturating_sub(refill_amount))
+						.ok_or(Error::<T>::CalculationOverflow)?;
+					Ok(())
+				},
+				None => Err(Error::<T>::InvalidRebondToken),
+			}
+		})?;
+
+		if !refill_amount.is_zero() {
+			Self::do_unlock_instant_redeem_debt(currency_id, refill_amount)?;
+		}
+		Ok(())
+	}
+
+	/// Unlock part of the instant redeem debt to the buffer through the normal unlock flow.
+	/// Parameters:
+	/// - `currency_id`: The currency id.
+	/// - `currency_amount`: The currency amount to unlock.
+	pub fn do_unlock_instant_redeem_debt(
+		currency_id: CurrencyId,
+		currency_amount: BalanceOf<T>,
+	) -> DispatchResult {
+		InstantRedeemDebt::<T>::try_mutate(currency_id, |debt| -> DispatchResult {
+			*debt =
+				debt.checked_sub(&currency_amount).ok_or(Error::<T>::ExceedInstantRedeemDebt)?;
+			Ok(())
+		})?;
+
+		// Calculate the time to be locked
+		let ongoing_time_unit =
+			OngoingTimeUnit::<T>::get(currency_id).ok_or(Error::<T>::OngoingTimeUnitNotSet)?;
+		let unlock_duration =
+			UnlockDuration::<T>::get(currency_id).ok_or(Error::<T>::UnlockDurationNotFound)?;
+		let lock_to_time_unit = ongoing_time_unit
+			.add(unlock_duration)
+			.ok_or(Error::<T>::UnlockDurationNotFound)?;
+
+		TokenUnlockNextId::<T>::mutate(&currency_id, |next_id| -> DispatchResult {
 			Self::update_unlock_ledger(
-				&redeemer,
+				&Self::instant_redeem_buffer_account(),
 				&currency_id,
 				&currency_amount,
 				&next_id,
 				&lock_to_time_unit,
-				Some(redeem_type),
+				Some(RedeemType::Native),
 				Operation::Add,
 			)?;
 
-			Self::deposit_event(Event::Redeemed {
-				redeemer: redeemer.clone(),
+			Self::deposit_event(Event::InstantRedeemDebtUnlocked {
 				currency_id,
-				v_currency_amount,
 				currency_amount,
-				redeem_fee,
 				unlock_id: *next_id,
 			});
 
 			// Increase the next unlock id
 			*next_id = next_id.checked_add(1).ok_or(Error::<T>::CalculationOverflow)?;
-
-			T::ChannelCommission::record_redeem_amount(v_currency_id, v_currency_amount)?;
-			let extra_weight = T::OnRedeemSuccess::on_redeemed(
-				redeemer,
-				currency_id,
-				currency_amount,
-				v_currency_amount,
-				redeem_fee,
-			);
-			Ok(Some(T::WeightInfo::redeem() + extra_weight).into())
+			Ok(())
 		})
 	}
 
+	/// Offer the receipt of an unlock record to another account, which has to accept it before
+	/// the receipt is moved into its user unlock ledger.
+	/// Parameters:
+	/// - `holder`: The current holder of the receipt.
+	/// - `currency_id`: The currency id of the unlock record.
+	/// - `unlock_id`: The unlock id.
+	/// - `dest`: The account that can accept the receipt.
+	pub fn do_offer_unlock_receipt(
+		holder: &AccountIdOf<T>,
+		currency_id: CurrencyId,
+		unlock_id: UnlockId,
+		dest: &AccountIdOf<T>,
+	) -> DispatchResult {
+		let (account, _, _, redeem_type) = TokenUnlockLedger::<T>::get(currency_id, unlock_id)
+			.ok_or(Error::<T>::TokenUnlockLedgerNotFound)?;
+		ensure!(account == *holder, Error::<T>::NotUnlockReceiptHolder);
+		// The receiver of a cross-chain redeem is fixed when redeeming.
+		ensure!(redeem_type == RedeemType::Native, Error::<T>::UnlockReceiptNotTransferable);
+
+		UnlockReceiptOffers::<T>::insert(currency_id, unlock_id, (holder.clone(), dest.clone()));
+
+		Self::deposit_event(Event::UnlockReceiptOffered {
+			from: holder.clone(),
+			to: dest.clone(),
+			currency_id,
+			unlock_id,
+		});
+		Ok(())
+	}
+
+	/// Move the receipt of an unlock record, together with its user unlock ledger entry, to
+	/// another account. A pending offer of the receipt is dropped.
+	/// Parameters:
+	/// - `holder`: The current holder of the receipt.
+	/// - `currency_id`: The currency id of the unlock record.
+	/// - `unlock_id`: The unlock id.
+	/// - `dest`: The new holder of the receipt.
+	#[transactional]
+	pub fn do_transfer_unlock_receipt(
+		holder: &AccountIdOf<T>,
+		currency_id: CurrencyId,
+		unlock_id: UnlockId,
+		dest: &AccountIdOf<T>,
+	) -> DispatchResult {
+		let currency_amount = TokenUnlockLedger::<T>::try_mutate(
+			currency_id,
+			unlock_id,
+			|maybe_ledger| -> Result<BalanceOf<T>, DispatchError> {
+				let (account, currency_amount, _, redeem_type) =
+					maybe_ledger.as_mut().ok_or(Error::<T>::TokenUnlockLedgerNotFound)?;
+				ensure!(account == holder, Error::<T>::NotUnlockReceiptHolder);
+				// The receiver of a cross-chain redeem is fixed when redeeming.
+				ensure!(
+					*redeem_type == RedeemType::Native,
+					Error::<T>::UnlockReceiptNotTransferable
+				);
+				*account = dest.clone();
+				Ok(*currency_amount)
+			},
+		)?;
+
+		if holder != dest {
+			Self::update_user_unlock_ledger(
+				holder,
+				&currency_id,
+				&currency_amount,
+				&unlock_id,
+				Operation::Sub,
+				true,
+			)?;
+			Self::update_user_unlock_ledger(
+				dest,
+				&currency_id,
+				&currency_amount,
+				&unlock_id,
+				Operation::Add,
+				false,
+			)?;
+		}
+		UnlockReceiptOffers::<T>::remove(currency_id, unlock_id);
+
+		Self::deposit_event(Event::UnlockReceiptTransferred {
+			from: holder.clone(),
+			to: dest.clone(),
+			currency_id,
+			unlock_id,
+			currency_amount,
+		});
+		Ok(())
+	}
+
+	/// The payload signed by the owner of a permit. It is bound to the genesis hash so that a
+	/// permit can not be replayed on another chain.
+	pub fn permit_payload(
+		permit: &Permit<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+	) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		(PERMIT_DOMAIN, genesis_hash, permit).encode()
+	}
+
+	/// Check a permit for the operation and amount, and consume its nonce.
+	/// Parameters:
+	/// - `relayer`: The account submitting the permit.
+	/// - `permit`: The permit signed by its owner.
+	/// - `signature`: The owner's signature of the permit payload.
+	/// - `action`: The operation to do.
+	/// - `amount`: The amount to mint or redeem.
+	pub fn use_permit(
+		relayer: AccountIdOf<T>,
+		permit: &Permit<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+		signature: &T::OffchainSignature,
+		action: PermitAction,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(permit.action == action, Error::<T>::PermitMismatch);
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= permit.deadline,
+			Error::<T>::PermitExpired
+		);
+		ensure!(amount <= permit.max_amount, Error::<T>::ExceedPermitAmount);
+		let nonce = PermitNonces::<T>::get(&permit.owner);
+		ensure!(permit.nonce == nonce, Error::<T>::InvalidPermitNonce);
+		ensure!(
+			signature.verify(&Self::permit_payload(permit)[..], &permit.owner),
+			Error::<T>::InvalidPermitSignature
+		);
+
+		PermitNonces::<T>::insert(&permit.owner, nonce.saturating_add(1));
+
+		Self::deposit_event(Event::PermitUsed {
+			owner: permit.owner.clone(),
+			relayer,
+			action,
+			currency_id: permit.currency_id,
+			amount,
+			nonce,
+		});
+		Ok(())
+	}
+
 	pub fn incentive_pool_account() -> AccountIdOf<T> {
 		T::IncentivePoolAccount::get().into_account_truncating()
 	}
 
+	pub fn instant_redeem_buffer_account() -> AccountIdOf<T> {
+		T::InstantRedeemBufferAccount::get().into_account_truncating()
+	}
+
+	/// The mint fee rate and redeem fee rate of a currency.
+	pub fn fees_of(currency_id: CurrencyId) -> (Permill, Permill) {
+		CurrencyFees::<T>::get(currency_id).unwrap_or_else(Fees::<T>::get)
+	}
+
+	/// The redeem fee rate of a redeemer, after the largest discount it has reached. The holding
+	/// discount only applies to the part of the redeemed amount the redeemer minted itself.
+	/// Parameters:
+	/// - `redeemer`: The redeemer account id.
+	/// - `currency_id`: The currency id.
+	/// - `v_currency_id`: The v_currency id.
+	/// - `v_currency_amount`: The v_currency amount to redeem.
+	pub fn redeem_fee_rate(
+		redeemer: &AccountIdOf<T>,
+		currency_id: CurrencyId,
+		v_currency_id: CurrencyId,
+		v_currency_amount: BalanceOf<T>,
+	) -> Permill {
+		let (_, redeem_rate) = Self::fees_of(currency_id);
+		let Some(config) = RedeemFeeDiscounts::<T>::get(currency_id) else {
+			return redeem_rate;
+		};
+
+		let mut bb_bnc_discount = Permill::zero();
+		if !config.bb_bnc_tiers.is_empty() {
+			let bb_bnc_balance =
+				T::BbBNC::balance_of(redeemer, None).unwrap_or_else(|_| Zero::zero());
+			bb_bnc_discount = Self::discount_reached(&config.bb_bnc_tiers, bb_bnc_balance);
+		}
+		let mut discount = bb_bnc_discount;
+		if let Some((since, minted_amount)) = VtokenHoldingSince::<T>::get(redeemer, v_currency_id)
+		{
+			let held_blocks = frame_system::Pallet::<T>::block_number().saturating_sub(since);
+			let holding_discount = Self::discount_reached(&config.holding_tiers, held_blocks);
+			let covered_amount = minted_amount
+				.min(T::MultiCurrency::free_balance(v_currency_id, redeemer))
+				.min(v_currency_amount);
+			let covered_share = Permill::from_rational(
+				covered_amount.saturated_into::<u128>(),
+				v_currency_amount.saturated_into::<u128>(),
+			);
+			discount = discount
+				.saturating_add(covered_share * holding_discount.saturating_sub(bb_bnc_discount));
+		}
+
+		redeem_rate * Permill::one().saturating_sub(discount)
+	}
+
+	fn discount_reached<V: PartialOrd>(tiers: &[(V, Permill)], value: V) -> Permill {
+		tiers
+			.iter()
+			.filter(|(threshold, _)| value >= *threshold)
+			.map(|(_, discount)| *discount)
+			.max()
+			.unwrap_or_default()
+	}
+
+	/// Move the holding start of a user's minted vtoken towards the current block, weighted by
+	/// the amount minted. Vtokens received by transfer are not counted as minted.
+	/// Parameters:
+	/// - `who`: The minter account id.
+	/// - `v_currency_id`: The v_currency id.
+	/// - `v_currency_amount`: The v_currency amount minted.
+	fn update_vtoken_holding_since(
+		who: &AccountIdOf<T>,
+		v_currency_id: CurrencyId,
+		v_currency_amount: BalanceOf<T>,
+	) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let balance = T::MultiCurrency::free_balance(v_currency_id, who);
+		VtokenHoldingSince::<T>::mutate(who, v_currency_id, |maybe_holding| {
+			let holding = match maybe_holding {
+				Some((since, minted_amount)) if !minted_amount.min(balance).is_zero() => {
+					let minted_amount = (*minted_amount).min(balance);
+					let elapsed = now.saturating_sub(*since).saturated_into::<u128>();
+					let delay = multiply_by_rational_with_rounding(
+						elapsed,
+						v_currency_amount.saturated_into::<u128>(),
+						minted_amount.saturating_add(v_currency_amount).saturated_into::<u128>(),
+						Rounding::Down,
+					)
+					.unwrap_or(elapsed);
+					(
+						since.saturating_add(delay.saturated_into()),
+						minted_amount.saturating_add(v_currency_amount),
+					)
+				},
+				_ => (now, v_currency_amount),
+			};
+			*maybe_holding = Some(holding);
+		});
+	}
+
+	/// Reduce the minted vtoken of a user by the amount it redeemed, minted vtoken being
+	/// redeemed first, and cap it by what the user still holds.
+	/// Parameters:
+	/// - `who`: The redeemer account id.
+	/// - `v_currency_id`: The v_currency id.
+	/// - `v_currency_amount`: The v_currency amount redeemed, including the redeem fee.
+	fn reduce_vtoken_holding(
+		who: &AccountIdOf<T>,
+		v_currency_id: CurrencyId,
+		v_currency_amount: BalanceOf<T>,
+	) {
+		let balance = T::MultiCurrency::free_balance(v_currency_id, who);
+		VtokenHoldingSince::<T>::mutate_exists(who, v_currency_id, |maybe_holding| {
+			if let Some((_, minted_amount)) = maybe_holding {
+				*minted_amount = minted_amount.saturating_sub(v_currency_amount).min(balance);
+				if minted_amount.is_zero() {
+					*maybe_holding = None;
+				}
+			}
+		});
+	}
+
+	/// Record the current exchange rate of the vtoken of a currency, replacing the snapshot
+	/// taken earlier in the same block.
+	/// Parameters:
+	/// - `currency_id`: The currency id.
+	pub fn record_exchange_rate(currency_id: CurrencyId) {
+		let Ok(v_currency_id) = currency_id.to_vtoken() else {
+			return;
+		};
+		let v_currency_total_issuance = T::MultiCurrency::total_issuance(v_currency_id);
+		let Some(exchange_rate) = FixedU128::checked_from_rational(
+			TokenPool::<T>::get(currency_id).saturated_into::<u128>(),
+			v_currency_total_issuance.saturated_into::<u128>(),
+		) else {
+			return;
+		};
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let snapshot = ExchangeRateSnapshot {
+			block_number,
+			time_unit: OngoingTimeUnit::<T>::get(currency_id).unwrap_or_default(),
+			exchange_rate,
+		};
+
+		let max_snapshots = T::MaxExchangeRateSnapshots::get();
+		if max_snapshots.is_zero() {
+			return;
+		}
+		ExchangeRateHistoryCursor::<T>::mutate(v_currency_id, |(next_slot, count)| {
+			let slot = *next_slot % max_snapshots;
+			let latest_slot = (slot + max_snapshots - 1) % max_snapshots;
+			if !count.is_zero() &&
+				ExchangeRateHistory::<T>::get(v_currency_id, latest_slot)
+					.is_some_and(|latest| latest.block_number == block_number)
+			{
+				ExchangeRateHistory::<T>::insert(v_currency_id, latest_slot, snapshot);
+				return;
+			}
+			ExchangeRateHistory::<T>::insert(v_currency_id, slot, snapshot);
+			*next_slot = (slot + 1) % max_snapshots;
+			*count = count.saturating_add(1).min(max_snapshots);
+		});
+	}
+
+	/// The exchange rate snapshots of a vtoken, from the oldest to the latest.
+	pub fn get_exchange_rate_history(
+		v_currency_id: CurrencyId,
+	) -> Vec<ExchangeRateSnapshot<BlockNumberFor<T>>> {
+		let max_snapshots = T::MaxExchangeRateSnapshots::get();
+		if max_snapshots.is_zero() {
+			return Vec::new();
+		}
+		let (next_slot, count) = ExchangeRateHistoryCursor::<T>::get(v_currency_id);
+		let count = count.min(max_snapshots);
+		let oldest_slot = (next_slot % max_snapshots + max_snapshots - count) % max_snapshots;
+		(0..count)
+			.filter_map(|i| {
+				ExchangeRateHistory::<T>::get(v_currency_id, (oldest_slot + i) % max_snapshots)
+			})
+			.collect()
+	}
+
+	/// The annualised yield of a vtoken, from the oldest snapshot within `window` blocks of the
+	/// latest snapshot to the latest snapshot.
+	/// Parameters:
+	/// - `v_currency_id`: The vtoken id.
+	/// - `window`: The number of blocks to look back.
+	/// - `blocks_per_year`: The number of blocks in a year.
+	/// Returns:
+	/// - `Option<FixedI128>`: The annualised yield, `None` if there are not two snapshots in the
+	///   window.
+	pub fn get_annualised_yield(
+		v_currency_id: CurrencyId,
+		window: BlockNumberFor<T>,
+		blocks_per_year: BlockNumberFor<T>,
+	) -> Option<FixedI128> {
+		let history = Self::get_exchange_rate_history(v_currency_id);
+		let latest = history.last()?;
+		let start_block = latest.block_number.saturating_sub(window);
+		let earliest = history.iter().find(|snapshot| snapshot.block_number >= start_block)?;
+		let elapsed = latest.block_number.saturating_sub(earliest.block_number);
+		if elapsed.is_zero() {
+			return None;
+		}
+
+		let growth = FixedI128::checked_from_rational(
+			latest.exchange_rate.into_inner(),
+			earliest.exchange_rate.into_inner(),
+		)?
+		.checked_sub(&FixedI128::one())?;
+		growth.checked_mul(&FixedI128::checked_from_rational(
+			blocks_per_year.saturated_into::<u128>(),
+			elapsed.saturated_into::<u128>(),
+		)?)
+	}
+
 	// to lock user vtoken for incentive minting
 	pub fn lock_vtoken_for_incentive_minting(
 		minter: AccountIdOf<T>,
@@ -842,7 +1638,9 @@ impl<T: Config> VtokenMintingOperator<CurrencyId, BalanceOf<T>, AccountIdOf<T>,
 		currency_id: CurrencyId,
 		currency_amount: BalanceOf<T>,
 	) -> DispatchResult {
-		Self::update_token_pool(&currency_id, &currency_amount, Operation::Add)
+		Self::update_token_pool(&currency_id, &currency_amount, Operation::Add)?;
+		Self::record_exchange_rate(currency_id);
+		Ok(())
 	}
 
 	fn decrease_token_pool(
@@ -857,6 +1655,7 @@ impl<T: Config> VtokenMintingOperator<CurrencyId, BalanceOf<T>, AccountIdOf<T>,
 			*time_unit_old = Some(time_unit);
 			Ok(())
 		})?;
+		Self::record_exchange_rate(currency_id);
 
 		Ok(())
 	}
@@ -1006,3 +1805,42 @@ impl<T: Config> VTokenSupplyProvider<CurrencyIdOf<T>, BalanceOf<T>> for Pallet<T
 		}
 	}
 }
+
+impl<T: Config> nonfungibles::Inspect<AccountIdOf<T>> for Pallet<T> {
+	type ItemId = UnlockId;
+	type CollectionId = CurrencyId;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountIdOf<T>> {
+		TokenUnlockLedger::<T>::get(collection, item).map(|(account, _, _, _)| account)
+	}
+
+	/// Exposes the `amount` and `time_unit` of the unlock record, SCALE encoded.
+	fn attribute(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		key: &[u8],
+	) -> Option<Vec<u8>> {
+		let (_, currency_amount, time_unit, _) = TokenUnlockLedger::<T>::get(collection, item)?;
+		match key {
+			b"amount" => Some(currency_amount.encode()),
+			b"time_unit" => Some(time_unit.encode()),
+			_ => None,
+		}
+	}
+
+	fn can_transfer(collection: &Self::CollectionId, item: &Self::ItemId) -> bool {
+		matches!(TokenUnlockLedger::<T>::get(collection, item), Some((_, _, _, RedeemType::Native)))
+	}
+}
+
+impl<T: Config> nonfungibles::Transfer<AccountIdOf<T>> for Pallet<T> {
+	fn transfer(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		destination: &AccountIdOf<T>,
+	) -> DispatchResult {
+		let holder = <Self as nonfungibles::Inspect<AccountIdOf<T>>>::owner(collection, item)
+			.ok_or(Error::<T>::TokenUnlockLedgerNotFound)?;
+		Self::do_transfer_unlock_receipt(&holder, *collection, *item, destination)
+	}
+}
@@ -37,12 +37,12 @@ pub use weights::WeightInfo;
 use crate::impls::Operation;
 use bb_bnc::traits::BbBNCInterface;
 use bifrost_primitives::{
-	CurrencyId, RedeemType, SlpxOperator, TimeUnit, VTokenMintRedeemProvider,
+	CurrencyId, ExchangeRateSnapshot, RedeemType, SlpxOperator, TimeUnit, VTokenMintRedeemProvider,
 };
 use frame_support::{
 	pallet_prelude::{DispatchResultWithPostInfo, *},
 	sp_runtime::{
-		traits::{CheckedAdd, CheckedSub, Saturating, Zero},
+		traits::{CheckedAdd, CheckedSub, IdentifyAccount, Saturating, Verify, Zero},
 		DispatchError, Permill,
 	},
 	traits::LockIdentifier,
@@ -63,6 +63,80 @@ pub type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<AccountId
 
 pub type UnlockId = u32;
 
+/// The fee range of instant redeems. The fee grows linearly from `min_fee` to `max_fee` with the
+/// share of the buffer that is lent out.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InstantRedeemConfig {
+	/// The fee rate when the buffer is unused.
+	pub min_fee: Permill,
+	/// The fee rate when the buffer is used up.
+	pub max_fee: Permill,
+}
+
+/// Maximum tiers of each kind in a redeem fee discount config
+pub const MAX_FEE_DISCOUNT_TIERS: u32 = 10;
+
+/// The redeem fee discount tiers of a currency, each tier is a threshold and the discount given
+/// once it is reached. The largest discount reached by a redeemer is applied.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeeDiscountConfig<Balance, BlockNumber> {
+	/// Tiers by the number of blocks the vtoken has been held since it was minted.
+	pub holding_tiers: BoundedVec<(BlockNumber, Permill), ConstU32<MAX_FEE_DISCOUNT_TIERS>>,
+	/// Tiers by the bbBNC balance of the redeemer.
+	pub bb_bnc_tiers: BoundedVec<(Balance, Permill), ConstU32<MAX_FEE_DISCOUNT_TIERS>>,
+}
+
+/// Maximum time units a redeem can roll over to
+pub const MAX_TIME_UNIT_ROLLOVER: u32 = 10;
+
+/// How the redeems of a currency are queued and paid out.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RedeemQueueConfig<Balance> {
+	/// The maximum currency amount unlocking at a time unit. The part of a redeem beyond it rolls
+	/// over to the following time units, what is left after `max_rollover` time units is queued
+	/// at the last of them beyond the cap.
+	pub time_unit_cap: Balance,
+	/// The maximum number of time units a redeem can roll over.
+	pub max_rollover: u32,
+	/// Whether the unlocks of a time unit are paid out pro rata when the entrance account can not
+	/// pay all of them.
+	pub pro_rata: bool,
+}
+
+/// The operation a permit allows the relayer to do on behalf of its owner.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PermitAction {
+	/// Mint vtoken with the owner's token.
+	Mint,
+	/// Redeem the owner's vtoken.
+	Redeem,
+}
+
+/// An off-chain permit signed by its owner, letting a relayer mint or redeem on the owner's
+/// behalf while paying the transaction fees.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Permit<AccountId, CurrencyId, Balance, BlockNumber> {
+	/// The account that signs the permit and owns the tokens.
+	pub owner: AccountId,
+	/// The operation allowed.
+	pub action: PermitAction,
+	/// The token to mint, or the vtoken to redeem.
+	pub currency_id: CurrencyId,
+	/// The maximum amount the relayer can mint or redeem.
+	pub max_amount: Balance,
+	/// The last block the permit can be used at.
+	pub deadline: BlockNumber,
+	/// The permit nonce of the owner, a permit can only be used once.
+	pub nonce: u64,
+	/// The remark recorded with a mint, unused for a redeem.
+	pub remark: BoundedVec<u8, ConstU32<32>>,
+	/// The channel credited with a mint, unused for a redeem.
+	pub channel_id: Option<u32>,
+}
+
+/// The prefix of the payload signed for a permit
+pub const PERMIT_DOMAIN: &[u8] = b"bifrost/vtoken-minting/permit";
+
 // incentive lock id for vtoken minted by user
 const INCENTIVE_LOCK_ID: LockIdentifier = *b"vmincntv";
 
@@ -103,6 +177,10 @@ pub mod pallet {
 		>;
 		/// Channel commission provider
 		type ChannelCommission: VTokenMintRedeemProvider<CurrencyId, BalanceOf<Self>>;
+		/// The signature of the off-chain permits.
+		type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+		/// The public key of the permit signers.
+		type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
 
 		/// Maximum unlock id of user
 		#[pallet::constant]
@@ -113,6 +191,9 @@ pub mod pallet {
 		/// Maximum unlocked vtoken records minted in an incentive mode
 		#[pallet::constant]
 		type MaxLockRecords: Get<u32>;
+		/// Maximum exchange rate snapshots kept for each vtoken
+		#[pallet::constant]
+		type MaxExchangeRateSnapshots: Get<u32>;
 		/// Currency receive account
 		#[pallet::constant]
 		type EntranceAccount: Get<PalletId>;
@@ -129,6 +210,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type IncentivePoolAccount: Get<PalletId>;
 
+		/// Buffer account that pays out instant redeems
+		#[pallet::constant]
+		type InstantRedeemBufferAccount: Get<PalletId>;
+
 		#[pallet::constant]
 		type RelayChainToken: Get<CurrencyId>;
 
@@ -290,6 +375,150 @@ pub mod pallet {
 			v_currency_id: CurrencyIdOf<T>,
 			blocks: Option<BlockNumberFor<T>>,
 		},
+		/// Unlock receipt offered.
+		UnlockReceiptOffered {
+			/// The holder of the receipt.
+			from: AccountIdOf<T>,
+			/// The account that can accept the receipt.
+			to: AccountIdOf<T>,
+			/// The currency id of the unlock record.
+			currency_id: CurrencyIdOf<T>,
+			/// The unlock_id of the receipt.
+			unlock_id: UnlockId,
+		},
+		/// Unlock receipt transferred.
+		UnlockReceiptTransferred {
+			/// The previous holder of the receipt.
+			from: AccountIdOf<T>,
+			/// The new holder of the receipt.
+			to: AccountIdOf<T>,
+			/// The currency id of the unlock record.
+			currency_id: CurrencyIdOf<T>,
+			/// The unlock_id of the receipt.
+			unlock_id: UnlockId,
+			/// The currency amount of the unlock record.
+			currency_amount: BalanceOf<T>,
+		},
+		/// Set instant redeem config.
+		InstantRedeemConfigSet {
+			/// The currency id set instant redeem config.
+			currency_id: CurrencyIdOf<T>,
+			/// The instant redeem config set, `None` if instant redeem is disabled.
+			config: Option<InstantRedeemConfig>,
+		},
+		/// Vtoken redeemed instantly from the buffer.
+		InstantRedeemed {
+			/// The redeemer account.
+			redeemer: AccountIdOf<T>,
+			/// The currency id paid out.
+			currency_id: CurrencyIdOf<T>,
+			/// The currency amount redeemed, including the instant redeem fee.
+			currency_amount: BalanceOf<T>,
+			/// The v_currency amount burned.
+			v_currency_amount: BalanceOf<T>,
+			/// Redeem fee
+			redeem_fee: BalanceOf<T>,
+			/// Instant redeem fee, kept by the buffer.
+			instant_redeem_fee: BalanceOf<T>,
+		},
+		/// Instant redeem buffer refilled by minting.
+		InstantRedeemBufferRefilled {
+			/// The currency id refilled.
+			currency_id: CurrencyIdOf<T>,
+			/// The currency amount refilled.
+			currency_amount: BalanceOf<T>,
+		},
+		/// Instant redeem debt unlocked to the buffer.
+		InstantRedeemDebtUnlocked {
+			/// The currency id unlocked.
+			currency_id: CurrencyIdOf<T>,
+			/// The currency amount unlocked.
+			currency_amount: BalanceOf<T>,
+			/// The unlock_id owned by the buffer.
+			unlock_id: UnlockId,
+		},
+		/// Rebond of an unlock_id scheduled.
+		RebondScheduled {
+			/// The rebonder account.
+			rebonder: AccountIdOf<T>,
+			/// The currency id to rebond.
+			currency_id: CurrencyIdOf<T>,
+			/// The unlock_id to rebond.
+			unlock_id: UnlockId,
+			/// The currency amount to rebond.
+			currency_amount: BalanceOf<T>,
+			/// The time unit to rebond at.
+			time_unit: TimeUnit,
+		},
+		/// Scheduled rebond of an unlock_id cancelled.
+		ScheduledRebondCancelled {
+			/// The rebonder account.
+			rebonder: AccountIdOf<T>,
+			/// The currency id of the scheduled rebond.
+			currency_id: CurrencyIdOf<T>,
+			/// The unlock_id of the scheduled rebond.
+			unlock_id: UnlockId,
+			/// The time unit of the scheduled rebond.
+			time_unit: TimeUnit,
+		},
+		/// Scheduled rebond failed.
+		ScheduledRebondFailed {
+			/// The currency id of the scheduled rebond.
+			currency_id: CurrencyIdOf<T>,
+			/// The unlock_id of the scheduled rebond.
+			unlock_id: UnlockId,
+			err: DispatchError,
+		},
+		/// Set mint fee and redeem fee of a currency.
+		CurrencyFeeSet {
+			/// The currency id set fees.
+			currency_id: CurrencyIdOf<T>,
+			/// The mint fee rate and redeem fee rate set, `None` to use the default fees.
+			fees: Option<(Permill, Permill)>,
+		},
+		/// Set redeem fee discount of a currency.
+		RedeemFeeDiscountSet {
+			/// The currency id set redeem fee discount.
+			currency_id: CurrencyIdOf<T>,
+			/// The redeem fee discount set, `None` if there is no discount.
+			config: Option<FeeDiscountConfig<BalanceOf<T>, BlockNumberFor<T>>>,
+		},
+		/// Set redeem queue config of a currency.
+		RedeemQueueConfigSet {
+			/// The currency id set redeem queue config.
+			currency_id: CurrencyIdOf<T>,
+			/// The redeem queue config set, `None` to remove the cap and pro rata payout.
+			config: Option<RedeemQueueConfig<BalanceOf<T>>>,
+		},
+		/// Part of a redeem rolled over to a later time unit by the time unit cap. The rest of the
+		/// redeem is in the unlock_id of the `Redeemed` event.
+		RedeemRolledOver {
+			/// The redeemer account.
+			redeemer: AccountIdOf<T>,
+			/// The currency id redeemed.
+			currency_id: CurrencyIdOf<T>,
+			/// The currency amount rolled over.
+			currency_amount: BalanceOf<T>,
+			/// The unlock_id of the currency amount rolled over.
+			unlock_id: UnlockId,
+			/// The time unit rolled over to.
+			time_unit: TimeUnit,
+		},
+		/// A permit was used by a relayer to mint or redeem on behalf of its owner.
+		PermitUsed {
+			/// The account that signed the permit.
+			owner: AccountIdOf<T>,
+			/// The account that submitted the permit.
+			relayer: AccountIdOf<T>,
+			/// The operation done.
+			action: PermitAction,
+			/// The currency id minted or redeemed.
+			currency_id: CurrencyIdOf<T>,
+			/// The amount minted or redeemed.
+			amount: BalanceOf<T>,
+			/// The nonce of the permit.
+			nonce: u64,
+		},
 	}
 
 	#[pallet::error]
@@ -342,6 +571,42 @@ pub mod pallet {
 		BalanceZero,
 		/// IncentiveLockBlocksNotSet
 		IncentiveLockBlocksNotSet,
+		/// Not the holder of the unlock receipt.
+		NotUnlockReceiptHolder,
+		/// The unlock receipt can not be transferred.
+		UnlockReceiptNotTransferable,
+		/// The unlock receipt is not offered to the account.
+		NoUnlockReceiptOffer,
+		/// Invalid instant redeem config.
+		InvalidInstantRedeemConfig,
+		/// Instant redeem not supported.
+		InstantRedeemNotSupported,
+		/// Not enough liquidity in the instant redeem buffer.
+		NotEnoughBufferLiquidity,
+		/// Exceed the instant redeem debt.
+		ExceedInstantRedeemDebt,
+		/// The rebond should be scheduled after the ongoing time unit.
+		InvalidRebondSchedule,
+		/// Too many rebonds scheduled for a time unit.
+		TooManyScheduledRebonds,
+		/// Scheduled rebond not found.
+		ScheduledRebondNotFound,
+		/// The fee discount tiers should be in ascending order.
+		InvalidFeeDiscountConfig,
+		/// Invalid redeem queue config.
+		InvalidRedeemQueueConfig,
+		/// The permit is past its deadline.
+		PermitExpired,
+		/// The permit nonce is not the owner's next nonce.
+		InvalidPermitNonce,
+		/// The permit is not signed by its owner.
+		InvalidPermitSignature,
+		/// The permit is not for this operation.
+		PermitMismatch,
+		/// The amount exceeds the maximum amount of the permit.
+		ExceedPermitAmount,
+		/// The rebond amount is zero.
+		RebondAmountZero,
 	}
 
 	/// The mint fee and redeem fee.
@@ -474,9 +739,112 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The instant redeem config for each currency
+	#[pallet::storage]
+	pub type InstantRedeemConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyIdOf<T>, InstantRedeemConfig>;
+
+	/// The amount of tokens paid out by the instant redeem buffer that are still staked
+	#[pallet::storage]
+	pub type InstantRedeemDebt<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+	/// The rebonds scheduled for each time unit and currency
+	#[pallet::storage]
+	pub type ScheduledRebonds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TimeUnit,
+		Blake2_128Concat,
+		CurrencyIdOf<T>,
+		BoundedVec<
+			// (rebonder account, unlock_id, rebond amount)
+			(AccountIdOf<T>, UnlockId, BalanceOf<T>),
+			T::MaximumUnlockIdOfTimeUnit,
+		>,
+		OptionQuery,
+	>;
+
+	/// The earliest time unit of each currency whose scheduled rebonds may not be executed yet
+	#[pallet::storage]
+	pub type ScheduledRebondTimeUnit<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyIdOf<T>, TimeUnit, OptionQuery>;
+
+	/// The exchange rate history of each vtoken, a ring buffer of snapshots indexed by slot. The
+	/// oldest snapshot is overwritten when it is full.
+	#[pallet::storage]
+	pub type ExchangeRateHistory<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		Twox64Concat,
+		u32,
+		ExchangeRateSnapshot<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The next slot and the number of snapshots of the exchange rate history of each vtoken
+	#[pallet::storage]
+	pub type ExchangeRateHistoryCursor<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyIdOf<T>, (u32, u32), ValueQuery>;
+
+	/// The mint fee and redeem fee of each currency, overriding the default fees.
+	#[pallet::storage]
+	pub type CurrencyFees<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyIdOf<T>, (Permill, Permill)>;
+
+	/// The redeem fee discount of each currency
+	#[pallet::storage]
+	pub type RedeemFeeDiscounts<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		FeeDiscountConfig<BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// The redeem queue config of each currency
+	#[pallet::storage]
+	pub type RedeemQueueConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyIdOf<T>, RedeemQueueConfig<BalanceOf<T>>>;
+
+	//【user + vtoken -> (block number, minted amount)】, the block since which the user has held
+	// its minted vtoken, weighted by the amounts minted, and the minted amount it still holds
+	#[pallet::storage]
+	pub type VtokenHoldingSince<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		CurrencyIdOf<T>,
+		(BlockNumberFor<T>, BalanceOf<T>),
+	>;
+
+	/// The next permit nonce of each account
+	#[pallet::storage]
+	pub type PermitNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u64, ValueQuery>;
+
+	/// According to currency_id and unlock_id, the pending offer of an unlock receipt, made by
+	/// its holder to the account that can accept it.
+	#[pallet::storage]
+	pub type UnlockReceiptOffers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyIdOf<T>,
+		Blake2_128Concat,
+		UnlockId,
+		(
+			// holder account
+			AccountIdOf<T>,
+			// recipient account
+			AccountIdOf<T>,
+		),
+	>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let mut weight = Weight::zero();
 			for currency in OngoingTimeUnit::<T>::iter_keys() {
 				let result = Self::handle_ledger_by_currency(currency);
 				match result {
@@ -490,9 +858,10 @@ pub mod pallet {
 						);
 					},
 				}
+				weight.saturating_accrue(Self::handle_scheduled_rebonds(currency));
 			}
 
-			T::WeightInfo::on_initialize()
+			T::WeightInfo::on_initialize().saturating_add(weight)
 		}
 	}
 
@@ -592,17 +961,7 @@ pub mod pallet {
 				currency_amount,
 			)?;
 
-			TokenToRebond::<T>::mutate(&currency_id, |maybe_value| -> Result<(), Error<T>> {
-				match maybe_value {
-					Some(rebonded_amount) => {
-						*rebonded_amount = rebonded_amount
-							.checked_add(&currency_amount)
-							.ok_or(Error::<T>::CalculationOverflow)?;
-						Ok(())
-					},
-					None => Err(Error::<T>::InvalidRebondToken),
-				}
-			})?;
+			Self::add_token_to_rebond(currency_id, currency_amount)?;
 
 			Self::deposit_event(Event::Rebonded {
 				rebonder,
@@ -626,49 +985,7 @@ pub mod pallet {
 			unlock_id: UnlockId,
 		) -> DispatchResult {
 			let rebonder = ensure_signed(origin)?;
-
-			let v_currency_id =
-				currency_id.to_vtoken().map_err(|_| Error::<T>::NotSupportTokenType)?;
-
-			let (who, unlock_amount, time_unit, _) =
-				TokenUnlockLedger::<T>::get(currency_id, unlock_id)
-					.ok_or(Error::<T>::TokenUnlockLedgerNotFound)?;
-			ensure!(who == rebonder, Error::<T>::CanNotRebond);
-
-			Self::update_unlock_ledger(
-				&rebonder,
-				&currency_id,
-				&unlock_amount,
-				&unlock_id,
-				&time_unit,
-				None,
-				Operation::Sub,
-			)?;
-
-			let (currency_amount, v_currency_amount, fee) =
-				Self::mint_without_transfer(&rebonder, v_currency_id, currency_id, unlock_amount)?;
-
-			TokenToRebond::<T>::mutate(&currency_id, |maybe_value| -> Result<(), Error<T>> {
-				match maybe_value {
-					Some(rebonded_amount) => {
-						*rebonded_amount = rebonded_amount
-							.checked_add(&currency_amount)
-							.ok_or(Error::<T>::CalculationOverflow)?;
-						Ok(())
-					},
-					None => Err(Error::<T>::InvalidRebondToken),
-				}
-			})?;
-
-			Self::deposit_event(Event::RebondedByUnlockId {
-				rebonder,
-				currency_id,
-				currency_amount: unlock_amount,
-				v_currency_amount,
-				fee,
-				unlock_id,
-			});
-			Ok(())
+			Self::do_rebond_by_unlock_id(&rebonder, currency_id, unlock_id, None)
 		}
 
 		/// Set the unlock duration for a currency.
@@ -1092,5 +1409,344 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Offer the receipt of an unlock record to another account. The receipt only moves
+		/// once `dest` accepts it, after which `dest` receives the currency when the record is
+		/// paid out. A new offer replaces the previous one.
+		/// Parameters:
+		/// - `currency_id`: The currency of the unlock record.
+		/// - `unlock_id`: The unlock_id of the receipt.
+		/// - `dest`: The account that can accept the receipt.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::transfer_unlock_receipt())]
+		pub fn transfer_unlock_receipt(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			unlock_id: UnlockId,
+			dest: AccountIdOf<T>,
+		) -> DispatchResult {
+			let holder = ensure_signed(origin)?;
+			Self::do_offer_unlock_receipt(&holder, currency_id, unlock_id, &dest)
+		}
+
+		/// Set the instant redeem config for a currency.
+		/// Parameters:
+		/// - `currency_id`: The currency to set instant redeem config.
+		/// - `config`: The instant redeem config to set, `None` to disable instant redeem.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::set_instant_redeem_config())]
+		pub fn set_instant_redeem_config(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			config: Option<InstantRedeemConfig>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			if let Some(config) = config {
+				ensure!(config.min_fee <= config.max_fee, Error::<T>::InvalidInstantRedeemConfig);
+				InstantRedeemConfigs::<T>::insert(currency_id, config);
+			} else {
+				InstantRedeemConfigs::<T>::remove(currency_id);
+			}
+
+			Self::deposit_event(Event::InstantRedeemConfigSet { currency_id, config });
+			Ok(())
+		}
+
+		/// Redeem currency by burning v_currency, paid out immediately from the buffer.
+		/// Parameters:
+		/// - `v_currency_id`: The v_currency to redeem.
+		/// - `v_currency_amount`: The amount of v_currency to redeem.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::instant_redeem())]
+		pub fn instant_redeem(
+			origin: OriginFor<T>,
+			v_currency_id: CurrencyIdOf<T>,
+			v_currency_amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let redeemer = ensure_signed(origin)?;
+			Self::do_instant_redeem(redeemer, v_currency_id, v_currency_amount)
+		}
+
+		/// Unlock part of the instant redeem debt, the buffer is repaid when it is unlocked.
+		/// Parameters:
+		/// - `currency_id`: The currency to unlock.
+		/// - `currency_amount`: The amount of currency to unlock.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::unlock_instant_redeem_debt())]
+		pub fn unlock_instant_redeem_debt(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			currency_amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+			Self::do_unlock_instant_redeem_debt(currency_id, currency_amount)
+		}
+
+		/// Rebond part of an unlock_id.
+		/// Parameters:
+		/// - `currency_id`: The currency to rebond.
+		/// - `unlock_id`: The unlock_id to rebond.
+		/// - `currency_amount`: The amount of currency to rebond. The amount should be less than or
+		///   equal to the amount of the unlock_id.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::partial_rebond_by_unlock_id())]
+		pub fn partial_rebond_by_unlock_id(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			unlock_id: UnlockId,
+			currency_amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let rebonder = ensure_signed(origin)?;
+			ensure!(!currency_amount.is_zero(), Error::<T>::RebondAmountZero);
+			Self::do_rebond_by_unlock_id(&rebonder, currency_id, unlock_id, Some(currency_amount))
+		}
+
+		/// Schedule to rebond an unlock_id if it is not paid out by a time unit. Scheduling the
+		/// same unlock_id again for the time unit replaces the scheduled amount.
+		/// Parameters:
+		/// - `currency_id`: The currency to rebond.
+		/// - `unlock_id`: The unlock_id to rebond.
+		/// - `currency_amount`: The amount of currency to rebond, capped by the amount left in the
+		///   unlock_id.
+		/// - `time_unit`: The time unit to rebond at, it should be after the ongoing time unit.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::schedule_rebond())]
+		pub fn schedule_rebond(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			unlock_id: UnlockId,
+			currency_amount: BalanceOf<T>,
+			time_unit: TimeUnit,
+		) -> DispatchResult {
+			let rebonder = ensure_signed(origin)?;
+
+			ensure!(TokenToRebond::<T>::contains_key(currency_id), Error::<T>::InvalidRebondToken);
+			ensure!(!currency_amount.is_zero(), Error::<T>::RebondAmountZero);
+			let (who, unlock_amount, _, _) = TokenUnlockLedger::<T>::get(currency_id, unlock_id)
+				.ok_or(Error::<T>::TokenUnlockLedgerNotFound)?;
+			ensure!(who == rebonder, Error::<T>::CanNotRebond);
+			ensure!(unlock_amount >= currency_amount, Error::<T>::NotEnoughBalanceToUnlock);
+			let ongoing_time_unit =
+				OngoingTimeUnit::<T>::get(currency_id).ok_or(Error::<T>::OngoingTimeUnitNotSet)?;
+			ensure!(time_unit > ongoing_time_unit, Error::<T>::InvalidRebondSchedule);
+
+			ScheduledRebonds::<T>::try_mutate(&time_unit, currency_id, |schedules| {
+				let schedules = schedules.get_or_insert_with(BoundedVec::default);
+				match schedules.iter_mut().find(|(who, id, _)| *who == rebonder && *id == unlock_id)
+				{
+					Some((_, _, amount)) => {
+						*amount = currency_amount;
+						Ok(())
+					},
+					None => schedules
+						.try_push((rebonder.clone(), unlock_id, currency_amount))
+						.map_err(|_| Error::<T>::TooManyScheduledRebonds),
+				}
+			})?;
+			ScheduledRebondTimeUnit::<T>::mutate(currency_id, |next_time_unit| {
+				if next_time_unit.as_ref().map_or(true, |next| time_unit < *next) {
+					*next_time_unit = Some(time_unit.clone());
+				}
+			});
+
+			Self::deposit_event(Event::RebondScheduled {
+				rebonder,
+				currency_id,
+				unlock_id,
+				currency_amount,
+				time_unit,
+			});
+			Ok(())
+		}
+
+		/// Cancel the rebonds of an unlock_id scheduled for a time unit.
+		/// Parameters:
+		/// - `currency_id`: The currency of the scheduled rebond.
+		/// - `unlock_id`: The unlock_id of the scheduled rebond.
+		/// - `time_unit`: The time unit of the scheduled rebond.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::cancel_scheduled_rebond())]
+		pub fn cancel_scheduled_rebond(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			unlock_id: UnlockId,
+			time_unit: TimeUnit,
+		) -> DispatchResult {
+			let rebonder = ensure_signed(origin)?;
+
+			ScheduledRebonds::<T>::try_mutate_exists(&time_unit, currency_id, |maybe_schedules| {
+				let schedules =
+					maybe_schedules.as_mut().ok_or(Error::<T>::ScheduledRebondNotFound)?;
+				let len = schedules.len();
+				schedules.retain(|(who, id, _)| *who != rebonder || *id != unlock_id);
+				ensure!(schedules.len() < len, Error::<T>::ScheduledRebondNotFound);
+				if schedules.is_empty() {
+					*maybe_schedules = None;
+				}
+				Ok::<(), Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::ScheduledRebondCancelled {
+				rebonder,
+				currency_id,
+				unlock_id,
+				time_unit,
+			});
+			Ok(())
+		}
+
+		/// Set the fees for mint and redeem of a currency.
+		/// Parameters:
+		/// - `currency_id`: The currency to set fees.
+		/// - `fees`: The mint fee and redeem fee, `None` to use the default fees.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::set_currency_fees())]
+		pub fn set_currency_fees(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			fees: Option<(Permill, Permill)>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			CurrencyFees::<T>::set(currency_id, fees);
+
+			Self::deposit_event(Event::CurrencyFeeSet { currency_id, fees });
+			Ok(())
+		}
+
+		/// Set the redeem fee discount of a currency.
+		/// Parameters:
+		/// - `currency_id`: The currency to set redeem fee discount.
+		/// - `config`: The discount tiers, `None` to remove the discount.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_redeem_fee_discount())]
+		pub fn set_redeem_fee_discount(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			config: Option<FeeDiscountConfig<BalanceOf<T>, BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			if let Some(config) = &config {
+				ensure!(
+					config.holding_tiers.windows(2).all(|tiers| tiers[0].0 < tiers[1].0) &&
+						config.bb_bnc_tiers.windows(2).all(|tiers| tiers[0].0 < tiers[1].0),
+					Error::<T>::InvalidFeeDiscountConfig
+				);
+			}
+			RedeemFeeDiscounts::<T>::set(currency_id, config.clone());
+
+			Self::deposit_event(Event::RedeemFeeDiscountSet { currency_id, config });
+			Ok(())
+		}
+
+		/// Set the redeem queue config of a currency.
+		/// Parameters:
+		/// - `currency_id`: The currency to set redeem queue config.
+		/// - `config`: The redeem queue config, `None` to remove the cap and pro rata payout.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_redeem_queue_config())]
+		pub fn set_redeem_queue_config(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			config: Option<RedeemQueueConfig<BalanceOf<T>>>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			if let Some(config) = config {
+				ensure!(
+					!config.time_unit_cap.is_zero() &&
+						config.max_rollover <= MAX_TIME_UNIT_ROLLOVER,
+					Error::<T>::InvalidRedeemQueueConfig
+				);
+			}
+			RedeemQueueConfigs::<T>::set(currency_id, config);
+
+			Self::deposit_event(Event::RedeemQueueConfigSet { currency_id, config });
+			Ok(())
+		}
+
+		/// Mint v_currency on behalf of the owner of a permit, the relayer pays the fees.
+		/// The currency is transferred from and the minted v_currency deposited to the owner.
+		/// Parameters:
+		/// - `permit`: The mint permit signed by its owner.
+		/// - `signature`: The owner's signature of `Pallet::permit_payload`.
+		/// - `currency_amount`: The amount of currency to mint, at most `permit.max_amount`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::mint_with_permit())]
+		pub fn mint_with_permit(
+			origin: OriginFor<T>,
+			permit: Permit<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+			signature: T::OffchainSignature,
+			currency_amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			Self::use_permit(relayer, &permit, &signature, PermitAction::Mint, currency_amount)?;
+			Self::do_mint(
+				permit.owner,
+				permit.currency_id,
+				currency_amount,
+				permit.remark,
+				permit.channel_id,
+			)?;
+			Ok(())
+		}
+
+		/// Redeem v_currency on behalf of the owner of a permit, the relayer pays the fees.
+		/// The v_currency is burnt from and the unlocked currency paid to the owner.
+		/// Parameters:
+		/// - `permit`: The redeem permit signed by its owner.
+		/// - `signature`: The owner's signature of `Pallet::permit_payload`.
+		/// - `v_currency_amount`: The amount of v_currency to redeem, at most `permit.max_amount`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::redeem_with_permit())]
+		pub fn redeem_with_permit(
+			origin: OriginFor<T>,
+			permit: Permit<AccountIdOf<T>, CurrencyIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+			signature: T::OffchainSignature,
+			v_currency_amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let relayer = ensure_signed(origin)?;
+			Self::use_permit(
+				relayer,
+				&permit,
+				&signature,
+				PermitAction::Redeem,
+				v_currency_amount,
+			)?;
+			let mut post_info = Self::do_redeem(
+				permit.owner,
+				permit.currency_id,
+				v_currency_amount,
+				RedeemType::Native,
+			)?;
+			// The actual weight of the redeem does not include checking the permit.
+			post_info.actual_weight = post_info.actual_weight.map(|weight| {
+				weight.saturating_add(
+					T::WeightInfo::redeem_with_permit().saturating_sub(T::WeightInfo::redeem()),
+				)
+			});
+			Ok(post_info)
+		}
+
+		/// Accept the receipt of an unlock record offered to the caller. The receipt and its
+		/// user unlock ledger entry move to the caller.
+		/// Parameters:
+		/// - `currency_id`: The currency of the unlock record.
+		/// - `unlock_id`: The unlock_id of the receipt.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::accept_unlock_receipt())]
+		pub fn accept_unlock_receipt(
+			origin: OriginFor<T>,
+			currency_id: CurrencyIdOf<T>,
+			unlock_id: UnlockId,
+		) -> DispatchResult {
+			let dest = ensure_signed(origin)?;
+			let (holder, offered_to) = UnlockReceiptOffers::<T>::get(currency_id, unlock_id)
+				.ok_or(Error::<T>::NoUnlockReceiptOffer)?;
+			ensure!(offered_to == dest, Error::<T>::NoUnlockReceiptOffer);
+			Self::do_transfer_unlock_receipt(&holder, currency_id, unlock_id, &dest)
+		}
 	}
 }
@@ -26,15 +26,16 @@ use bifrost_asset_registry::AssetIdMaps;
 use bifrost_primitives::{
 	currency::{BNC, DOT, FIL, KSM, MOVR, VBNC, VFIL, VKSM, VMOVR},
 	BifrostEntranceAccount, BifrostExitAccount, BifrostFeeAccount, CurrencyId, CurrencyIdMapping,
-	IncentivePoolAccount, MockXcmTransfer, MoonbeamChainId, SlpxOperator, KUSD,
+	IncentivePoolAccount, InstantRedeemBufferPalletId, MockXcmTransfer, MoonbeamChainId,
+	SlpxOperator, KUSD,
 };
 use bifrost_runtime_common::{micro, milli};
 use frame_support::{derive_impl, ord_parameter_types, parameter_types, traits::Nothing};
 use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
 use sp_runtime::{
-	traits::{ConstU32, IdentityLookup},
-	AccountId32, BuildStorage, DispatchError, DispatchResult,
+	traits::{ConstU32, IdentityLookup, Verify},
+	AccountId32, BuildStorage, DispatchError, DispatchResult, MultiSignature,
 };
 use xcm::prelude::*;
 
@@ -156,6 +157,7 @@ parameter_types! {
 	pub const MaximumUnlockIdOfUser: u32 = 1_000;
 	pub const MaximumUnlockIdOfTimeUnit: u32 = 1_000;
 	pub const MaxLockRecords: u32 = 64;
+	pub const MaxExchangeRateSnapshots: u32 = 3;
 }
 
 ord_parameter_types! {
@@ -170,11 +172,15 @@ impl vtoken_minting::Config for Runtime {
 	type MaximumUnlockIdOfUser = MaximumUnlockIdOfUser;
 	type MaximumUnlockIdOfTimeUnit = MaximumUnlockIdOfTimeUnit;
 	type MaxLockRecords = MaxLockRecords;
+	type MaxExchangeRateSnapshots = MaxExchangeRateSnapshots;
+	type OffchainSignature = MultiSignature;
+	type SigningPublicKey = <MultiSignature as Verify>::Signer;
 	type EntranceAccount = BifrostEntranceAccount;
 	type ExitAccount = BifrostExitAccount;
 	type FeeAccount = BifrostFeeAccount;
 	type RedeemFeeAccount = BifrostFeeAccount;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BifrostSlpx = SlpxInterface;
 	type BbBNC = BbBNC;
 	type RelayChainToken = RelayCurrencyId;
@@ -23,10 +23,13 @@
 use crate::{mock::*, DispatchError::Module, *};
 use bifrost_primitives::{
 	currency::{BNC, FIL, KSM, MOVR, VBNC, VFIL, VKSM, VMOVR},
-	VtokenMintingOperator,
+	ExchangeRateSnapshot, VtokenMintingOperator,
 };
-use frame_support::{assert_noop, assert_ok, sp_runtime::Permill, BoundedVec};
-use sp_runtime::ModuleError;
+use frame_support::{
+	assert_noop, assert_ok, sp_runtime::Permill, traits::tokens::nonfungibles, BoundedVec,
+};
+use sp_core::{sr25519, Pair};
+use sp_runtime::{FixedI128, FixedPointNumber, FixedU128, ModuleError, MultiSignature};
 
 #[test]
 fn mint_bnc() {
@@ -418,6 +421,381 @@ fn rebond_by_unlock_id() {
 	});
 }
 
+#[test]
+fn partial_and_scheduled_rebond() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(2)
+		));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 300, BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 200));
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 100));
+		assert_ok!(VtokenMinting::add_support_rebond_token(RuntimeOrigin::signed(ALICE), KSM));
+
+		assert_noop!(
+			VtokenMinting::partial_rebond_by_unlock_id(Some(BOB).into(), KSM, 0, 250),
+			Error::<Runtime>::NotEnoughBalanceToUnlock
+		);
+		assert_noop!(
+			VtokenMinting::partial_rebond_by_unlock_id(Some(BOB).into(), KSM, 0, 0),
+			Error::<Runtime>::RebondAmountZero
+		);
+		assert_ok!(VtokenMinting::partial_rebond_by_unlock_id(Some(BOB).into(), KSM, 0, 50));
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 0),
+			Some((BOB, 150, TimeUnit::Era(3), RedeemType::Native))
+		);
+		assert_eq!(
+			UserUnlockLedger::<Runtime>::get(BOB, KSM),
+			Some((250, BoundedVec::try_from(vec![0, 1]).unwrap()))
+		);
+		assert_eq!(
+			TimeUnitUnlockLedger::<Runtime>::get(TimeUnit::Era(3), KSM),
+			Some((250, BoundedVec::try_from(vec![0, 1]).unwrap(), KSM))
+		);
+		assert_eq!(UnlockingTotal::<Runtime>::get(KSM), 250);
+		assert_eq!(TokenToRebond::<Runtime>::get(KSM), Some(50));
+		assert_eq!(Tokens::free_balance(VKSM, &BOB), 1050);
+
+		assert_noop!(
+			VtokenMinting::schedule_rebond(Some(BOB).into(), KSM, 1, 100, TimeUnit::Era(1)),
+			Error::<Runtime>::InvalidRebondSchedule
+		);
+		assert_noop!(
+			VtokenMinting::schedule_rebond(Some(ALICE).into(), KSM, 1, 100, TimeUnit::Era(2)),
+			Error::<Runtime>::CanNotRebond
+		);
+		assert_noop!(
+			VtokenMinting::schedule_rebond(Some(BOB).into(), KSM, 1, 0, TimeUnit::Era(2)),
+			Error::<Runtime>::RebondAmountZero
+		);
+		assert_ok!(VtokenMinting::schedule_rebond(Some(BOB).into(), KSM, 1, 100, TimeUnit::Era(2)));
+		assert_ok!(VtokenMinting::schedule_rebond(Some(BOB).into(), KSM, 0, 150, TimeUnit::Era(2)));
+		assert_ok!(VtokenMinting::cancel_scheduled_rebond(
+			Some(BOB).into(),
+			KSM,
+			0,
+			TimeUnit::Era(2)
+		));
+		assert_noop!(
+			VtokenMinting::cancel_scheduled_rebond(Some(BOB).into(), KSM, 0, TimeUnit::Era(2)),
+			Error::<Runtime>::ScheduledRebondNotFound
+		);
+		assert_ok!(VtokenMinting::schedule_rebond(Some(BOB).into(), KSM, 0, 150, TimeUnit::Era(2)));
+		// The scheduled amount is capped by what is left in the unlock record.
+		assert_ok!(VtokenMinting::partial_rebond_by_unlock_id(Some(BOB).into(), KSM, 0, 30));
+
+		// Nothing is rebonded before the scheduled time unit.
+		assert_ok!(VtokenMinting::set_hook_iteration_limit(RuntimeOrigin::signed(ALICE), 10));
+		VtokenMinting::on_initialize(1);
+		assert_eq!(ScheduledRebonds::<Runtime>::get(TimeUnit::Era(2), KSM).unwrap().len(), 2);
+		assert_eq!(UnlockingTotal::<Runtime>::get(KSM), 220);
+
+		assert_eq!(ScheduledRebondTimeUnit::<Runtime>::get(KSM), Some(TimeUnit::Era(2)));
+
+		// The rebonds are still executed when the ongoing time unit skips the scheduled one.
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(3)));
+		VtokenMinting::on_initialize(2);
+		assert_eq!(ScheduledRebonds::<Runtime>::get(TimeUnit::Era(2), KSM), None);
+		assert_eq!(ScheduledRebondTimeUnit::<Runtime>::get(KSM), Some(TimeUnit::Era(3)));
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 0), None);
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 1), None);
+		assert_eq!(UserUnlockLedger::<Runtime>::get(BOB, KSM), None);
+		assert_eq!(TimeUnitUnlockLedger::<Runtime>::get(TimeUnit::Era(3), KSM), None);
+		assert_eq!(UnlockingTotal::<Runtime>::get(KSM), 0);
+		assert_eq!(TokenToRebond::<Runtime>::get(KSM), Some(300));
+		assert_eq!(Tokens::free_balance(VKSM, &BOB), 1300);
+	});
+}
+
+#[test]
+fn schedule_rebond_replaces_duplicate() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(2)
+		));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 300, BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 200));
+		assert_ok!(VtokenMinting::add_support_rebond_token(RuntimeOrigin::signed(ALICE), KSM));
+
+		// Scheduling the same unlock_id again does not grow the shared queue of the time unit.
+		for amount in 1..=MaximumUnlockIdOfTimeUnit::get() + 1 {
+			assert_ok!(VtokenMinting::schedule_rebond(
+				Some(BOB).into(),
+				KSM,
+				0,
+				(amount % 200 + 1).into(),
+				TimeUnit::Era(2)
+			));
+		}
+		assert_ok!(VtokenMinting::schedule_rebond(Some(BOB).into(), KSM, 0, 150, TimeUnit::Era(2)));
+		assert_eq!(
+			ScheduledRebonds::<Runtime>::get(TimeUnit::Era(2), KSM),
+			Some(BoundedVec::try_from(vec![(BOB, 0, 150)]).unwrap())
+		);
+
+		// The same unlock_id can still be scheduled for another time unit.
+		assert_ok!(VtokenMinting::schedule_rebond(Some(BOB).into(), KSM, 0, 50, TimeUnit::Era(3)));
+		assert_eq!(
+			ScheduledRebonds::<Runtime>::get(TimeUnit::Era(3), KSM),
+			Some(BoundedVec::try_from(vec![(BOB, 0, 50)]).unwrap())
+		);
+	});
+}
+
+#[test]
+fn transfer_unlock_receipt() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		assert_ok!(VtokenMinting::set_hook_iteration_limit(RuntimeOrigin::signed(ALICE), 10));
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(0)
+		));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 200, BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 100, BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 200));
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 100));
+
+		assert_noop!(
+			VtokenMinting::transfer_unlock_receipt(Some(CHARLIE).into(), KSM, 0, ALICE),
+			Error::<Runtime>::NotUnlockReceiptHolder
+		);
+		assert_noop!(
+			VtokenMinting::transfer_unlock_receipt(Some(BOB).into(), KSM, 2, CHARLIE),
+			Error::<Runtime>::TokenUnlockLedgerNotFound
+		);
+		assert_ok!(VtokenMinting::transfer_unlock_receipt(Some(BOB).into(), KSM, 0, CHARLIE));
+		// The receipt only moves once it is accepted.
+		assert_eq!(UnlockReceiptOffers::<Runtime>::get(KSM, 0), Some((BOB, CHARLIE)));
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 0),
+			Some((BOB, 200, TimeUnit::Era(1), RedeemType::Native))
+		);
+		assert_eq!(UserUnlockLedger::<Runtime>::get(CHARLIE, KSM), None);
+		assert_noop!(
+			VtokenMinting::accept_unlock_receipt(Some(ALICE).into(), KSM, 0),
+			Error::<Runtime>::NoUnlockReceiptOffer
+		);
+		assert_noop!(
+			VtokenMinting::accept_unlock_receipt(Some(CHARLIE).into(), KSM, 1),
+			Error::<Runtime>::NoUnlockReceiptOffer
+		);
+		assert_ok!(VtokenMinting::accept_unlock_receipt(Some(CHARLIE).into(), KSM, 0));
+		assert_eq!(UnlockReceiptOffers::<Runtime>::get(KSM, 0), None);
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 0),
+			Some((CHARLIE, 200, TimeUnit::Era(1), RedeemType::Native))
+		);
+		assert_eq!(
+			UserUnlockLedger::<Runtime>::get(BOB, KSM),
+			Some((100, BoundedVec::try_from(vec![1]).unwrap()))
+		);
+		assert_eq!(
+			UserUnlockLedger::<Runtime>::get(CHARLIE, KSM),
+			Some((200, BoundedVec::try_from(vec![0]).unwrap()))
+		);
+		assert_eq!(
+			<VtokenMinting as nonfungibles::Inspect<AccountId>>::owner(&KSM, &0),
+			Some(CHARLIE)
+		);
+		// The time unit ledger and the unlocking total are not affected.
+		assert_eq!(
+			TimeUnitUnlockLedger::<Runtime>::get(TimeUnit::Era(1), KSM),
+			Some((300, BoundedVec::try_from(vec![0, 1]).unwrap(), KSM))
+		);
+		assert_eq!(UnlockingTotal::<Runtime>::get(KSM), 300);
+
+		// The holder of the receipt is paid out.
+		VtokenMinting::on_initialize(100);
+		VtokenMinting::on_initialize(100);
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 200);
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 0), None);
+		assert_eq!(UserUnlockLedger::<Runtime>::get(CHARLIE, KSM), None);
+		assert_eq!(UserUnlockLedger::<Runtime>::get(BOB, KSM), None);
+		assert_eq!(UnlockingTotal::<Runtime>::get(KSM), 0);
+	});
+}
+
+#[test]
+fn unlock_receipt_offer_does_not_fill_recipient() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		assert_ok!(VtokenMinting::set_hook_iteration_limit(RuntimeOrigin::signed(ALICE), 10));
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(0)
+		));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 200, BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 100));
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 100));
+
+		// The user unlock ledger of CHARLIE is full.
+		let full_ledger: BoundedVec<UnlockId, MaximumUnlockIdOfUser> =
+			BoundedVec::truncate_from((100..100 + MaximumUnlockIdOfUser::get()).collect());
+		UserUnlockLedger::<Runtime>::insert(CHARLIE, KSM, (1000, full_ledger.clone()));
+
+		// Offering receipts to a full account leaves its ledger untouched.
+		assert_ok!(VtokenMinting::transfer_unlock_receipt(Some(BOB).into(), KSM, 0, CHARLIE));
+		assert_ok!(VtokenMinting::transfer_unlock_receipt(Some(BOB).into(), KSM, 1, CHARLIE));
+		assert_eq!(UserUnlockLedger::<Runtime>::get(CHARLIE, KSM), Some((1000, full_ledger)));
+
+		// The recipient can not accept more receipts than its ledger holds.
+		assert_noop!(
+			VtokenMinting::accept_unlock_receipt(Some(CHARLIE).into(), KSM, 0),
+			Error::<Runtime>::TooManyRedeems
+		);
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 0),
+			Some((BOB, 100, TimeUnit::Era(1), RedeemType::Native))
+		);
+		assert_eq!(
+			UserUnlockLedger::<Runtime>::get(BOB, KSM),
+			Some((200, BoundedVec::try_from(vec![0, 1]).unwrap()))
+		);
+
+		// Once the record is paid out to its holder, the pending offer is dropped.
+		VtokenMinting::on_initialize(100);
+		VtokenMinting::on_initialize(100);
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 0), None);
+		assert_eq!(UnlockReceiptOffers::<Runtime>::get(KSM, 0), None);
+		assert_eq!(UnlockReceiptOffers::<Runtime>::get(KSM, 1), None);
+	});
+}
+
+#[test]
+fn instant_redeem() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let buffer_account = VtokenMinting::instant_redeem_buffer_account();
+		let (entrance_account, _exit_account) = VtokenMinting::get_entrance_and_exit_accounts();
+		assert_noop!(
+			VtokenMinting::set_instant_redeem_config(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				Some(InstantRedeemConfig {
+					min_fee: Permill::from_percent(11),
+					max_fee: Permill::from_percent(1)
+				})
+			),
+			Error::<Runtime>::InvalidInstantRedeemConfig
+		);
+		assert_noop!(
+			VtokenMinting::instant_redeem(Some(BOB).into(), VKSM, 400),
+			Error::<Runtime>::InstantRedeemNotSupported
+		);
+		assert_ok!(VtokenMinting::set_instant_redeem_config(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(InstantRedeemConfig {
+				min_fee: Permill::from_percent(1),
+				max_fee: Permill::from_percent(11)
+			})
+		));
+		assert_ok!(Tokens::transfer(Some(BOB).into(), buffer_account.clone(), KSM, 1000));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 1000, BoundedVec::default(), None));
+		let bob_balance = Tokens::free_balance(KSM, &BOB);
+
+		// 40% of the buffer is used, the fee rate is 1% + 10% * 40% = 5%.
+		assert_ok!(VtokenMinting::instant_redeem(Some(BOB).into(), VKSM, 400));
+		assert_eq!(Tokens::free_balance(KSM, &BOB), bob_balance + 380);
+		assert_eq!(Tokens::free_balance(KSM, &buffer_account), 620);
+		assert_eq!(Tokens::free_balance(VKSM, &BOB), 1600);
+		assert_eq!(InstantRedeemDebt::<Runtime>::get(KSM), 400);
+		assert_eq!(TokenPool::<Runtime>::get(KSM), 1600);
+		assert_eq!(UnlockingTotal::<Runtime>::get(KSM), 0);
+		assert_noop!(
+			VtokenMinting::instant_redeem(Some(BOB).into(), VKSM, 700),
+			Error::<Runtime>::NotEnoughBufferLiquidity
+		);
+
+		// Minting repays the buffer before the rest goes to the entrance account.
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 100, BoundedVec::default(), None));
+		assert_eq!(Tokens::free_balance(KSM, &buffer_account), 720);
+		assert_eq!(Tokens::free_balance(KSM, &entrance_account), 1000);
+		assert_eq!(InstantRedeemDebt::<Runtime>::get(KSM), 300);
+		assert_eq!(TokenPool::<Runtime>::get(KSM), 1700);
+
+		// The rest of the debt is repaid through the unlock flow.
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(1)
+		));
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_noop!(
+			VtokenMinting::unlock_instant_redeem_debt(RuntimeOrigin::signed(ALICE), KSM, 301),
+			Error::<Runtime>::ExceedInstantRedeemDebt
+		);
+		assert_ok!(VtokenMinting::unlock_instant_redeem_debt(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			300
+		));
+		assert_eq!(InstantRedeemDebt::<Runtime>::get(KSM), 0);
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 0),
+			Some((buffer_account, 300, TimeUnit::Era(2), RedeemType::Native))
+		);
+		assert_eq!(UnlockingTotal::<Runtime>::get(KSM), 300);
+		assert_eq!(TokenPool::<Runtime>::get(KSM), 1700);
+	});
+}
+
+#[test]
+fn rebond_refills_instant_redeem_buffer() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let buffer_account = VtokenMinting::instant_redeem_buffer_account();
+		pub const FEE: Permill = Permill::from_percent(0);
+		assert_ok!(VtokenMinting::set_fees(RuntimeOrigin::root(), FEE, FEE));
+		assert_ok!(VtokenMinting::set_instant_redeem_config(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(InstantRedeemConfig {
+				min_fee: Permill::from_percent(1),
+				max_fee: Permill::from_percent(11)
+			})
+		));
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(1)
+		));
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::add_support_rebond_token(RuntimeOrigin::signed(ALICE), KSM));
+		assert_ok!(Tokens::transfer(Some(BOB).into(), buffer_account.clone(), KSM, 1000));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 1000, BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::instant_redeem(Some(BOB).into(), VKSM, 400));
+		assert_eq!(InstantRedeemDebt::<Runtime>::get(KSM), 400);
+
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 200));
+		assert_eq!(UnlockingTotal::<Runtime>::get(KSM), 200);
+
+		// The rebonded tokens are unlocked to the buffer instead of being restaked.
+		assert_ok!(VtokenMinting::rebond(Some(BOB).into(), KSM, 150));
+		assert_eq!(InstantRedeemDebt::<Runtime>::get(KSM), 250);
+		assert_eq!(TokenToRebond::<Runtime>::get(KSM), Some(0));
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 1),
+			Some((buffer_account, 150, TimeUnit::Era(2), RedeemType::Native))
+		);
+		assert_eq!(UnlockingTotal::<Runtime>::get(KSM), 200);
+	});
+}
+
 #[test]
 fn fast_redeem_for_fil() {
 	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
@@ -731,3 +1109,354 @@ fn set_vtoken_incentive_lock_blocks_should_work() {
 		assert_eq!(MintWithLockBlocks::<Runtime>::get(VKSM), None);
 	})
 }
+
+#[test]
+fn exchange_rate_history() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		// The snapshot taken earlier in the same block is replaced.
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_eq!(
+			VtokenMinting::get_exchange_rate_history(VKSM),
+			vec![ExchangeRateSnapshot {
+				block_number: 1,
+				time_unit: TimeUnit::Era(1),
+				exchange_rate: FixedU128::from_rational(1, 1),
+			}]
+		);
+		assert_eq!(VtokenMinting::get_annualised_yield(VKSM, 100, 1000), None);
+
+		System::set_block_number(11);
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 10));
+		System::set_block_number(21);
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 10));
+		// 2% in 20 blocks, with 1000 blocks a year.
+		assert_eq!(
+			VtokenMinting::get_annualised_yield(VKSM, 20, 1000),
+			Some(FixedI128::from_rational(1, 1))
+		);
+
+		// The oldest snapshot is dropped when the history is full.
+		System::set_block_number(31);
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 10));
+		let history = VtokenMinting::get_exchange_rate_history(VKSM);
+		assert_eq!(history.len(), 3);
+		assert_eq!(history[0].block_number, 11);
+		assert_eq!(history[0].exchange_rate, FixedU128::from_rational(101, 100));
+		assert_eq!(history[2].exchange_rate, FixedU128::from_rational(103, 100));
+		// The snapshot of block 31 overwrote the one of block 1 in the first slot.
+		assert_eq!(ExchangeRateHistoryCursor::<Runtime>::get(VKSM), (1, 3));
+		assert_eq!(ExchangeRateHistory::<Runtime>::get(VKSM, 0).unwrap().block_number, 31);
+
+		System::set_block_number(41);
+		assert_ok!(VtokenMinting::decrease_token_pool(KSM, 30));
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(2)));
+		assert!(VtokenMinting::get_annualised_yield(VKSM, 10, 1000).unwrap().is_negative());
+	})
+}
+
+#[test]
+fn currency_fees_and_redeem_fee_discount() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		let fee_account: AccountId = <Runtime as Config>::FeeAccount::get();
+		assert_ok!(VtokenMinting::set_fees(
+			RuntimeOrigin::root(),
+			Permill::from_percent(1),
+			Permill::from_percent(1)
+		));
+		assert_noop!(
+			VtokenMinting::set_currency_fees(RuntimeOrigin::signed(ALICE), KSM, None),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(VtokenMinting::set_currency_fees(
+			RuntimeOrigin::root(),
+			KSM,
+			Some((Permill::from_percent(10), Permill::from_percent(20)))
+		));
+		assert_eq!(
+			VtokenMinting::fees_of(KSM),
+			(Permill::from_percent(10), Permill::from_percent(20))
+		);
+		assert_eq!(
+			VtokenMinting::fees_of(MOVR),
+			(Permill::from_percent(1), Permill::from_percent(1))
+		);
+
+		assert_noop!(
+			VtokenMinting::set_redeem_fee_discount(
+				RuntimeOrigin::root(),
+				KSM,
+				Some(FeeDiscountConfig {
+					holding_tiers: BoundedVec::truncate_from(vec![
+						(20, Permill::from_percent(50)),
+						(10, Permill::from_percent(20))
+					]),
+					bb_bnc_tiers: BoundedVec::default(),
+				})
+			),
+			Error::<Runtime>::InvalidFeeDiscountConfig
+		);
+		assert_ok!(VtokenMinting::set_redeem_fee_discount(
+			RuntimeOrigin::root(),
+			KSM,
+			Some(FeeDiscountConfig {
+				holding_tiers: BoundedVec::truncate_from(vec![
+					(10, Permill::from_percent(50)),
+					(20, Permill::from_percent(60))
+				]),
+				bb_bnc_tiers: BoundedVec::truncate_from(vec![(1, Permill::from_percent(100))]),
+			})
+		));
+
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(1)
+		));
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 1000, BoundedVec::default(), None));
+		assert_eq!(Tokens::free_balance(KSM, &fee_account), 100);
+		assert_eq!(Tokens::free_balance(VKSM, &BOB), 1900);
+		assert_eq!(VtokenHoldingSince::<Runtime>::get(BOB, VKSM), Some((1, 900)));
+
+		// BOB has no bbBNC, and has not held VKSM long enough for a discount.
+		System::set_block_number(5);
+		assert_eq!(VtokenMinting::redeem_fee_rate(&BOB, KSM, VKSM, 100), Permill::from_percent(20));
+
+		System::set_block_number(11);
+		assert_eq!(VtokenMinting::redeem_fee_rate(&BOB, KSM, VKSM, 100), Permill::from_percent(10));
+		// The VKSM BOB did not mint itself gets no holding discount.
+		assert_eq!(
+			VtokenMinting::redeem_fee_rate(&BOB, KSM, VKSM, 1800),
+			Permill::from_percent(15)
+		);
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 100));
+		assert_eq!(Tokens::free_balance(VKSM, &fee_account), 10);
+		assert_eq!(VtokenHoldingSince::<Runtime>::get(BOB, VKSM), Some((1, 800)));
+
+		// The holding start moves towards the current block by the share minted.
+		System::set_block_number(21);
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 1000, BoundedVec::default(), None));
+		assert_eq!(Tokens::free_balance(VKSM, &BOB), 2700);
+		assert_eq!(VtokenHoldingSince::<Runtime>::get(BOB, VKSM), Some((11, 1700)));
+
+		// The minted amount is capped by what BOB still holds after a transfer.
+		assert_ok!(Tokens::transfer(Some(BOB).into(), ALICE, VKSM, 2000));
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 100));
+		assert_eq!(VtokenHoldingSince::<Runtime>::get(BOB, VKSM), Some((11, 600)));
+	})
+}
+
+#[test]
+fn redeem_queue_cap_and_pro_rata() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		let (entrance_account, _exit_account) = VtokenMinting::get_entrance_and_exit_accounts();
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(1)
+		));
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(VtokenMinting::set_hook_iteration_limit(RuntimeOrigin::signed(ALICE), 10));
+		assert_noop!(
+			VtokenMinting::set_redeem_queue_config(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				Some(RedeemQueueConfig { time_unit_cap: 0, max_rollover: 1, pro_rata: true })
+			),
+			Error::<Runtime>::InvalidRedeemQueueConfig
+		);
+		assert_ok!(VtokenMinting::set_redeem_queue_config(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(RedeemQueueConfig { time_unit_cap: 100, max_rollover: 1, pro_rata: true })
+		));
+
+		assert_ok!(Tokens::transfer(Some(BOB).into(), CHARLIE, VKSM, 100));
+		assert_ok!(VtokenMinting::redeem(Some(CHARLIE).into(), VKSM, 40));
+		// The part of the redeem beyond the cap of Era(2) rolls over to Era(3).
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 100));
+		System::assert_has_event(RuntimeEvent::VtokenMinting(Event::RedeemRolledOver {
+			redeemer: BOB,
+			currency_id: KSM,
+			currency_amount: 40,
+			unlock_id: 2,
+			time_unit: TimeUnit::Era(3),
+		}));
+		assert_eq!(
+			TimeUnitUnlockLedger::<Runtime>::get(TimeUnit::Era(2), KSM),
+			Some((100, BoundedVec::try_from(vec![0, 1]).unwrap(), KSM))
+		);
+		assert_eq!(
+			TimeUnitUnlockLedger::<Runtime>::get(TimeUnit::Era(3), KSM),
+			Some((40, BoundedVec::try_from(vec![2]).unwrap(), KSM))
+		);
+		assert_eq!(
+			UserUnlockLedger::<Runtime>::get(BOB, KSM),
+			Some((100, BoundedVec::try_from(vec![1, 2]).unwrap()))
+		);
+		// Once the time units it can roll over to are full, the rest is queued at the last one.
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 200));
+		assert_eq!(
+			TimeUnitUnlockLedger::<Runtime>::get(TimeUnit::Era(3), KSM),
+			Some((240, BoundedVec::try_from(vec![2, 3]).unwrap(), KSM))
+		);
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 3),
+			Some((BOB, 200, TimeUnit::Era(3), RedeemType::Native))
+		);
+
+		// The entrance account pays half of Era(2), each unlock gets half of its amount.
+		assert_ok!(Tokens::transfer(Some(BOB).into(), entrance_account, KSM, 50));
+		assert_ok!(VtokenMinting::set_min_time_unit(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(2)
+		));
+		let bob_balance = Tokens::free_balance(KSM, &BOB);
+		assert_ok!(VtokenMinting::handle_ledger_by_currency(KSM));
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 20);
+		assert_eq!(Tokens::free_balance(KSM, &BOB), bob_balance + 30);
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 0).unwrap().1, 20);
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 1).unwrap().1, 30);
+		assert_eq!(TimeUnitUnlockLedger::<Runtime>::get(TimeUnit::Era(2), KSM).unwrap().0, 50);
+	})
+}
+
+#[test]
+fn mint_and_redeem_with_permit() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		let pair = sr25519::Pair::from_seed(&[7u8; 32]);
+		let owner: AccountId = pair.public().into();
+		let sign = |permit: &Permit<AccountId, CurrencyId, Balance, BlockNumber>| {
+			MultiSignature::from(pair.sign(&VtokenMinting::permit_payload(permit)))
+		};
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(1)
+		));
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(Tokens::transfer(Some(BOB).into(), owner.clone(), KSM, 100));
+
+		let mint_permit = Permit {
+			owner: owner.clone(),
+			action: PermitAction::Mint,
+			currency_id: KSM,
+			max_amount: 100,
+			deadline: 10,
+			nonce: 0,
+			remark: BoundedVec::default(),
+			channel_id: None,
+		};
+		assert_noop!(
+			VtokenMinting::redeem_with_permit(
+				Some(CHARLIE).into(),
+				mint_permit.clone(),
+				sign(&mint_permit),
+				100
+			),
+			Error::<Runtime>::PermitMismatch
+		);
+		assert_noop!(
+			VtokenMinting::mint_with_permit(
+				Some(CHARLIE).into(),
+				mint_permit.clone(),
+				sign(&mint_permit),
+				101
+			),
+			Error::<Runtime>::ExceedPermitAmount
+		);
+		let other_pair = sr25519::Pair::from_seed(&[8u8; 32]);
+		assert_noop!(
+			VtokenMinting::mint_with_permit(
+				Some(CHARLIE).into(),
+				mint_permit.clone(),
+				MultiSignature::from(other_pair.sign(&VtokenMinting::permit_payload(&mint_permit))),
+				100
+			),
+			Error::<Runtime>::InvalidPermitSignature
+		);
+		// The mint channel is signed, a relayer can't redirect it.
+		let redirected_permit = Permit { channel_id: Some(0), ..mint_permit.clone() };
+		assert_noop!(
+			VtokenMinting::mint_with_permit(
+				Some(CHARLIE).into(),
+				redirected_permit,
+				sign(&mint_permit),
+				100
+			),
+			Error::<Runtime>::InvalidPermitSignature
+		);
+
+		// The relayer mints with the owner's token, the vtoken goes to the owner.
+		assert_ok!(VtokenMinting::mint_with_permit(
+			Some(CHARLIE).into(),
+			mint_permit.clone(),
+			sign(&mint_permit),
+			100
+		));
+		System::assert_has_event(RuntimeEvent::VtokenMinting(Event::PermitUsed {
+			owner: owner.clone(),
+			relayer: CHARLIE,
+			action: PermitAction::Mint,
+			currency_id: KSM,
+			amount: 100,
+			nonce: 0,
+		}));
+		assert_eq!(Tokens::free_balance(KSM, &owner), 0);
+		assert_eq!(Tokens::free_balance(VKSM, &owner), 100);
+		assert_eq!(Tokens::free_balance(VKSM, &CHARLIE), 0);
+		assert_eq!(PermitNonces::<Runtime>::get(&owner), 1);
+		assert_noop!(
+			VtokenMinting::mint_with_permit(
+				Some(CHARLIE).into(),
+				mint_permit.clone(),
+				sign(&mint_permit),
+				100
+			),
+			Error::<Runtime>::InvalidPermitNonce
+		);
+
+		let redeem_permit = Permit {
+			owner: owner.clone(),
+			action: PermitAction::Redeem,
+			currency_id: VKSM,
+			max_amount: 50,
+			deadline: 10,
+			nonce: 1,
+			remark: BoundedVec::default(),
+			channel_id: None,
+		};
+		System::set_block_number(11);
+		assert_noop!(
+			VtokenMinting::redeem_with_permit(
+				Some(CHARLIE).into(),
+				redeem_permit.clone(),
+				sign(&redeem_permit),
+				50
+			),
+			Error::<Runtime>::PermitExpired
+		);
+		System::set_block_number(10);
+		assert_ok!(VtokenMinting::redeem_with_permit(
+			Some(CHARLIE).into(),
+			redeem_permit.clone(),
+			sign(&redeem_permit),
+			50
+		));
+		assert_eq!(Tokens::free_balance(VKSM, &owner), 50);
+		assert_eq!(
+			UserUnlockLedger::<Runtime>::get(&owner, KSM),
+			Some((50, BoundedVec::try_from(vec![0]).unwrap()))
+		);
+		assert_eq!(PermitNonces::<Runtime>::get(&owner), 2);
+	})
+}
@@ -68,10 +68,23 @@ pub trait WeightInfo {
 	fn redeem() -> Weight;
 	fn rebond() -> Weight;
 	fn rebond_by_unlock_id() -> Weight;
+	fn partial_rebond_by_unlock_id() -> Weight;
 	fn on_initialize() -> Weight;
 	fn unlock_incentive_minted_vtoken() -> Weight;
 	fn set_incentive_coef() -> Weight;
 	fn set_vtoken_incentive_lock_blocks() -> Weight;
+	fn transfer_unlock_receipt() -> Weight;
+	fn set_instant_redeem_config() -> Weight;
+	fn instant_redeem() -> Weight;
+	fn unlock_instant_redeem_debt() -> Weight;
+	fn schedule_rebond() -> Weight;
+	fn cancel_scheduled_rebond() -> Weight;
+	fn set_currency_fees() -> Weight;
+	fn set_redeem_fee_discount() -> Weight;
+	fn set_redeem_queue_config() -> Weight;
+	fn mint_with_permit() -> Weight;
+	fn redeem_with_permit() -> Weight;
+	fn accept_unlock_receipt() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -199,12 +212,18 @@ impl WeightInfo for () {
 	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
 	/// Storage: VtokenMinting Fees (r:1 w:0)
 	/// Proof: VtokenMinting Fees (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Storage: Tokens Accounts (r:4 w:4)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
 	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	/// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	/// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	/// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2041`
@@ -213,8 +232,18 @@ impl WeightInfo for () {
 		Weight::from_parts(325_143_000, 8769)
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			// `InstantRedeemDebt` and the instant redeem buffer account refilled by the mint, and
+			// `CurrencyFees` and `VtokenHoldingSince`, are not covered by the benchmark run above.
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 
+	/// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	/// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	/// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	/// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
 	fn mint_with_lock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2041`
@@ -223,6 +252,10 @@ impl WeightInfo for () {
 		Weight::from_parts(325_143_000, 8769)
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			// `InstantRedeemDebt` and the instant redeem buffer account refilled by the mint, and
+			// `CurrencyFees` and `VtokenHoldingSince`, are not covered by the benchmark run above.
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 
 	/// Storage: VtokenMinting MinimumRedeem (r:1 w:0)
@@ -251,10 +284,24 @@ impl WeightInfo for () {
 	/// Proof: VtokenMinting TokenUnlockNextId (max_values: None, max_size: Some(26), added: 2501, mode: MaxEncodedLen)
 	/// Storage: VtokenMinting UserUnlockLedger (r:1 w:1)
 	/// Proof: VtokenMinting UserUnlockLedger (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
-	/// Storage: VtokenMinting TimeUnitUnlockLedger (r:1 w:1)
+	/// Storage: VtokenMinting TimeUnitUnlockLedger (r:11 w:11)
 	/// Proof: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: Some(282), added: 2757, mode: MaxEncodedLen)
-	/// Storage: VtokenMinting TokenUnlockLedger (r:0 w:1)
+	/// Storage: VtokenMinting TokenUnlockLedger (r:0 w:11)
 	/// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	/// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting RedeemFeeDiscounts (r:1 w:0)
+	/// Proof Skipped: VtokenMinting RedeemFeeDiscounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	/// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC UserPositions (r:1 w:0)
+	/// Proof Skipped: BbBNC UserPositions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC UserPointEpoch (r:10 w:0)
+	/// Proof Skipped: BbBNC UserPointEpoch (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC UserPointHistory (r:10 w:0)
+	/// Proof Skipped: BbBNC UserPointHistory (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting RedeemQueueConfigs (r:1 w:0)
+	/// Proof Skipped: VtokenMinting RedeemQueueConfigs (max_values: None, max_size: None, mode: Measured)
 	fn redeem() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2187`
@@ -263,17 +310,31 @@ impl WeightInfo for () {
 		Weight::from_parts(377_727_000, 6176)
 			.saturating_add(RocksDbWeight::get().reads(15_u64))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			// `CurrencyFees`, `RedeemFeeDiscounts`, `VtokenHoldingSince`, the bbBNC positions of
+			// the redeemer, `RedeemQueueConfigs` and the unlock ledgers of the
+			// `MAX_TIME_UNIT_ROLLOVER` time units a redeem rolls over to are not covered by the
+			// benchmark run above.
+			.saturating_add(RocksDbWeight::get().reads(35_u64))
+			.saturating_add(RocksDbWeight::get().writes(21_u64))
 	}
 	/// Storage: VtokenMinting TokenToRebond (r:1 w:1)
 	/// Proof: VtokenMinting TokenToRebond (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
-	/// Storage: VtokenMinting UserUnlockLedger (r:1 w:1)
+	/// Storage: VtokenMinting UserUnlockLedger (r:2 w:2)
 	/// Proof: VtokenMinting UserUnlockLedger (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
-	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:1)
+	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:2)
 	/// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
-	/// Storage: VtokenMinting TimeUnitUnlockLedger (r:1 w:1)
+	/// Storage: VtokenMinting TimeUnitUnlockLedger (r:2 w:2)
 	/// Proof: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: Some(282), added: 2757, mode: MaxEncodedLen)
 	/// Storage: VtokenMinting UnlockingTotal (r:1 w:1)
 	/// Proof: VtokenMinting UnlockingTotal (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	/// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	/// Proof Skipped: VtokenMinting OngoingTimeUnit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting UnlockDuration (r:1 w:0)
+	/// Proof Skipped: VtokenMinting UnlockDuration (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting TokenUnlockNextId (r:1 w:1)
+	/// Proof Skipped: VtokenMinting TokenUnlockNextId (max_values: None, max_size: None, mode: Measured)
 	/// Storage: VtokenMinting TokenPool (r:1 w:1)
 	/// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
 	/// Storage: Tokens TotalIssuance (r:1 w:1)
@@ -284,6 +345,10 @@ impl WeightInfo for () {
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
 	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	/// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	/// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
 	fn rebond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2620`
@@ -292,17 +357,30 @@ impl WeightInfo for () {
 		Weight::from_parts(237_749_000, 8769)
 			.saturating_add(RocksDbWeight::get().reads(12_u64))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			// `InstantRedeemDebt`, the unlock record of the instant redeem buffer refilled by the
+			// rebond, and `CurrencyFees` and `VtokenHoldingSince`, are not covered by the benchmark
+			// run above.
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: VtokenMinting TokenToRebond (r:1 w:1)
 	/// Proof: VtokenMinting TokenToRebond (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
-	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:1)
+	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:2)
 	/// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
-	/// Storage: VtokenMinting TimeUnitUnlockLedger (r:1 w:1)
+	/// Storage: VtokenMinting TimeUnitUnlockLedger (r:2 w:2)
 	/// Proof: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: Some(282), added: 2757, mode: MaxEncodedLen)
-	/// Storage: VtokenMinting UserUnlockLedger (r:1 w:1)
+	/// Storage: VtokenMinting UserUnlockLedger (r:2 w:2)
 	/// Proof: VtokenMinting UserUnlockLedger (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
 	/// Storage: VtokenMinting UnlockingTotal (r:1 w:1)
 	/// Proof: VtokenMinting UnlockingTotal (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	/// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	/// Proof Skipped: VtokenMinting OngoingTimeUnit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting UnlockDuration (r:1 w:0)
+	/// Proof Skipped: VtokenMinting UnlockDuration (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting TokenUnlockNextId (r:1 w:1)
+	/// Proof Skipped: VtokenMinting TokenUnlockNextId (max_values: None, max_size: None, mode: Measured)
 	/// Storage: VtokenMinting TokenPool (r:1 w:1)
 	/// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
 	/// Storage: Tokens TotalIssuance (r:1 w:1)
@@ -313,6 +391,10 @@ impl WeightInfo for () {
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
 	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	/// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	/// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
 	fn rebond_by_unlock_id() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2620`
@@ -321,6 +403,55 @@ impl WeightInfo for () {
 		Weight::from_parts(227_544_000, 8769)
 			.saturating_add(RocksDbWeight::get().reads(12_u64))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			// `InstantRedeemDebt`, the unlock record of the instant redeem buffer refilled by the
+			// rebond, and `CurrencyFees` and `VtokenHoldingSince`, are not covered by the benchmark
+			// run above.
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: VtokenMinting TokenToRebond (r:1 w:1)
+	/// Proof: VtokenMinting TokenToRebond (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:2)
+	/// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting TimeUnitUnlockLedger (r:2 w:2)
+	/// Proof: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: Some(282), added: 2757, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting UserUnlockLedger (r:2 w:2)
+	/// Proof: VtokenMinting UserUnlockLedger (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting UnlockingTotal (r:1 w:1)
+	/// Proof: VtokenMinting UnlockingTotal (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	/// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	/// Proof Skipped: VtokenMinting OngoingTimeUnit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting UnlockDuration (r:1 w:0)
+	/// Proof Skipped: VtokenMinting UnlockDuration (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting TokenUnlockNextId (r:1 w:1)
+	/// Proof Skipped: VtokenMinting TokenUnlockNextId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting TokenPool (r:1 w:1)
+	/// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting Fees (r:1 w:0)
+	/// Proof: VtokenMinting Fees (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
+	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	/// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	/// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
+	/// Estimated from the benchmarked `rebond_by_unlock_id`, which goes through the same path
+	/// with the whole unlock record.
+	fn partial_rebond_by_unlock_id() -> Weight {
+		Weight::from_parts(227_544_000, 8769)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			// `InstantRedeemDebt`, the unlock record of the instant redeem buffer refilled by the
+			// rebond, and `CurrencyFees` and `VtokenHoldingSince`, are not covered by the
+			// `rebond_by_unlock_id` benchmark.
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
 	/// Proof: VtokenMinting OngoingTimeUnit (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
@@ -350,4 +481,179 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:0)
+	/// Proof Skipped: VtokenMinting TokenUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting UnlockReceiptOffers (r:0 w:1)
+	/// Proof Skipped: VtokenMinting UnlockReceiptOffers (max_values: None, max_size: None, mode: Measured)
+	/// Estimated from the benchmarked `set_minimum_redeem`, which also reads and writes a single
+	/// record, with the proof of a `TokenUnlockLedger` record.
+	fn transfer_unlock_receipt() -> Weight {
+		Weight::from_parts(46_842_000, 3601)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VtokenMinting InstantRedeemConfigs (r:0 w:1)
+	/// Proof Skipped: VtokenMinting InstantRedeemConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Estimated from the benchmarked `set_fees`, which also sets a single config value.
+	fn set_instant_redeem_config() -> Weight {
+		Weight::from_parts(44_736_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VtokenMinting InstantRedeemConfigs (r:1 w:0)
+	/// Proof Skipped: VtokenMinting InstantRedeemConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	/// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting MinimumRedeem (r:1 w:0)
+	/// Proof Skipped: VtokenMinting MinimumRedeem (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting Fees (r:1 w:0)
+	/// Proof Skipped: VtokenMinting Fees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting TokenPool (r:1 w:1)
+	/// Proof Skipped: VtokenMinting TokenPool (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof Skipped: Tokens TotalIssuance (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof Skipped: Tokens Accounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	/// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting RedeemFeeDiscounts (r:1 w:0)
+	/// Proof Skipped: VtokenMinting RedeemFeeDiscounts (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	/// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC UserPositions (r:1 w:0)
+	/// Proof Skipped: BbBNC UserPositions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC UserPointEpoch (r:10 w:0)
+	/// Proof Skipped: BbBNC UserPointEpoch (max_values: None, max_size: None, mode: Measured)
+	/// Storage: BbBNC UserPointHistory (r:10 w:0)
+	/// Proof Skipped: BbBNC UserPointHistory (max_values: None, max_size: None, mode: Measured)
+	/// Bounded by the benchmarked `redeem`, which takes the fee and burns the vtoken the same
+	/// way and writes the unlock ledgers where an instant redeem pays out of the buffer.
+	fn instant_redeem() -> Weight {
+		Weight::from_parts(377_727_000, 6176)
+			.saturating_add(RocksDbWeight::get().reads(34_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	/// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	/// Proof Skipped: VtokenMinting OngoingTimeUnit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting UnlockDuration (r:1 w:0)
+	/// Proof Skipped: VtokenMinting UnlockDuration (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting TokenUnlockNextId (r:1 w:1)
+	/// Proof Skipped: VtokenMinting TokenUnlockNextId (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:1)
+	/// Proof Skipped: VtokenMinting TokenUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting UserUnlockLedger (r:1 w:1)
+	/// Proof Skipped: VtokenMinting UserUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting TimeUnitUnlockLedger (r:1 w:1)
+	/// Proof Skipped: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting UnlockingTotal (r:1 w:1)
+	/// Proof Skipped: VtokenMinting UnlockingTotal (max_values: None, max_size: None, mode: Measured)
+	/// Bounded by the benchmarked `redeem`, which goes through the same unlock ledger update.
+	fn unlock_instant_redeem_debt() -> Weight {
+		Weight::from_parts(377_727_000, 6176)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: VtokenMinting TokenToRebond (r:1 w:0)
+	/// Proof Skipped: VtokenMinting TokenToRebond (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:0)
+	/// Proof Skipped: VtokenMinting TokenUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	/// Proof Skipped: VtokenMinting OngoingTimeUnit (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting ScheduledRebonds (r:1 w:1)
+	/// Proof Skipped: VtokenMinting ScheduledRebonds (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting ScheduledRebondTimeUnit (r:1 w:1)
+	/// Proof Skipped: VtokenMinting ScheduledRebondTimeUnit (max_values: None, max_size: None, mode: Measured)
+	fn schedule_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2118`
+		//  Estimated: `4236`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 4236)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: VtokenMinting ScheduledRebonds (r:1 w:1)
+	/// Proof Skipped: VtokenMinting ScheduledRebonds (max_values: None, max_size: None, mode: Measured)
+	fn cancel_scheduled_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2118`
+		//  Estimated: `4236`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 4236)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VtokenMinting CurrencyFees (r:0 w:1)
+	/// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	fn set_currency_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VtokenMinting RedeemFeeDiscounts (r:0 w:1)
+	/// Proof Skipped: VtokenMinting RedeemFeeDiscounts (max_values: None, max_size: None, mode: Measured)
+	fn set_redeem_fee_discount() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(0_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VtokenMinting RedeemQueueConfigs (r:0 w:1)
+	/// Proof Skipped: VtokenMinting RedeemQueueConfigs (max_values: None, max_size: None, mode: Measured)
+	fn set_redeem_queue_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VtokenMinting PermitNonces (r:1 w:1)
+	/// Proof Skipped: VtokenMinting PermitNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// The benchmarked `mint`, plus the permit nonce, the genesis hash and the sr25519
+	/// verification of the permit signature, about 48 microseconds in the `sr25519_verification`
+	/// baseline benchmark of frame-benchmarking.
+	fn mint_with_permit() -> Weight {
+		Self::mint()
+			.saturating_add(Weight::from_parts(48_000_000, 3509))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VtokenMinting PermitNonces (r:1 w:1)
+	/// Proof Skipped: VtokenMinting PermitNonces (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// The benchmarked `redeem`, plus the permit nonce, the genesis hash and the sr25519
+	/// verification of the permit signature, about 48 microseconds in the `sr25519_verification`
+	/// baseline benchmark of frame-benchmarking.
+	fn redeem_with_permit() -> Weight {
+		Self::redeem()
+			.saturating_add(Weight::from_parts(48_000_000, 3509))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VtokenMinting UnlockReceiptOffers (r:1 w:1)
+	/// Proof Skipped: VtokenMinting UnlockReceiptOffers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:1)
+	/// Proof Skipped: VtokenMinting TokenUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting UserUnlockLedger (r:2 w:2)
+	/// Proof Skipped: VtokenMinting UserUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	/// Estimated as twice the benchmarked `set_minimum_redeem`, as the receipt is taken out of one
+	/// user unlock ledger and pushed into another, with the proof of the offer, the
+	/// `TokenUnlockLedger` record and both `UserUnlockLedger` records.
+	fn accept_unlock_receipt() -> Weight {
+		Weight::from_parts(93_684_000, 11384)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
@@ -0,0 +1,56 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{CurrencyId, Rate};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_runtime::{FixedU128, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// The position of an account in a single lend-market market.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Serialize, Deserialize)]
+pub struct MarketPosition<Balance> {
+	/// The underlying asset of the market.
+	pub asset_id: CurrencyId,
+	/// The voucher amount held by the account.
+	pub voucher_balance: Balance,
+	/// The underlying amount the vouchers can be redeemed for.
+	pub supply_balance: Balance,
+	/// Whether the deposit is used as collateral.
+	pub is_collateral: bool,
+	/// The borrow balance, including the interest accrued so far.
+	pub borrow_balance: Balance,
+	/// The current supply rate of the market.
+	pub supply_rate: Rate,
+	/// The current borrow rate of the market.
+	pub borrow_rate: Rate,
+}
+
+/// Everything an account holds in the lend-market.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Serialize, Deserialize)]
+pub struct AccountPosition<Balance> {
+	/// The markets in which the account supplies or borrows.
+	pub markets: Vec<MarketPosition<Balance>>,
+	/// The rewards accrued to the account and not yet claimed.
+	pub reward_accrued: Balance,
+	/// The current health factor, `None` if the account has no borrows.
+	pub health_factor: Option<FixedU128>,
+	/// The health factor projected with the current rates and prices, `None` if the account
+	/// has no borrows.
+	pub projected_health_factor: Option<FixedU128>,
+}
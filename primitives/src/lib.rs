@@ -38,6 +38,8 @@ pub use crate::xcm::*;
 pub mod mock_xcm;
 pub use crate::mock_xcm::*;
 
+pub mod lend_market;
+pub use crate::lend_market::*;
 pub mod price;
 pub use crate::price::*;
 pub mod salp;
@@ -46,6 +48,8 @@ pub mod traits;
 pub use crate::traits::*;
 pub mod time_unit;
 pub use crate::time_unit::*;
+pub mod vtoken_minting;
+pub use crate::vtoken_minting::*;
 
 #[cfg(test)]
 mod tests;
@@ -177,6 +181,7 @@ parameter_types! {
 	pub const FlexibleFeePalletId: PalletId = PalletId(*b"bf/flexi");
 	pub const IncentivePoolAccount: PalletId = PalletId(*b"bf/inpoo");
 	pub const IncentivePalletId: PalletId = PalletId(*b"bf/bbict");
+	pub const InstantRedeemBufferPalletId: PalletId = PalletId(*b"bf/vtbuf");
 	pub const LendMarketPalletId: PalletId = PalletId(*b"bf/ldmkt");
 	pub const LighteningRedeemPalletId: PalletId = PalletId(*b"lighten#");
 	pub const LiquidityAccount: PalletId = PalletId(*b"bf/liqdt");
@@ -185,6 +190,7 @@ parameter_types! {
 	pub const MerkleDirtributorPalletId: PalletId = PalletId(*b"bf/mklds");
 	pub const OraclePalletId: PalletId = PalletId(*b"bf/oracl");
 	pub const ParachainStakingPalletId: PalletId = PalletId(*b"bf/stake");
+	pub const SlashInsurancePalletId: PalletId = PalletId(*b"bf/slins");
 	pub const SlpEntrancePalletId: PalletId = PalletId(*b"bf/vtkin");
 	pub const SlpExitPalletId: PalletId = PalletId(*b"bf/vtout");
 	pub const StableAssetPalletId: PalletId = PalletId(*b"nuts/sta");
@@ -32,4 +32,5 @@ pub trait OraclePriceProvider {
 		amount_in: Balance,
 		currency_out: &CurrencyId,
 	) -> Option<(Balance, Price, Price)>;
+	fn is_price_paused(asset_id: &CurrencyId) -> bool;
 }
@@ -20,10 +20,13 @@
 
 use parity_scale_codec::MaxEncodedLen;
 use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_core::{Decode, Encode, RuntimeDebug};
 
 // For vtoken-minting and slp modules
-#[derive(Encode, Decode, Clone, RuntimeDebug, Eq, TypeInfo, MaxEncodedLen)]
+#[derive(
+	Encode, Decode, Clone, RuntimeDebug, Eq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
 pub enum TimeUnit {
 	// Kusama staking time unit
 	Era(#[codec(compact)] u32),
@@ -34,9 +34,9 @@ use bifrost_primitives::{
 	BifrostCrowdloanId, BifrostVsbondAccount, BuybackPalletId, CommissionPalletId,
 	FarmingBoostPalletId, FarmingGaugeRewardIssuerPalletId, FarmingKeeperPalletId,
 	FarmingRewardIssuerPalletId, FeeSharePalletId, FlexibleFeePalletId, IncentivePoolAccount,
-	LendMarketPalletId, MerkleDirtributorPalletId, OraclePalletId, ParachainStakingPalletId,
-	SlpEntrancePalletId, SlpExitPalletId, SystemMakerPalletId, SystemStakingPalletId,
-	TreasuryPalletId, VBNCConvertPalletId,
+	InstantRedeemBufferPalletId, LendMarketPalletId, MerkleDirtributorPalletId, OraclePalletId,
+	ParachainStakingPalletId, SlashInsurancePalletId, SlpEntrancePalletId, SlpExitPalletId,
+	SystemMakerPalletId, SystemStakingPalletId, TreasuryPalletId, VBNCConvertPalletId,
 };
 pub use frame_support::{
 	construct_runtime, match_types, parameter_types,
@@ -83,9 +83,9 @@ pub use bifrost_primitives::{
 		CheckSubAccount, FarmingInfo, VtokenMintingInterface, VtokenMintingOperator,
 		XcmDestWeightAndFeeHandler,
 	},
-	AccountId, Amount, AssetIds, Balance, BlockNumber, CurrencyId, CurrencyIdMapping,
-	DistributionId, Liquidity, Moment, ParaId, PoolId, Price, Rate, Ratio, RpcContributionStatus,
-	Shortfall, TimeUnit, TokenSymbol,
+	AccountId, AccountPosition, Amount, AssetIds, Balance, BlockNumber, CurrencyId,
+	CurrencyIdMapping, DistributionId, Liquidity, Moment, ParaId, PoolId, Price, Rate, Ratio,
+	RpcContributionStatus, Shortfall, TimeUnit, TokenSymbol,
 };
 pub use bifrost_runtime_common::{
 	cent, constants::time::*, dollar, micro, milli, millicent, AuraId, CouncilCollective,
@@ -1229,6 +1229,9 @@ impl bifrost_slp::Config for Runtime {
 	type StablePoolHandler = StablePool;
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = BifrostTreasuryAccount;
+	type OracleSignature = Signature;
+	type OracleSigner = <Signature as Verify>::Signer;
+	type SlashInsurancePalletId = SlashInsurancePalletId;
 }
 
 impl bifrost_vstoken_conversion::Config for Runtime {
@@ -1456,7 +1459,11 @@ impl bifrost_vtoken_minting::Config for Runtime {
 	type MoonbeamChainId = MoonriverChainId;
 	type ChannelCommission = ChannelCommission;
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<256>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = ();
 }
 
@@ -1574,6 +1581,14 @@ impl pallet_prices::Config for Runtime {
 	type RelayCurrency = RelayCurrencyId;
 	type CurrencyIdConvert = AssetIdMaps<Runtime>;
 	type Assets = Currencies;
+	type PriceSources = (
+		pallet_prices::VtokenPriceSource<Runtime, VtokenMinting>,
+		pallet_prices::StablePoolPriceSource<Runtime, StablePool>,
+	);
+	type UnixTime = Timestamp;
+	type MaxAggregatedAssets = ConstU32<20>;
+	type MaxPriceObservations = ConstU32<60>;
+	type MaxCircuitBreakerAssets = ConstU32<20>;
 	type WeightInfo = pallet_prices::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1589,6 +1604,7 @@ impl lend_market::Config for Runtime {
 	type RewardAssetId = NativeCurrencyId;
 	type LiquidationFreeAssetId = RelayCurrencyId;
 	type MaxLengthLimit = MaxLengthLimit;
+	type VtokenMinting = VtokenMinting;
 }
 
 parameter_types! {
@@ -1904,6 +1920,7 @@ pub mod migrations {
 		frame_support::migrations::RemovePallet<VSBondAuctionName, RocksDbWeight>,
 		bifrost_system_staking::migrations::v1::MigrateToV1<Runtime>,
 		lend_market::migrations::v1::MigrateToV1<Runtime>,
+		lend_market::migrations::v2::MigrateToV2<Runtime>,
 	);
 }
 
@@ -1939,6 +1956,7 @@ mod benches {
 		[bifrost_vtoken_voting, VtokenVoting]
 		[lend_market, LendMarket]
 		[leverage_staking, LeverageStaking]
+		[pallet_prices, Prices]
 		[bifrost_vbnc_convert, VBNCConvert]
 		[bifrost_xcm_interface, XcmInterface]
 		// [bifrost_channel_commission, ChannelCommission]
@@ -2236,6 +2254,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl lend_market_rpc_runtime_api::LendMarketApi<Block, AccountId, Balance> for Runtime {
 		fn get_account_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
 			LendMarket::get_account_liquidity(&account)
@@ -2248,8 +2267,13 @@ impl_runtime_apis! {
 		fn get_liquidation_threshold_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
 			LendMarket::get_account_liquidation_threshold_liquidity(&account)
 		}
+
+		fn get_account_position(account: AccountId, blocks_ahead: BlockNumber) -> Result<AccountPosition<Balance>, DispatchError> {
+			LendMarket::get_account_position(&account, SECS_PER_BLOCK.saturating_mul(blocks_ahead.into()))
+		}
 	}
 
+	#[api_version(2)]
 	impl bifrost_vtoken_minting_rpc_runtime_api::VtokenMintingRuntimeApi<Block, CurrencyId, Balance> for Runtime {
 		fn get_currency_amount_by_v_currency_amount(currnecy_id: CurrencyId, v_currency_id: CurrencyId, v_currency_amount: Balance) -> Balance {
 			VtokenMinting::get_currency_amount_by_v_currency_amount(currnecy_id, v_currency_id, v_currency_amount).unwrap_or(0)
@@ -2258,6 +2282,20 @@ impl_runtime_apis! {
 		fn get_v_currency_amount_by_currency_amount(currnecy_id: CurrencyId, v_currency_id: CurrencyId, currency_amount: Balance) -> Balance {
 			VtokenMinting::get_v_currency_amount_by_currency_amount(currnecy_id, v_currency_id, currency_amount).unwrap_or(0)
 		}
+
+		fn get_exchange_rate_history(v_currency_id: CurrencyId) -> Vec<bifrost_primitives::ExchangeRateSnapshot<BlockNumber>> {
+			VtokenMinting::get_exchange_rate_history(v_currency_id)
+		}
+
+		fn get_annualised_yield(v_currency_id: CurrencyId, window: BlockNumber) -> Option<sp_runtime::FixedI128> {
+			VtokenMinting::get_annualised_yield(v_currency_id, window, 365 * DAYS)
+		}
+	}
+
+	impl bifrost_slp_rpc_runtime_api::SlpRuntimeApi<Block, MultiLocation, bifrost_slp::LedgerDivergence<Balance>> for Runtime {
+		fn get_delegator_ledger_divergences(currency_id: CurrencyId) -> Vec<(MultiLocation, bifrost_slp::LedgerDivergence<Balance>)> {
+			Slp::get_delegator_ledger_divergences(currency_id)
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
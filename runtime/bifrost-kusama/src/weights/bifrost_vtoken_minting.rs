@@ -177,12 +177,18 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
 	// Storage: VtokenMinting Fees (r:1 w:0)
 	// Proof: VtokenMinting Fees (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	// Storage: Tokens Accounts (r:3 w:3)
+	// Storage: Tokens Accounts (r:4 w:4)
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
 	// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
 	fn mint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2041`
@@ -191,6 +197,10 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 		Weight::from_parts(207_086_000, 8769)
 			.saturating_add(T::DbWeight::get().reads(9))
 			.saturating_add(T::DbWeight::get().writes(6))
+			// `InstantRedeemDebt` and the instant redeem buffer account refilled by the mint, and
+			// `CurrencyFees` and `VtokenHoldingSince`, are not covered by the benchmark run above.
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: VtokenMinting MinimumRedeem (r:1 w:0)
 	// Proof: VtokenMinting MinimumRedeem (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
@@ -218,10 +228,24 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 	// Proof: VtokenMinting TokenUnlockNextId (max_values: None, max_size: Some(26), added: 2501, mode: MaxEncodedLen)
 	// Storage: VtokenMinting UserUnlockLedger (r:1 w:1)
 	// Proof: VtokenMinting UserUnlockLedger (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
-	// Storage: VtokenMinting TimeUnitUnlockLedger (r:1 w:1)
+	// Storage: VtokenMinting TimeUnitUnlockLedger (r:11 w:11)
 	// Proof: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: Some(282), added: 2757, mode: MaxEncodedLen)
-	// Storage: VtokenMinting TokenUnlockLedger (r:0 w:1)
+	// Storage: VtokenMinting TokenUnlockLedger (r:0 w:11)
 	// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting RedeemFeeDiscounts (r:1 w:0)
+	// Proof Skipped: VtokenMinting RedeemFeeDiscounts (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
+	// Storage: BbBNC UserPositions (r:1 w:0)
+	// Proof Skipped: BbBNC UserPositions (max_values: None, max_size: None, mode: Measured)
+	// Storage: BbBNC UserPointEpoch (r:10 w:0)
+	// Proof Skipped: BbBNC UserPointEpoch (max_values: None, max_size: None, mode: Measured)
+	// Storage: BbBNC UserPointHistory (r:10 w:0)
+	// Proof Skipped: BbBNC UserPointHistory (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting RedeemQueueConfigs (r:1 w:0)
+	// Proof Skipped: VtokenMinting RedeemQueueConfigs (max_values: None, max_size: None, mode: Measured)
 	fn redeem() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2187`
@@ -230,17 +254,31 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 		Weight::from_parts(249_155_000, 6176)
 			.saturating_add(T::DbWeight::get().reads(15))
 			.saturating_add(T::DbWeight::get().writes(10))
+			// `CurrencyFees`, `RedeemFeeDiscounts`, `VtokenHoldingSince`, the bbBNC positions of
+			// the redeemer, `RedeemQueueConfigs` and the unlock ledgers of the
+			// `MAX_TIME_UNIT_ROLLOVER` time units a redeem rolls over to are not covered by the
+			// benchmark run above.
+			.saturating_add(T::DbWeight::get().reads(35))
+			.saturating_add(T::DbWeight::get().writes(21))
 	}
 	// Storage: VtokenMinting TokenToRebond (r:1 w:1)
 	// Proof: VtokenMinting TokenToRebond (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
-	// Storage: VtokenMinting UserUnlockLedger (r:1 w:1)
+	// Storage: VtokenMinting UserUnlockLedger (r:2 w:2)
 	// Proof: VtokenMinting UserUnlockLedger (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
-	// Storage: VtokenMinting TokenUnlockLedger (r:1 w:1)
+	// Storage: VtokenMinting TokenUnlockLedger (r:1 w:2)
 	// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
-	// Storage: VtokenMinting TimeUnitUnlockLedger (r:1 w:1)
+	// Storage: VtokenMinting TimeUnitUnlockLedger (r:2 w:2)
 	// Proof: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: Some(282), added: 2757, mode: MaxEncodedLen)
 	// Storage: VtokenMinting UnlockingTotal (r:1 w:1)
 	// Proof: VtokenMinting UnlockingTotal (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	// Proof Skipped: VtokenMinting OngoingTimeUnit (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting UnlockDuration (r:1 w:0)
+	// Proof Skipped: VtokenMinting UnlockDuration (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting TokenUnlockNextId (r:1 w:1)
+	// Proof Skipped: VtokenMinting TokenUnlockNextId (max_values: None, max_size: None, mode: Measured)
 	// Storage: VtokenMinting TokenPool (r:1 w:1)
 	// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
 	// Storage: Tokens TotalIssuance (r:1 w:1)
@@ -251,6 +289,10 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
 	// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
 	fn rebond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2620`
@@ -259,17 +301,30 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 		Weight::from_parts(235_084_000, 8769)
 			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(10))
+			// `InstantRedeemDebt`, the unlock record of the instant redeem buffer refilled by the
+			// rebond, and `CurrencyFees` and `VtokenHoldingSince`, are not covered by the benchmark
+			// run above.
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	// Storage: VtokenMinting TokenToRebond (r:1 w:1)
 	// Proof: VtokenMinting TokenToRebond (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
-	// Storage: VtokenMinting TokenUnlockLedger (r:1 w:1)
+	// Storage: VtokenMinting TokenUnlockLedger (r:1 w:2)
 	// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
-	// Storage: VtokenMinting TimeUnitUnlockLedger (r:1 w:1)
+	// Storage: VtokenMinting TimeUnitUnlockLedger (r:2 w:2)
 	// Proof: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: Some(282), added: 2757, mode: MaxEncodedLen)
-	// Storage: VtokenMinting UserUnlockLedger (r:1 w:1)
+	// Storage: VtokenMinting UserUnlockLedger (r:2 w:2)
 	// Proof: VtokenMinting UserUnlockLedger (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
 	// Storage: VtokenMinting UnlockingTotal (r:1 w:1)
 	// Proof: VtokenMinting UnlockingTotal (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	// Proof Skipped: VtokenMinting OngoingTimeUnit (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting UnlockDuration (r:1 w:0)
+	// Proof Skipped: VtokenMinting UnlockDuration (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting TokenUnlockNextId (r:1 w:1)
+	// Proof Skipped: VtokenMinting TokenUnlockNextId (max_values: None, max_size: None, mode: Measured)
 	// Storage: VtokenMinting TokenPool (r:1 w:1)
 	// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
 	// Storage: Tokens TotalIssuance (r:1 w:1)
@@ -280,6 +335,10 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
 	// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
 	fn rebond_by_unlock_id() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2620`
@@ -288,6 +347,55 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 		Weight::from_parts(223_798_000, 8769)
 			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(10))
+			// `InstantRedeemDebt`, the unlock record of the instant redeem buffer refilled by the
+			// rebond, and `CurrencyFees` and `VtokenHoldingSince`, are not covered by the benchmark
+			// run above.
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	// Storage: VtokenMinting TokenToRebond (r:1 w:1)
+	// Proof: VtokenMinting TokenToRebond (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: VtokenMinting TokenUnlockLedger (r:1 w:2)
+	// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	// Storage: VtokenMinting TimeUnitUnlockLedger (r:2 w:2)
+	// Proof: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: Some(282), added: 2757, mode: MaxEncodedLen)
+	// Storage: VtokenMinting UserUnlockLedger (r:2 w:2)
+	// Proof: VtokenMinting UserUnlockLedger (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	// Storage: VtokenMinting UnlockingTotal (r:1 w:1)
+	// Proof: VtokenMinting UnlockingTotal (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	// Proof Skipped: VtokenMinting OngoingTimeUnit (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting UnlockDuration (r:1 w:0)
+	// Proof Skipped: VtokenMinting UnlockDuration (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting TokenUnlockNextId (r:1 w:1)
+	// Proof Skipped: VtokenMinting TokenUnlockNextId (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting TokenPool (r:1 w:1)
+	// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: VtokenMinting Fees (r:1 w:0)
+	// Proof: VtokenMinting Fees (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
+	// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
+	// Estimated from the benchmarked `rebond_by_unlock_id`, which goes through the same path
+	// with the whole unlock record.
+	fn partial_rebond_by_unlock_id() -> Weight {
+		Weight::from_parts(223_798_000, 8769)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(10))
+			// `InstantRedeemDebt`, the unlock record of the instant redeem buffer refilled by the
+			// rebond, and `CurrencyFees` and `VtokenHoldingSince`, are not covered by the
+			// `rebond_by_unlock_id` benchmark.
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
 	// Proof: VtokenMinting OngoingTimeUnit (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
@@ -300,10 +408,20 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
 
+	// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
 	fn mint_with_lock() -> Weight {
 		Weight::from_parts(325_143_000, 8769)
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			// `InstantRedeemDebt` and the instant redeem buffer account refilled by the mint, and
+			// `CurrencyFees` and `VtokenHoldingSince`, are not covered by the benchmark run above.
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 
 	fn unlock_incentive_minted_vtoken() -> Weight {
@@ -323,4 +441,177 @@ impl<T: frame_system::Config> bifrost_vtoken_minting::WeightInfo for BifrostWeig
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: VtokenMinting TokenUnlockLedger (r:1 w:0)
+	// Proof Skipped: VtokenMinting TokenUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting UnlockReceiptOffers (r:0 w:1)
+	// Proof Skipped: VtokenMinting UnlockReceiptOffers (max_values: None, max_size: None, mode: Measured)
+	// Estimated from the benchmarked `set_minimum_redeem`, which also reads and writes a single
+	// record, with the proof of a `TokenUnlockLedger` record.
+	fn transfer_unlock_receipt() -> Weight {
+		Weight::from_parts(33_004_000, 3601)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: VtokenMinting InstantRedeemConfigs (r:0 w:1)
+	// Proof Skipped: VtokenMinting InstantRedeemConfigs (max_values: None, max_size: None, mode: Measured)
+	// Estimated from the benchmarked `set_fees`, which also sets a single config value.
+	fn set_instant_redeem_config() -> Weight {
+		Weight::from_parts(31_421_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: VtokenMinting InstantRedeemConfigs (r:1 w:0)
+	// Proof Skipped: VtokenMinting InstantRedeemConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting MinimumRedeem (r:1 w:0)
+	// Proof Skipped: VtokenMinting MinimumRedeem (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting Fees (r:1 w:0)
+	// Proof Skipped: VtokenMinting Fees (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting TokenPool (r:1 w:1)
+	// Proof Skipped: VtokenMinting TokenPool (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof Skipped: Tokens TotalIssuance (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Proof Skipped: Tokens Accounts (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting CurrencyFees (r:1 w:0)
+	// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting RedeemFeeDiscounts (r:1 w:0)
+	// Proof Skipped: VtokenMinting RedeemFeeDiscounts (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting VtokenHoldingSince (r:1 w:1)
+	// Proof Skipped: VtokenMinting VtokenHoldingSince (max_values: None, max_size: None, mode: Measured)
+	// Storage: BbBNC UserPositions (r:1 w:0)
+	// Proof Skipped: BbBNC UserPositions (max_values: None, max_size: None, mode: Measured)
+	// Storage: BbBNC UserPointEpoch (r:10 w:0)
+	// Proof Skipped: BbBNC UserPointEpoch (max_values: None, max_size: None, mode: Measured)
+	// Storage: BbBNC UserPointHistory (r:10 w:0)
+	// Proof Skipped: BbBNC UserPointHistory (max_values: None, max_size: None, mode: Measured)
+	// Bounded by the benchmarked `redeem`, which takes the fee and burns the vtoken the same
+	// way and writes the unlock ledgers where an instant redeem pays out of the buffer.
+	fn instant_redeem() -> Weight {
+		Weight::from_parts(249_155_000, 6176)
+			.saturating_add(T::DbWeight::get().reads(34))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: VtokenMinting InstantRedeemDebt (r:1 w:1)
+	// Proof Skipped: VtokenMinting InstantRedeemDebt (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	// Proof Skipped: VtokenMinting OngoingTimeUnit (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting UnlockDuration (r:1 w:0)
+	// Proof Skipped: VtokenMinting UnlockDuration (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting TokenUnlockNextId (r:1 w:1)
+	// Proof Skipped: VtokenMinting TokenUnlockNextId (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting TokenUnlockLedger (r:1 w:1)
+	// Proof Skipped: VtokenMinting TokenUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting UserUnlockLedger (r:1 w:1)
+	// Proof Skipped: VtokenMinting UserUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting TimeUnitUnlockLedger (r:1 w:1)
+	// Proof Skipped: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting UnlockingTotal (r:1 w:1)
+	// Proof Skipped: VtokenMinting UnlockingTotal (max_values: None, max_size: None, mode: Measured)
+	// Bounded by the benchmarked `redeem`, which goes through the same unlock ledger update.
+	fn unlock_instant_redeem_debt() -> Weight {
+		Weight::from_parts(249_155_000, 6176)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: VtokenMinting TokenToRebond (r:1 w:0)
+	// Proof Skipped: VtokenMinting TokenToRebond (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting TokenUnlockLedger (r:1 w:0)
+	// Proof Skipped: VtokenMinting TokenUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	// Proof Skipped: VtokenMinting OngoingTimeUnit (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting ScheduledRebonds (r:1 w:1)
+	// Proof Skipped: VtokenMinting ScheduledRebonds (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting ScheduledRebondTimeUnit (r:1 w:1)
+	// Proof Skipped: VtokenMinting ScheduledRebondTimeUnit (max_values: None, max_size: None, mode: Measured)
+	fn schedule_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2118`
+		//  Estimated: `4236`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 4236)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: VtokenMinting ScheduledRebonds (r:1 w:1)
+	// Proof Skipped: VtokenMinting ScheduledRebonds (max_values: None, max_size: None, mode: Measured)
+	fn cancel_scheduled_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2118`
+		//  Estimated: `4236`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 4236)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: VtokenMinting CurrencyFees (r:0 w:1)
+	// Proof Skipped: VtokenMinting CurrencyFees (max_values: None, max_size: None, mode: Measured)
+	fn set_currency_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: VtokenMinting RedeemFeeDiscounts (r:0 w:1)
+	// Proof Skipped: VtokenMinting RedeemFeeDiscounts (max_values: None, max_size: None, mode: Measured)
+	fn set_redeem_fee_discount() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: VtokenMinting RedeemQueueConfigs (r:0 w:1)
+	// Proof Skipped: VtokenMinting RedeemQueueConfigs (max_values: None, max_size: None, mode: Measured)
+	fn set_redeem_queue_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: VtokenMinting PermitNonces (r:1 w:1)
+	// Proof Skipped: VtokenMinting PermitNonces (max_values: None, max_size: None, mode: Measured)
+	// Storage: System BlockHash (r:1 w:0)
+	// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	// The benchmarked `mint`, plus the permit nonce, the genesis hash and the sr25519
+	// verification of the permit signature, about 48 microseconds in the `sr25519_verification`
+	// baseline benchmark of frame-benchmarking.
+	fn mint_with_permit() -> Weight {
+		Self::mint()
+			.saturating_add(Weight::from_parts(48_000_000, 3509))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: VtokenMinting PermitNonces (r:1 w:1)
+	// Proof Skipped: VtokenMinting PermitNonces (max_values: None, max_size: None, mode: Measured)
+	// Storage: System BlockHash (r:1 w:0)
+	// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	// The benchmarked `redeem`, plus the permit nonce, the genesis hash and the sr25519
+	// verification of the permit signature, about 48 microseconds in the `sr25519_verification`
+	// baseline benchmark of frame-benchmarking.
+	fn redeem_with_permit() -> Weight {
+		Self::redeem()
+			.saturating_add(Weight::from_parts(48_000_000, 3509))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: VtokenMinting UnlockReceiptOffers (r:1 w:1)
+	// Proof Skipped: VtokenMinting UnlockReceiptOffers (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting TokenUnlockLedger (r:1 w:1)
+	// Proof Skipped: VtokenMinting TokenUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting UserUnlockLedger (r:2 w:2)
+	// Proof Skipped: VtokenMinting UserUnlockLedger (max_values: None, max_size: None, mode: Measured)
+	// Estimated as twice the benchmarked `set_minimum_redeem`, as the receipt is taken out of one
+	// user unlock ledger and pushed into another, with the proof of the offer, the
+	// `TokenUnlockLedger` record and both `UserUnlockLedger` records.
+	fn accept_unlock_receipt() -> Weight {
+		Weight::from_parts(66_008_000, 11384)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
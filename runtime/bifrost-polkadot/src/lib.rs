@@ -34,9 +34,10 @@ use bifrost_primitives::{
 	BifrostCrowdloanId, BifrostVsbondAccount, BuyBackAccount, BuybackPalletId, CloudsPalletId,
 	CommissionPalletId, FarmingBoostPalletId, FarmingGaugeRewardIssuerPalletId,
 	FarmingKeeperPalletId, FarmingRewardIssuerPalletId, FeeSharePalletId, FlexibleFeePalletId,
-	IncentivePalletId, IncentivePoolAccount, LendMarketPalletId, LiquidityAccount,
-	MerkleDirtributorPalletId, OraclePalletId, SlpEntrancePalletId, SlpExitPalletId,
-	SystemMakerPalletId, SystemStakingPalletId, TreasuryPalletId,
+	IncentivePalletId, IncentivePoolAccount, InstantRedeemBufferPalletId, LendMarketPalletId,
+	LiquidityAccount, MerkleDirtributorPalletId, OraclePalletId, SlashInsurancePalletId,
+	SlpEntrancePalletId, SlpExitPalletId, SystemMakerPalletId, SystemStakingPalletId,
+	TreasuryPalletId,
 };
 use cumulus_pallet_parachain_system::{RelayNumberStrictlyIncreases, RelaychainDataProvider};
 pub use frame_support::{
@@ -84,9 +85,9 @@ pub use bifrost_primitives::{
 		CheckSubAccount, FarmingInfo, VtokenMintingInterface, VtokenMintingOperator,
 		XcmDestWeightAndFeeHandler,
 	},
-	AccountId, Amount, AssetIds, Balance, BlockNumber, CurrencyId, CurrencyIdMapping,
-	DistributionId, Liquidity, Moment, Nonce, ParaId, PoolId, Price, Rate, Ratio,
-	RpcContributionStatus, Shortfall, TimeUnit, TokenSymbol, DOT_TOKEN_ID, GLMR_TOKEN_ID,
+	AccountId, AccountPosition, Amount, AssetIds, Balance, BlockNumber, CurrencyId,
+	CurrencyIdMapping, DistributionId, Liquidity, Moment, Nonce, ParaId, PoolId, Price, Rate,
+	Ratio, RpcContributionStatus, Shortfall, TimeUnit, TokenSymbol, DOT_TOKEN_ID, GLMR_TOKEN_ID,
 };
 use bifrost_runtime_common::{
 	constants::time::*, dollar, micro, milli, AuraId, CouncilCollective,
@@ -1108,6 +1109,9 @@ impl bifrost_slp::Config for Runtime {
 	type StablePoolHandler = StablePool;
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = BifrostTreasuryAccount;
+	type OracleSignature = Signature;
+	type OracleSigner = <Signature as Verify>::Signer;
+	type SlashInsurancePalletId = SlashInsurancePalletId;
 }
 
 parameter_types! {
@@ -1342,7 +1346,11 @@ impl bifrost_vtoken_minting::Config for Runtime {
 	type MoonbeamChainId = MoonbeamChainId;
 	type ChannelCommission = ChannelCommission;
 	type MaxLockRecords = ConstU32<100>;
+	type MaxExchangeRateSnapshots = ConstU32<256>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
 	type IncentivePoolAccount = IncentivePoolAccount;
+	type InstantRedeemBufferAccount = InstantRedeemBufferPalletId;
 	type BbBNC = BbBNC;
 }
 
@@ -1437,6 +1445,14 @@ impl pallet_prices::Config for Runtime {
 	type RelayCurrency = RelayCurrencyId;
 	type CurrencyIdConvert = AssetIdMaps<Runtime>;
 	type Assets = Currencies;
+	type PriceSources = (
+		pallet_prices::VtokenPriceSource<Runtime, VtokenMinting>,
+		pallet_prices::StablePoolPriceSource<Runtime, StablePool>,
+	);
+	type UnixTime = Timestamp;
+	type MaxAggregatedAssets = ConstU32<20>;
+	type MaxPriceObservations = ConstU32<60>;
+	type MaxCircuitBreakerAssets = ConstU32<20>;
 	type WeightInfo = pallet_prices::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1452,6 +1468,7 @@ impl lend_market::Config for Runtime {
 	type RewardAssetId = NativeCurrencyId;
 	type LiquidationFreeAssetId = RelayCurrencyId;
 	type MaxLengthLimit = MaxLengthLimit;
+	type VtokenMinting = VtokenMinting;
 }
 
 parameter_types! {
@@ -1834,6 +1851,7 @@ pub mod migrations {
 		frame_support::migrations::RemovePallet<SystemMakerName, RocksDbWeight>,
 		bifrost_system_staking::migrations::v1::MigrateToV1<Runtime>,
 		lend_market::migrations::v1::MigrateToV1<Runtime>,
+		lend_market::migrations::v2::MigrateToV2<Runtime>,
 	);
 }
 
@@ -2423,6 +2441,7 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		}
 	}
 
+	#[api_version(2)]
 	impl lend_market_rpc_runtime_api::LendMarketApi<Block, AccountId, Balance> for Runtime {
 		fn get_account_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
 			LendMarket::get_account_liquidity(&account)
@@ -2435,6 +2454,10 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		fn get_liquidation_threshold_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
 			LendMarket::get_account_liquidation_threshold_liquidity(&account)
 		}
+
+		fn get_account_position(account: AccountId, blocks_ahead: BlockNumber) -> Result<AccountPosition<Balance>, DispatchError> {
+			LendMarket::get_account_position(&account, SECS_PER_BLOCK.saturating_mul(blocks_ahead.into()))
+		}
 	}
 
 	impl bifrost_stable_pool_rpc_runtime_api::StablePoolRuntimeApi<Block> for Runtime {
@@ -2455,6 +2478,7 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		}
 	}
 
+	#[api_version(2)]
 	impl bifrost_vtoken_minting_rpc_runtime_api::VtokenMintingRuntimeApi<Block, CurrencyId, Balance> for Runtime {
 		fn get_currency_amount_by_v_currency_amount(currnecy_id: CurrencyId, v_currency_id: CurrencyId, v_currency_amount: Balance) -> Balance {
 			VtokenMinting::get_currency_amount_by_v_currency_amount(currnecy_id, v_currency_id, v_currency_amount).unwrap_or(0)
@@ -2463,6 +2487,20 @@ impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		fn get_v_currency_amount_by_currency_amount(currnecy_id: CurrencyId, v_currency_id: CurrencyId, currency_amount: Balance) -> Balance {
 			VtokenMinting::get_v_currency_amount_by_currency_amount(currnecy_id, v_currency_id, currency_amount).unwrap_or(0)
 		}
+
+		fn get_exchange_rate_history(v_currency_id: CurrencyId) -> Vec<bifrost_primitives::ExchangeRateSnapshot<BlockNumber>> {
+			VtokenMinting::get_exchange_rate_history(v_currency_id)
+		}
+
+		fn get_annualised_yield(v_currency_id: CurrencyId, window: BlockNumber) -> Option<sp_runtime::FixedI128> {
+			VtokenMinting::get_annualised_yield(v_currency_id, window, 365 * DAYS)
+		}
+	}
+
+	impl bifrost_slp_rpc_runtime_api::SlpRuntimeApi<Block, MultiLocation, bifrost_slp::LedgerDivergence<Balance>> for Runtime {
+		fn get_delegator_ledger_divergences(currency_id: CurrencyId) -> Vec<(MultiLocation, bifrost_slp::LedgerDivergence<Balance>)> {
+			Slp::get_delegator_ledger_divergences(currency_id)
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -89,14 +89,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp DelegatorLedgerXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
 	// Storage: PolkadotXcm Queries (r:0 w:1)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn bond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `629`
 		//  Estimated: `4094`
 		// Minimum execution time: 104_135 nanoseconds.
 		Weight::from_parts(105_944_000, 4094)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	// Storage: Slp DelegatorLedgers (r:1 w:0)
 	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
@@ -114,14 +118,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp DelegatorLedgerXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
 	// Storage: PolkadotXcm Queries (r:0 w:1)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn bond_extra() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `896`
 		//  Estimated: `4361`
 		// Minimum execution time: 105_850 nanoseconds.
 		Weight::from_parts(109_005_000, 4361)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Slp DelegatorLedgers (r:1 w:0)
 	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
@@ -143,14 +151,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp DelegatorLedgerXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
 	// Storage: PolkadotXcm Queries (r:0 w:1)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn unbond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1028`
 		//  Estimated: `4493`
 		// Minimum execution time: 128_505 nanoseconds.
 		Weight::from_parts(130_525_000, 4493)
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Slp DelegatorLedgers (r:1 w:0)
 	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
@@ -170,14 +182,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp DelegatorLedgerXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
 	// Storage: PolkadotXcm Queries (r:0 w:1)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn unbond_all() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1006`
 		//  Estimated: `4471`
 		// Minimum execution time: 120_242 nanoseconds.
 		Weight::from_parts(124_199_000, 4471)
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Slp DelegatorLedgers (r:1 w:0)
 	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
@@ -195,14 +211,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp DelegatorLedgerXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
 	// Storage: PolkadotXcm Queries (r:0 w:1)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn rebond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `941`
 		//  Estimated: `4406`
 		// Minimum execution time: 106_179 nanoseconds.
 		Weight::from_parts(108_122_000, 4406)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Slp DelegatorLedgers (r:1 w:0)
 	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
@@ -222,14 +242,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp ValidatorsByDelegatorXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
 	// Storage: PolkadotXcm Queries (r:0 w:1)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `963`
 		//  Estimated: `4428`
 		// Minimum execution time: 120_559 nanoseconds.
 		Weight::from_parts(123_430_000, 4428)
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Slp DelegatorLedgers (r:1 w:0)
 	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
@@ -247,14 +271,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp ValidatorsByDelegatorXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
 	// Storage: PolkadotXcm Queries (r:0 w:1)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn undelegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1031`
 		//  Estimated: `4496`
 		// Minimum execution time: 111_483 nanoseconds.
 		Weight::from_parts(113_771_000, 4496)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Slp DelegatorLedgers (r:1 w:0)
 	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
@@ -274,14 +302,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp ValidatorsByDelegatorXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
 	// Storage: PolkadotXcm Queries (r:0 w:1)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn redelegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `963`
 		//  Estimated: `4428`
 		// Minimum execution time: 121_646 nanoseconds.
 		Weight::from_parts(123_838_000, 4428)
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Slp DelegatorsMultilocation2Index (r:1 w:0)
 	// Proof Skipped: Slp DelegatorsMultilocation2Index (max_values: None, max_size: None, mode: Measured)
@@ -338,14 +370,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp DelegatorLedgerXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
 	// Storage: PolkadotXcm Queries (r:0 w:1)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn chill() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1006`
 		//  Estimated: `4471`
 		// Minimum execution time: 127_921 nanoseconds.
 		Weight::from_parts(129_570_000, 4471)
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Slp DelegatorsMultilocation2Index (r:1 w:0)
 	// Proof Skipped: Slp DelegatorsMultilocation2Index (max_values: None, max_size: None, mode: Measured)
@@ -353,25 +389,35 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	// Storage: XcmInterface XcmWeightAndFee (r:1 w:0)
 	// Proof Skipped: XcmInterface XcmWeightAndFee (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn transfer_back() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `401`
 		//  Estimated: `3866`
 		// Minimum execution time: 74_216 nanoseconds.
 		Weight::from_parts(75_781_000, 3866)
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	// Storage: Slp DelegatorsMultilocation2Index (r:1 w:0)
 	// Proof Skipped: Slp DelegatorsMultilocation2Index (max_values: None, max_size: None, mode: Measured)
 	// Storage: XcmInterface XcmWeightAndFee (r:1 w:0)
 	// Proof Skipped: XcmInterface XcmWeightAndFee (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn transfer_to() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `298`
 		//  Estimated: `3763`
 		// Minimum execution time: 55_326 nanoseconds.
 		Weight::from_parts(57_260_000, 3763)
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	// Storage: Slp DelegatorLedgers (r:1 w:0)
 	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
@@ -385,14 +431,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof: ParachainInfo ParachainId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	// Storage: PolkadotXcm Queries (r:0 w:1)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
 	fn convert_asset() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `697`
 		//  Estimated: `4162`
 		// Minimum execution time: 87_626 nanoseconds.
 		Weight::from_parts(89_702_000, 4162)
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: VtokenMinting TokenPool (r:1 w:1)
 	// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
@@ -488,14 +538,18 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp DelegatorLatestTuneRecord (max_values: None, max_size: None, mode: Measured)
 	// Storage: Slp DelegatorLedgers (r:1 w:1)
 	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp FilecoinOracleSets (r:1 w:0)
+	// Proof Skipped: Slp FilecoinOracleSets (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp FilecoinVerifiedRewards (r:1 w:1)
+	// Proof Skipped: Slp FilecoinVerifiedRewards (max_values: None, max_size: None, mode: Measured)
 	fn charge_host_fee_and_tune_vtoken_exchange_rate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2097`
 		//  Estimated: `5562`
 		// Minimum execution time: 215_456 nanoseconds.
 		Weight::from_parts(221_225_000, 5562)
-			.saturating_add(T::DbWeight::get().reads(12))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	// Storage: Slp OperateOrigins (r:1 w:1)
 	// Proof Skipped: Slp OperateOrigins (max_values: None, max_size: None, mode: Measured)
@@ -688,14 +742,22 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
 	// Storage: PolkadotXcm Queries (r:1 w:0)
 	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	// Proof: VtokenMinting OngoingTimeUnit (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	// Storage: VtokenMinting TokenPool (r:1 w:1)
+	// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: Slp SlashRecords (r:1 w:1)
+	// Proof Skipped: Slp SlashRecords (max_values: None, max_size: None, mode: Measured)
 	fn confirm_delegator_ledger_query_response() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `597`
 		//  Estimated: `4062`
 		// Minimum execution time: 73_648 nanoseconds.
 		Weight::from_parts(74_976_000, 4062)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	// Storage: Slp DelegatorLedgerXcmUpdateQueue (r:1 w:1)
 	// Proof Skipped: Slp DelegatorLedgerXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
@@ -798,4 +860,192 @@ impl<T: frame_system::Config> bifrost_slp::WeightInfo for BifrostWeight<T> {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: Slp ValidatorScoringConfigs (r:0 w:1)
+	// Proof Skipped: Slp ValidatorScoringConfigs (max_values: None, max_size: None, mode: Measured)
+	fn set_validator_scoring_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 27_361 nanoseconds.
+		Weight::from_parts(28_102_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Slp OperateOrigins (r:1 w:0)
+	// Proof Skipped: Slp OperateOrigins (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp Validators (r:1 w:0)
+	// Proof Skipped: Slp Validators (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp ValidatorScoreInputs (r:0 w:50)
+	// Proof Skipped: Slp ValidatorScoreInputs (max_values: None, max_size: None, mode: Measured)
+	// The range of component `n` is `[1, 50]`.
+	fn update_validator_score_inputs(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1322`
+		//  Estimated: `4787`
+		// Minimum execution time: 39_620 nanoseconds.
+		Weight::from_parts(36_745_188, 4787)
+			// Standard Error: 5_138
+			.saturating_add(Weight::from_parts(2_976_411, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes((1).saturating_mul(n.into())))
+	}
+	// Storage: Slp OperateOrigins (r:1 w:0)
+	// Proof Skipped: Slp OperateOrigins (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	// Proof: VtokenMinting OngoingTimeUnit (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
+	// Storage: Slp LastRebalanceTimeUnit (r:1 w:1)
+	// Proof Skipped: Slp LastRebalanceTimeUnit (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp ValidatorScoringConfigs (r:1 w:0)
+	// Proof Skipped: Slp ValidatorScoringConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp Validators (r:1 w:0)
+	// Proof Skipped: Slp Validators (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp ValidatorScoreInputs (r:100 w:0)
+	// Proof Skipped: Slp ValidatorScoreInputs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp DelegatorNextIndex (r:1 w:0)
+	// Proof Skipped: Slp DelegatorNextIndex (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp DelegatorsIndex2Multilocation (r:100 w:0)
+	// Proof Skipped: Slp DelegatorsIndex2Multilocation (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp DelegatorLedgers (r:100 w:0)
+	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp ValidatorsByDelegator (r:100 w:0)
+	// Proof Skipped: Slp ValidatorsByDelegator (max_values: None, max_size: None, mode: Measured)
+	// The range of component `v` is `[1, 100]`.
+	// The range of component `d` is `[1, 100]`.
+	fn rebalance_validators(v: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1190 + d * (121 ±0) + v * (98 ±0)`
+		//  Estimated: `4655 + d * (2596 ±0) + v * (2573 ±0)`
+		// Minimum execution time: 97_436 nanoseconds.
+		Weight::from_parts(60_184_000, 4655)
+			// Standard Error: 8_302
+			.saturating_add(Weight::from_parts(6_912_447, 0).saturating_mul(v.into()))
+			// Standard Error: 8_302
+			.saturating_add(Weight::from_parts(11_034_582, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((1).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().reads((3).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2573).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 2596).saturating_mul(d.into()))
+	}
+	// Storage: Slp OperateOrigins (r:1 w:0)
+	// Proof Skipped: Slp OperateOrigins (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp DelegatorsMultilocation2Index (r:1 w:0)
+	// Proof Skipped: Slp DelegatorsMultilocation2Index (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	// Proof: VtokenMinting OngoingTimeUnit (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
+	// Storage: Slp DelegatorLedgerXcmUpdateQueue (r:100 w:100)
+	// Proof Skipped: Slp DelegatorLedgerXcmUpdateQueue (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp DelegatorLedgers (r:2 w:100)
+	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm Queries (r:100 w:100)
+	// Proof Skipped: PolkadotXcm Queries (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp DelegatorLedgerDivergences (r:0 w:1)
+	// Proof Skipped: Slp DelegatorLedgerDivergences (max_values: None, max_size: None, mode: Measured)
+	// The range of component `q` is `[1, 100]`.
+	fn reconcile_delegator_ledger(q: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612 + q * (163 ±0)`
+		//  Estimated: `4077 + q * (2638 ±0)`
+		// Minimum execution time: 50_812 nanoseconds.
+		Weight::from_parts(52_306_000, 4077)
+			// Standard Error: 9_417
+			.saturating_add(Weight::from_parts(24_871_309, 0).saturating_mul(q.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(q.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(q.into())))
+			.saturating_add(Weight::from_parts(0, 2638).saturating_mul(q.into()))
+	}
+	// Storage: Slp FilecoinOracleSets (r:0 w:1)
+	// Proof Skipped: Slp FilecoinOracleSets (max_values: None, max_size: None, mode: Measured)
+	fn set_filecoin_oracle_set() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 31_208 nanoseconds.
+		Weight::from_parts(32_415_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Slp FilecoinOracleSets (r:1 w:0)
+	// Proof Skipped: Slp FilecoinOracleSets (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp DelegatorsMultilocation2Index (r:1 w:0)
+	// Proof Skipped: Slp DelegatorsMultilocation2Index (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	// Proof: VtokenMinting OngoingTimeUnit (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
+	// Storage: Slp VerifiedFilecoinReports (r:1 w:1)
+	// Proof Skipped: Slp VerifiedFilecoinReports (max_values: None, max_size: None, mode: Measured)
+	// Storage: System BlockHash (r:1 w:0)
+	// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	// Storage: Slp FilecoinVerifiedRewards (r:1 w:1)
+	// Proof Skipped: Slp FilecoinVerifiedRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp FilecoinTransfersIn (r:1 w:1)
+	// Proof Skipped: Slp FilecoinTransfersIn (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp DelegatorLedgers (r:0 w:1)
+	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
+	// The range of component `n` is `[1, 50]`.
+	fn submit_filecoin_report(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2046`
+		//  Estimated: `5511`
+		// Minimum execution time: 101_344 nanoseconds.
+		Weight::from_parts(58_620_917, 5511)
+			// Standard Error: 9_412
+			.saturating_add(Weight::from_parts(47_903_512, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: Slp OperatorApprovalConfigs (r:0 w:1)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	fn set_operator_approval_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 30_562 nanoseconds.
+		Weight::from_parts(31_794_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
+	fn approve_operation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1842`
+		//  Estimated: `5307`
+		// Minimum execution time: 52_317 nanoseconds.
+		Weight::from_parts(54_026_000, 5307)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Slp PendingOperations (r:1 w:1)
+	// Proof Skipped: Slp PendingOperations (max_values: None, max_size: None, mode: Measured)
+	// Storage: Slp OperatorApprovalConfigs (r:1 w:0)
+	// Proof Skipped: Slp OperatorApprovalConfigs (max_values: None, max_size: None, mode: Measured)
+	fn cancel_operation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1911`
+		//  Estimated: `5376`
+		// Minimum execution time: 45_803 nanoseconds.
+		Weight::from_parts(47_251_000, 5376)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Slp DelegatorLedgers (r:1 w:1)
+	// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
+	// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
+	// Proof: VtokenMinting OngoingTimeUnit (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	// Storage: VtokenMinting TokenPool (r:1 w:1)
+	// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: Slp SlashRecords (r:1 w:1)
+	// Proof Skipped: Slp SlashRecords (max_values: None, max_size: None, mode: Measured)
+	// The benchmarked `set_delegator_ledger`, plus the insurance fund transfer, the token pool
+	// and the slash records of the slash.
+	fn report_slash() -> Weight {
+		Self::set_delegator_ledger()
+			.saturating_add(Weight::from_parts(0, 10201))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -210,13 +210,15 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     // Storage: `System::Events` (r:1 w:1)
     // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
     fn transfer_to() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `942`
-        //  Estimated: `3583`
+        //  Estimated: `8670`
         // Minimum execution time: 51_620 nanoseconds.
-        Weight::from_parts(52_770_000, 3583)
-            .saturating_add(T::DbWeight::get().reads(7))
+        Weight::from_parts(52_770_000, 8670)
+            .saturating_add(T::DbWeight::get().reads(8))
             .saturating_add(T::DbWeight::get().writes(2))
     }
     // Storage: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (r:1 w:0)
@@ -233,13 +235,15 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     // Storage: `System::Events` (r:1 w:1)
     // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
     fn transfer_back() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `596`
-        //  Estimated: `3567`
+        //  Estimated: `8654`
         // Minimum execution time: 57_783 nanoseconds.
-        Weight::from_parts(59_016_000, 3567)
-            .saturating_add(T::DbWeight::get().reads(7))
+        Weight::from_parts(59_016_000, 8654)
+            .saturating_add(T::DbWeight::get().reads(8))
             .saturating_add(T::DbWeight::get().writes(2))
     }
     // Storage: `ParachainSystem::ValidationData` (r:1 w:0)
@@ -260,13 +264,15 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     // Storage: `System::Events` (r:1 w:1)
     // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
     fn update_ongoing_time_unit() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `560`
-        //  Estimated: `3567`
+        //  Estimated: `8654`
         // Minimum execution time: 45_649 nanoseconds.
-        Weight::from_parts(47_962_000, 3567)
-            .saturating_add(T::DbWeight::get().reads(9))
+        Weight::from_parts(47_962_000, 8654)
+            .saturating_add(T::DbWeight::get().reads(10))
             .saturating_add(T::DbWeight::get().writes(4))
     }
     // Storage: `SlpV2::ConfigurationByStakingProtocol` (r:1 w:0)
@@ -289,13 +295,15 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     // Storage: `System::Events` (r:1 w:1)
     // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
     fn update_token_exchange_rate() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `755`
-        //  Estimated: `3717`
+        //  Estimated: `8804`
         // Minimum execution time: 62_204 nanoseconds.
-        Weight::from_parts(63_446_000, 3717)
-            .saturating_add(T::DbWeight::get().reads(10))
+        Weight::from_parts(63_446_000, 8804)
+            .saturating_add(T::DbWeight::get().reads(11))
             .saturating_add(T::DbWeight::get().writes(5))
     }
     // Storage: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (r:1 w:0)
@@ -318,13 +326,15 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
     // Storage: `PolkadotXcm::Queries` (r:0 w:1)
     // Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
     fn astar_dapp_staking() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `836`
-        //  Estimated: `3567`
+        //  Estimated: `8654`
         // Minimum execution time: 72_646 nanoseconds.
-        Weight::from_parts(73_892_000, 3567)
-            .saturating_add(T::DbWeight::get().reads(8))
+        Weight::from_parts(73_892_000, 8654)
+            .saturating_add(T::DbWeight::get().reads(9))
             .saturating_add(T::DbWeight::get().writes(5))
     }
     // Storage: `SlpV2::PendingStatusByQueryId` (r:1 w:0)
@@ -339,12 +349,151 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     // Storage: `System::Events` (r:1 w:1)
     // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
     fn notify_astar_dapp_staking() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `567`
-        //  Estimated: `3717`
+        //  Estimated: `8804`
         // Minimum execution time: 40_876 nanoseconds.
-        Weight::from_parts(41_668_000, 3717)
+        Weight::from_parts(41_668_000, 8804)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    // Storage: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (r:1 w:0)
+    // Proof: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (r:1 w:0)
+    // Proof: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:0)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(772), added: 3247, mode: `MaxEncodedLen`)
+    // Storage: `System::Number` (r:1 w:0)
+    // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+    // Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::ConfigurationByStakingProtocol` (r:1 w:0)
+    // Proof: `SlpV2::ConfigurationByStakingProtocol` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+    // Storage: `System::ExecutionPhase` (r:1 w:0)
+    // Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    // Storage: `System::EventCount` (r:1 w:1)
+    // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::Events` (r:1 w:1)
+    // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::PendingStatusByQueryId` (r:0 w:1)
+    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+    // Storage: `PolkadotXcm::Queries` (r:0 w:1)
+    // Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
+    fn polkadot_staking() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `903`
+        //  Estimated: `9324`
+        // Minimum execution time: 76_318 nanoseconds.
+        Weight::from_parts(77_504_000, 9324)
+            .saturating_add(T::DbWeight::get().reads(10))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    // Storage: `SlpV2::PendingStatusByQueryId` (r:1 w:1)
+    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:1)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(772), added: 3247, mode: `MaxEncodedLen`)
+    // Storage: `System::Number` (r:1 w:0)
+    // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::ExecutionPhase` (r:1 w:0)
+    // Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    // Storage: `System::EventCount` (r:1 w:1)
+    // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::Events` (r:1 w:1)
+    // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
+    fn notify_polkadot_staking() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `612`
+        //  Estimated: `9324`
+        // Minimum execution time: 42_953 nanoseconds.
+        Weight::from_parts(43_820_000, 9324)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(3))
+            // Removing the pending status is not covered by the benchmark run above.
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    // Storage: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (r:1 w:0)
+    // Proof: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (r:1 w:0)
+    // Proof: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:0)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(6337), added: 8812, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::ValidatorsByStakingProtocolAndDelegator` (r:1 w:0)
+    // Proof: `SlpV2::ValidatorsByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(8772), added: 11247, mode: `MaxEncodedLen`)
+    // Storage: `System::Number` (r:1 w:0)
+    // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+    // Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::ConfigurationByStakingProtocol` (r:1 w:0)
+    // Proof: `SlpV2::ConfigurationByStakingProtocol` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+    // Storage: `System::ExecutionPhase` (r:1 w:0)
+    // Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    // Storage: `System::EventCount` (r:1 w:1)
+    // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::Events` (r:1 w:1)
+    // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::PendingStatusByQueryId` (r:0 w:1)
+    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+    // Storage: `PolkadotXcm::Queries` (r:0 w:1)
+    // Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
+    fn moonbeam_parachain_staking() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1021`
+        //  Estimated: `17324`
+        // Minimum execution time: 79_245 nanoseconds.
+        Weight::from_parts(80_631_000, 17324)
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    // Storage: `SlpV2::PendingStatusByQueryId` (r:1 w:1)
+    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:1)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(6337), added: 8812, mode: `MaxEncodedLen`)
+    // Storage: `System::Number` (r:1 w:0)
+    // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::ExecutionPhase` (r:1 w:0)
+    // Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    // Storage: `System::EventCount` (r:1 w:1)
+    // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::Events` (r:1 w:1)
+    // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:0)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
+    fn notify_moonbeam_parachain_staking() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `689`
+        //  Estimated: `14889`
+        // Minimum execution time: 44_716 nanoseconds.
+        Weight::from_parts(45_592_000, 14889)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    // Storage: `SlpV2::StakingProtocolInfoByProtocolId` (r:1 w:1)
+    // Proof: `SlpV2::StakingProtocolInfoByProtocolId` (`max_values`: None, `max_size`: Some(2612), added: 5087, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (r:1 w:0)
+    // Proof: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    // Storage: `System::Number` (r:1 w:0)
+    // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::ExecutionPhase` (r:1 w:0)
+    // Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    // Storage: `System::EventCount` (r:1 w:1)
+    // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::Events` (r:1 w:1)
+    // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    fn set_staking_protocol_info() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `238`
+        //  Estimated: `9128`
+        // Minimum execution time: 35_914 nanoseconds.
+        Weight::from_parts(37_108_000, 9128)
             .saturating_add(T::DbWeight::get().reads(6))
             .saturating_add(T::DbWeight::get().writes(3))
     }